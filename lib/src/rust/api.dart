@@ -3,34 +3,91 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import 'api/credentials.dart';
+import 'api/logger.dart';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'api.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `ensure_client_initialized`, `get_or_create_channel_mutex`, `lock_client_with_owner`, `split_trace_camera`
+// These functions are ignored because they are not marked as `pub`: `add_camera`, `client_lock_timeout`, `decrypt_batch`, `decrypt_locked`, `decrypt_with_epoch`, `ensure_client_initialized`, `get_or_create_channel_mutex`, `initialize_from_params`, `is_fatal_for_group`, `lock_client_with_owner`, `metric_kind`, `refuse_pairing`, `reload_client_from_disk`, `resolve_call`, `split_trace_camera`, `to_legacy_string`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ClientKey`, `InitParams`, `TracedClientGuard`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `deref_mut`, `deref`, `drop`, `eq`, `hash`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `deref_mut`, `deref`, `drop`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `hash`
 
 Future<bool> initializeCamera({
   required String cameraName,
   required String fileDir,
   required bool firstTime,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiInitializeCamera(
   cameraName: cameraName,
   fileDir: fileDir,
   firstTime: firstTime,
+  context: context,
 );
 
-Future<void> deregisterCamera({required String cameraName}) =>
-    RustLib.instance.api.crateApiDeregisterCamera(cameraName: cameraName);
+Future<void> deregisterCamera({
+  required String cameraName,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiDeregisterCamera(
+  cameraName: cameraName,
+  context: context,
+);
+
+/// Throw away the in-memory client for `camera_name` and load it again from
+/// disk, without touching the state there. Use this instead of deregistering
+/// when the client may be behind what another process wrote.
+///
+/// The reload waits for the call holding the client, as any call does, and
+/// fails with "Error: Busy" past the lock timeout. Calls that already failed
+/// against the old client are not retried; repeat them after the reload.
+Future<ClientReloadReport> reloadCameraClient({
+  required String cameraName,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiReloadCameraClient(
+  cameraName: cameraName,
+  context: context,
+);
+
+/// Decrypt a motion video, using `assumed_epoch` to decide whether the client
+/// needs to catch up with state written by another process.
+Future<DecryptedFile> decryptVideoAtEpoch({
+  required String cameraName,
+  required String encFilename,
+  required BigInt assumedEpoch,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiDecryptVideoAtEpoch(
+  cameraName: cameraName,
+  encFilename: encFilename,
+  assumedEpoch: assumedEpoch,
+  context: context,
+);
+
+/// Thumbnail counterpart of [`decrypt_video_at_epoch`].
+Future<DecryptedFile> decryptThumbnailAtEpoch({
+  required String cameraName,
+  required String encFilename,
+  required String pendingMetaDirectory,
+  required BigInt assumedEpoch,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiDecryptThumbnailAtEpoch(
+  cameraName: cameraName,
+  encFilename: encFilename,
+  pendingMetaDirectory: pendingMetaDirectory,
+  assumedEpoch: assumedEpoch,
+  context: context,
+);
 
 Future<String> decryptVideo({
   required String cameraName,
   required String encFilename,
   required BigInt assumedEpoch,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiDecryptVideo(
   cameraName: cameraName,
   encFilename: encFilename,
   assumedEpoch: assumedEpoch,
+  context: context,
 );
 
 Future<String> decryptThumbnail({
@@ -38,13 +95,47 @@ Future<String> decryptThumbnail({
   required String encFilename,
   required String pendingMetaDirectory,
   required BigInt assumedEpoch,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiDecryptThumbnail(
   cameraName: cameraName,
   encFilename: encFilename,
   pendingMetaDirectory: pendingMetaDirectory,
   assumedEpoch: assumedEpoch,
+  context: context,
+);
+
+/// Decrypt a queue of motion videos in order under a single client lock.
+/// Returns one outcome per input file, in the same order.
+Future<List<DecryptOutcome>> decryptVideosBatch({
+  required String cameraName,
+  required List<String> encFilenames,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiDecryptVideosBatch(
+  cameraName: cameraName,
+  encFilenames: encFilenames,
+  context: context,
+);
+
+/// Thumbnail counterpart of [`decrypt_videos_batch`].
+Future<List<DecryptOutcome>> decryptThumbnailsBatch({
+  required String cameraName,
+  required List<String> encFilenames,
+  required String pendingMetaDirectory,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiDecryptThumbnailsBatch(
+  cameraName: cameraName,
+  encFilenames: encFilenames,
+  pendingMetaDirectory: pendingMetaDirectory,
+  context: context,
 );
 
+/// Pair with a camera and hand it the relay server `credentials`. `ip` and the
+/// credentials are checked before the camera is contacted.
+///
+/// The native client only pairs with a bare IPv4 address (optionally naming
+/// port 12348, the one it always dials), so hostnames, IPv6 addresses and other
+/// ports are refused before anything is resolved. Other backends take any
+/// address `probe_proprietary_device` accepts.
 Future<String> flutterAddCamera({
   required String cameraName,
   required String ip,
@@ -53,7 +144,8 @@ Future<String> flutterAddCamera({
   required String ssid,
   required String password,
   required String pairingToken,
-  required String credentialsFull,
+  required ServerCredentials credentials,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiFlutterAddCamera(
   cameraName: cameraName,
   ip: ip,
@@ -62,7 +154,8 @@ Future<String> flutterAddCamera({
   ssid: ssid,
   password: password,
   pairingToken: pairingToken,
-  credentialsFull: credentialsFull,
+  credentials: credentials,
+  context: context,
 );
 
 Future<void> shutdownApp() => RustLib.instance.api.crateApiShutdownApp();
@@ -73,45 +166,55 @@ Future<bool> pingProprietaryDevice({required String cameraIp}) =>
 Future<Uint8List> encryptSettingsMessage({
   required String cameraName,
   required List<int> data,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiEncryptSettingsMessage(
   cameraName: cameraName,
   data: data,
+  context: context,
 );
 
 Future<String> decryptMessage({
   required String clientTag,
   required String cameraName,
   required List<int> data,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiDecryptMessage(
   clientTag: clientTag,
   cameraName: cameraName,
   data: data,
+  context: context,
 );
 
 Future<String> getGroupName({
   required String clientTag,
   required String cameraName,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiGetGroupName(
   clientTag: clientTag,
   cameraName: cameraName,
+  context: context,
 );
 
 Future<bool> livestreamUpdate({
   required String cameraName,
   required List<int> msg,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiLivestreamUpdate(
   cameraName: cameraName,
   msg: msg,
+  context: context,
 );
 
 Future<Uint8List> livestreamDecrypt({
   required String cameraName,
   required List<int> data,
   required BigInt expectedChunkNumber,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiLivestreamDecrypt(
   cameraName: cameraName,
   data: data,
   expectedChunkNumber: expectedChunkNumber,
+  context: context,
 );
 
 Future<String> rustLibVersion() =>
@@ -120,17 +223,106 @@ Future<String> rustLibVersion() =>
 Future<Uint8List> generateHeartbeatRequestConfigCommand({
   required String cameraName,
   required BigInt timestamp,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiGenerateHeartbeatRequestConfigCommand(
   cameraName: cameraName,
   timestamp: timestamp,
+  context: context,
 );
 
 Future<String> processHeartbeatConfigResponse({
   required String cameraName,
   required List<int> configResponse,
   required BigInt expectedTimestamp,
+  CallContext? context,
 }) => RustLib.instance.api.crateApiProcessHeartbeatConfigResponse(
   cameraName: cameraName,
   configResponse: configResponse,
   expectedTimestamp: expectedTimestamp,
+  context: context,
 );
+
+/// What `reload_camera_client` found on disk.
+class ClientReloadReport {
+  /// A client was loaded in memory before the reload.
+  final bool wasLoaded;
+  /// The state on disk loaded again. If not, the camera has no client until
+  /// its state is restored or it is paired again.
+  final bool consistent;
+  /// Why the state did not load, when `consistent` is false.
+  final String? error;
+
+  const ClientReloadReport({
+    required this.wasLoaded,
+    required this.consistent,
+    this.error,
+  });
+
+  @override
+  int get hashCode => wasLoaded.hashCode ^ consistent.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClientReloadReport &&
+          runtimeType == other.runtimeType &&
+          wasLoaded == other.wasLoaded &&
+          consistent == other.consistent &&
+          error == other.error;
+}
+
+@freezed
+sealed class DecryptError with _$DecryptError implements FrbException {
+  const DecryptError._();
+
+  /// The client lock was held by someone else past the timeout.
+  const factory DecryptError.busy() = DecryptError_Busy;
+  /// The client could not be created (missing init params or bad state on disk).
+  const factory DecryptError.notInitialized() = DecryptError_NotInitialized;
+  /// The file belongs to a different MLS epoch than the group is at, even after
+  /// reloading the client from disk. `expected` is the group epoch and `found`
+  /// is the epoch carried by the file, when the client reports them. Callers
+  /// should queue the file rather than discard it.
+  const factory DecryptError.epochMismatch({BigInt? expected, BigInt? found}) =
+      DecryptError_EpochMismatch;
+  /// Any other error reported by the native layer.
+  const factory DecryptError.failed(String field0) = DecryptError_Failed;
+}
+
+@freezed
+sealed class DecryptOutcome with _$DecryptOutcome {
+  const DecryptOutcome._();
+
+  const factory DecryptOutcome.decrypted(DecryptedFile field0) =
+      DecryptOutcome_Decrypted;
+  const factory DecryptOutcome.failed(DecryptError field0) =
+      DecryptOutcome_Failed;
+  /// Not attempted because an earlier file in the queue hit a fatal group error.
+  const factory DecryptOutcome.skipped() = DecryptOutcome_Skipped;
+}
+
+class DecryptedFile {
+  final String filename;
+  /// MLS epoch of the group after decrypting the file, if the client reports it.
+  final BigInt? epoch;
+  /// True if the in-memory client was behind and had to be reloaded from disk.
+  final bool caughtUp;
+
+  const DecryptedFile({
+    required this.filename,
+    this.epoch,
+    required this.caughtUp,
+  });
+
+  @override
+  int get hashCode => filename.hashCode ^ epoch.hashCode ^ caughtUp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DecryptedFile &&
+          runtimeType == other.runtimeType &&
+          filename == other.filename &&
+          epoch == other.epoch &&
+          caughtUp == other.caughtUp;
+}
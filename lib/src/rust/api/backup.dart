@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'logger.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cipher`, `decode`, `encode`, `list_files`, `open_backup`, `put_bytes`, `refuse_newer_state`, `restore`, `safe_join`, `state_files`, `take_bytes`, `take`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Payload`
+
+/// Back up the MLS client state of `camera_name`, encrypted under `passphrase`.
+Future<Uint8List> exportCameraBackup({
+  required String cameraName,
+  required String passphrase,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiBackupExportCameraBackup(
+  cameraName: cameraName,
+  passphrase: passphrase,
+  context: context,
+);
+
+/// Restore a backup made by `export_camera_backup` into `file_dir` and return
+/// the camera name it belongs to, removing state files the backup doesn't have.
+/// Refuses to replace state known to be from a later epoch. Call
+/// `initialize_camera` with the returned name afterwards.
+Future<String> importCameraBackup({
+  required List<int> backup,
+  required String passphrase,
+  required String fileDir,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiBackupImportCameraBackup(
+  backup: backup,
+  passphrase: passphrase,
+  fileDir: fileDir,
+  context: context,
+);
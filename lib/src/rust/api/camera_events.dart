@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `emit`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Receive every camera event from now on. Each isolate that subscribes gets
+/// its own copy; closed streams are dropped on the next event.
+Stream<CameraEvent> subscribeCameraEvents() =>
+    RustLib.instance.api.crateApiCameraEventsSubscribeCameraEvents();
+
+class CameraEvent {
+  final PlatformInt64 timeMillis;
+  final String cameraName;
+  final CameraEventKind kind;
+  final String? detail;
+  final String? traceId;
+
+  const CameraEvent({
+    required this.timeMillis,
+    required this.cameraName,
+    required this.kind,
+    this.detail,
+    this.traceId,
+  });
+
+  @override
+  int get hashCode =>
+      timeMillis.hashCode ^
+      cameraName.hashCode ^
+      kind.hashCode ^
+      detail.hashCode ^
+      traceId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CameraEvent &&
+          runtimeType == other.runtimeType &&
+          timeMillis == other.timeMillis &&
+          cameraName == other.cameraName &&
+          kind == other.kind &&
+          detail == other.detail &&
+          traceId == other.traceId;
+}
+
+enum CameraEventKind {
+  /// `initialize_camera` stored the state directory; the client loads lazily.
+  registered,
+  /// The client was loaded from (or created in) the state directory.
+  clientInitialized,
+  clientInitFailed,
+  paired,
+  pairingFailed,
+  deregistered,
+  /// `detail` carries the firmware version.
+  heartbeatHealthy,
+  /// `detail` carries the result Dart would have received.
+  heartbeatFailed,
+  /// A call gave up waiting for the client lock; `detail` names the holder.
+  lockTimeout,
+}
@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_field`, `encode`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`
+
+class ServerCredentials {
+  /// Base URL of the relay server, e.g. `https://relay.example.com:8000`.
+  final String address;
+  final String username;
+  final String password;
+
+  const ServerCredentials({
+    required this.address,
+    required this.username,
+    required this.password,
+  });
+
+  /// Check that every field is something the camera can use.
+  Future<void> validate() =>
+      RustLib.instance.api.crateApiCredentialsServerCredentialsValidate(
+        that: this,
+      );
+
+  @override
+  int get hashCode => address.hashCode ^ username.hashCode ^ password.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ServerCredentials &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          username == other.username &&
+          password == other.password;
+}
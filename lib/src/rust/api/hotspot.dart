@@ -0,0 +1,99 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `device_addr`, `probe`, `resolve_device_addr`, `sleep_unless_cancelled`, `split_host_port`, `wait_for_device`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Check once whether the camera at `address` (see `resolve_device_addr` for
+/// the accepted forms) accepts connections on `port`, or the pairing port.
+/// Unlike `ping_proprietary_device`, bad input is an error rather than false.
+Future<bool> probeProprietaryDevice({required String address, int? port}) =>
+    RustLib.instance.api.crateApiHotspotProbeProprietaryDevice(
+      address: address,
+      port: port,
+    );
+
+/// Reserve an id for a `wait_for_proprietary_device` call, so the wait can be
+/// cancelled with `cancel_device_wait` while it runs.
+Future<String> newDeviceWaiter() =>
+    RustLib.instance.api.crateApiHotspotNewDeviceWaiter();
+
+/// Probe the camera at `camera_ip` (and `port`, as for
+/// `probe_proprietary_device`) every `poll_interval_millis` until it has
+/// answered `required_stable_polls` times in a row, then wait
+/// `settle_delay_millis` more and report it ready. Gives up after
+/// `timeout_millis`, or when `cancel_device_wait(waiter_id)` is called.
+/// `waiter_id` comes from `new_device_waiter` and is used up by the wait.
+Future<WaitOutcome> waitForProprietaryDevice({
+  required String cameraIp,
+  int? port,
+  required BigInt timeoutMillis,
+  required BigInt pollIntervalMillis,
+  required int requiredStablePolls,
+  required BigInt settleDelayMillis,
+  required String waiterId,
+}) => RustLib.instance.api.crateApiHotspotWaitForProprietaryDevice(
+  cameraIp: cameraIp,
+  port: port,
+  timeoutMillis: timeoutMillis,
+  pollIntervalMillis: pollIntervalMillis,
+  requiredStablePolls: requiredStablePolls,
+  settleDelayMillis: settleDelayMillis,
+  waiterId: waiterId,
+);
+
+/// Cancel a pending `wait_for_proprietary_device`. Returns false if the id is
+/// unknown or its wait has already ended. Ids no wait takes expire after a
+/// minute.
+Future<bool> cancelDeviceWait({required String waiterId}) =>
+    RustLib.instance.api.crateApiHotspotCancelDeviceWait(waiterId: waiterId);
+
+class WaitOutcome {
+  final WaitStatus status;
+  /// Probes made, successful or not.
+  final int attempts;
+  /// Successful probes in a row when the wait ended.
+  final int stablePolls;
+  /// How long each probe took to connect or fail, in order.
+  final Uint64List probeLatenciesMicros;
+  final BigInt elapsedMillis;
+  /// Why the most recent failed probe failed.
+  final String? lastError;
+
+  const WaitOutcome({
+    required this.status,
+    required this.attempts,
+    required this.stablePolls,
+    required this.probeLatenciesMicros,
+    required this.elapsedMillis,
+    this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      status.hashCode ^
+      attempts.hashCode ^
+      stablePolls.hashCode ^
+      probeLatenciesMicros.hashCode ^
+      elapsedMillis.hashCode ^
+      lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WaitOutcome &&
+          runtimeType == other.runtimeType &&
+          status == other.status &&
+          attempts == other.attempts &&
+          stablePolls == other.stablePolls &&
+          probeLatenciesMicros == other.probeLatenciesMicros &&
+          elapsedMillis == other.elapsedMillis &&
+          lastError == other.lastError;
+}
+
+enum WaitStatus { ready, timedOut, cancelled }
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'logger.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `after_times`, `begin_box`, `box_header`, `children`, `end_box`, `find`, `finish`, `fix_duration`, `flush`, `forget`, `observe`, `payload`, `push`, `read_u32`, `require`, `rescale`, `send`, `spawn_writer`, `tap`, `write_bytes`, `write_duration`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BoxHeader`, `CurrentBox`, `FixedDuration`, `Mp4Box`, `Recording`, `Sink`, `StreamTap`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Start saving the livestream of `camera_name` to `out_path`. The file is
+/// created (or truncated) now and holds video from the next fragment on.
+Future<void> startLivestreamRecording({
+  required String cameraName,
+  required String outPath,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiLivestreamRecordingStartLivestreamRecording(
+  cameraName: cameraName,
+  outPath: outPath,
+  context: context,
+);
+
+/// Stop recording the livestream of `camera_name` and finalize the file.
+/// If no complete fragment arrived, the file is deleted and an error returned.
+Future<RecordingSummary> stopLivestreamRecording({
+  required String cameraName,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiLivestreamRecordingStopLivestreamRecording(
+  cameraName: cameraName,
+  context: context,
+);
+
+class RecordingSummary {
+  final String path;
+  /// `moof` + `mdat` fragments written.
+  final int fragments;
+  final int frames;
+  final BigInt durationMillis;
+  final BigInt bytes;
+
+  const RecordingSummary({
+    required this.path,
+    required this.fragments,
+    required this.frames,
+    required this.durationMillis,
+    required this.bytes,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      fragments.hashCode ^
+      frames.hashCode ^
+      durationMillis.hashCode ^
+      bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecordingSummary &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          fragments == other.fragments &&
+          frames == other.frames &&
+          durationMillis == other.durationMillis &&
+          bytes == other.bytes;
+}
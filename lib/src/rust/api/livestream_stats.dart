@@ -0,0 +1,116 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'logger.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `begin_session`, `chunk`, `decrypted`, `forget`, `lock_acquired`, `now_millis`, `percentile`, `record_update_failure`, `snapshot`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChunkStats`, `SessionStats`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Statistics for the current livestream session of `camera_name`. All zero
+/// if it has never streamed.
+Future<LivestreamStats> livestreamStats({
+  required String cameraName,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiLivestreamStatsLivestreamStats(
+  cameraName: cameraName,
+  context: context,
+);
+
+class LivestreamStats {
+  /// When the last successful `livestream_update` started this session.
+  /// None if there has been none.
+  final PlatformInt64? sessionStartedAtMillis;
+  final BigInt updateFailures;
+  final BigInt chunksReceived;
+  final BigInt chunksDecrypted;
+  /// Chunks that did not decrypt, including those given up on because the
+  /// client lock was busy.
+  final BigInt chunksFailed;
+  /// Chunks whose number was not one past the last decrypted chunk.
+  final BigInt chunksOutOfOrder;
+  /// Encrypted bytes handed to `livestream_decrypt`.
+  final BigInt bytesReceived;
+  final BigInt bytesDecrypted;
+  final BigInt? lastChunkNumber;
+  final PlatformInt64? lastChunkAtMillis;
+  /// Decrypt latency percentiles over the most recent chunks.
+  final BigInt decryptP50Micros;
+  final BigInt decryptP90Micros;
+  final BigInt decryptP99Micros;
+  final BigInt decryptMaxMicros;
+  final BigInt lockWaitTotalMicros;
+  final BigInt lockWaitMaxMicros;
+  final BigInt lockTimeouts;
+
+  const LivestreamStats({
+    this.sessionStartedAtMillis,
+    required this.updateFailures,
+    required this.chunksReceived,
+    required this.chunksDecrypted,
+    required this.chunksFailed,
+    required this.chunksOutOfOrder,
+    required this.bytesReceived,
+    required this.bytesDecrypted,
+    this.lastChunkNumber,
+    this.lastChunkAtMillis,
+    required this.decryptP50Micros,
+    required this.decryptP90Micros,
+    required this.decryptP99Micros,
+    required this.decryptMaxMicros,
+    required this.lockWaitTotalMicros,
+    required this.lockWaitMaxMicros,
+    required this.lockTimeouts,
+  });
+
+  static Future<LivestreamStats> default_() =>
+      RustLib.instance.api.crateApiLivestreamStatsLivestreamStatsDefault();
+
+  @override
+  int get hashCode =>
+      sessionStartedAtMillis.hashCode ^
+      updateFailures.hashCode ^
+      chunksReceived.hashCode ^
+      chunksDecrypted.hashCode ^
+      chunksFailed.hashCode ^
+      chunksOutOfOrder.hashCode ^
+      bytesReceived.hashCode ^
+      bytesDecrypted.hashCode ^
+      lastChunkNumber.hashCode ^
+      lastChunkAtMillis.hashCode ^
+      decryptP50Micros.hashCode ^
+      decryptP90Micros.hashCode ^
+      decryptP99Micros.hashCode ^
+      decryptMaxMicros.hashCode ^
+      lockWaitTotalMicros.hashCode ^
+      lockWaitMaxMicros.hashCode ^
+      lockTimeouts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LivestreamStats &&
+          runtimeType == other.runtimeType &&
+          sessionStartedAtMillis == other.sessionStartedAtMillis &&
+          updateFailures == other.updateFailures &&
+          chunksReceived == other.chunksReceived &&
+          chunksDecrypted == other.chunksDecrypted &&
+          chunksFailed == other.chunksFailed &&
+          chunksOutOfOrder == other.chunksOutOfOrder &&
+          bytesReceived == other.bytesReceived &&
+          bytesDecrypted == other.bytesDecrypted &&
+          lastChunkNumber == other.lastChunkNumber &&
+          lastChunkAtMillis == other.lastChunkAtMillis &&
+          decryptP50Micros == other.decryptP50Micros &&
+          decryptP90Micros == other.decryptP90Micros &&
+          decryptP99Micros == other.decryptP99Micros &&
+          decryptMaxMicros == other.decryptMaxMicros &&
+          lockWaitTotalMicros == other.lockWaitTotalMicros &&
+          lockWaitMaxMicros == other.lockWaitMaxMicros &&
+          lockTimeouts == other.lockTimeouts;
+}
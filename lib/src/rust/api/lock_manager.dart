@@ -6,18 +6,194 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Blocking lock
+// These functions are ignored because they are not marked as `pub`: `acquire_blocking`, `acquire`, `add_handle`, `clear_owner_info`, `hold`, `is_dead_process`, `join_hold`, `joinable`, `log_lock_timeout`, `now_millis`, `open_lock_file`, `owners_dir`, `parse_owner_info`, `refuse_handle_holds`, `release_all_locks`, `release`, `try_acquire`, `write_owner_info`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Acquired`, `HandleInfo`, `Hold`, `Registry`, `Wait`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `drop`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Deprecated: use `acquire_lock_as`, which returns a handle.
+///
+/// Blocking exclusive lock, released with `release_lock(path)`. Fails if this
+/// process holds `path` through handles.
 Future<bool> acquireLock({required String path}) =>
     RustLib.instance.api.crateApiLockManagerAcquireLock(path: path);
 
-/// Non-blocking lock
+/// Deprecated: use `try_acquire_lock_as`, which returns a handle.
+///
+/// Non-blocking exclusive lock, released with `release_lock(path)`. Fails if
+/// this process holds `path` through handles.
 Future<bool> tryAcquireLock({required String path}) =>
     RustLib.instance.api.crateApiLockManagerTryAcquireLock(path: path);
 
-/// Release a previously acquired lock
+/// Deprecated: use `release_lock_handle`.
+///
+/// Release the lock taken on `path` by `acquire_lock` or `try_acquire_lock`.
+/// Locks held through handles are left alone.
 Future<void> releaseLock({required String path}) =>
     RustLib.instance.api.crateApiLockManagerReleaseLock(path: path);
 
+/// Blocking exclusive lock, recording `owner` (e.g. the isolate label) next to
+/// the lock file. An owner may lock a path it already holds; the lock is
+/// released once every handle it took is.
+Future<LockHandle> acquireLockAs({required String path, String? owner}) =>
+    RustLib.instance.api.crateApiLockManagerAcquireLockAs(
+      path: path,
+      owner: owner,
+    );
+
+/// Blocking shared lock. Any number of shared holders may coexist, but they
+/// exclude exclusive holders. Asking for an exclusive lock on a path held
+/// shared here waits until every shared handle is released.
+Future<LockHandle> acquireSharedLock({required String path, String? owner}) =>
+    RustLib.instance.api.crateApiLockManagerAcquireSharedLock(
+      path: path,
+      owner: owner,
+    );
+
+/// Non-blocking exclusive lock, recording `owner` next to the lock file.
+/// Returns `None` if the lock is held elsewhere.
+Future<LockHandle?> tryAcquireLockAs({required String path, String? owner}) =>
+    RustLib.instance.api.crateApiLockManagerTryAcquireLockAs(
+      path: path,
+      owner: owner,
+    );
+
+/// Non-blocking shared lock
+Future<LockHandle?> tryAcquireSharedLock({
+  required String path,
+  String? owner,
+}) => RustLib.instance.api.crateApiLockManagerTryAcquireSharedLock(
+  path: path,
+  owner: owner,
+);
+
+/// Exclusive lock that gives up after `timeout_millis`. Returns `None` on timeout.
+Future<LockHandle?> acquireLockWithTimeout({
+  required String path,
+  required BigInt timeoutMillis,
+  String? owner,
+}) => RustLib.instance.api.crateApiLockManagerAcquireLockWithTimeout(
+  path: path,
+  timeoutMillis: timeoutMillis,
+  owner: owner,
+);
+
+/// Reserve an id for a `wait_for_lock` call, so the wait can be cancelled
+/// with `cancel_lock_wait` while it runs.
+Future<String> newLockWaiter() =>
+    RustLib.instance.api.crateApiLockManagerNewLockWaiter();
+
+/// Wait up to `timeout_millis` for an exclusive lock, giving up early if
+/// `cancel_lock_wait(waiter_id)` is called. `waiter_id` comes from
+/// `new_lock_waiter` and is used up by the wait. Returns the lock handle, or
+/// fails with "Timeout ..." or "Cancelled".
+Future<LockHandle> waitForLock({
+  required String path,
+  required BigInt timeoutMillis,
+  String? owner,
+  required String waiterId,
+}) => RustLib.instance.api.crateApiLockManagerWaitForLock(
+  path: path,
+  timeoutMillis: timeoutMillis,
+  owner: owner,
+  waiterId: waiterId,
+);
+
+/// Cancel a pending `wait_for_lock`. Returns false if the id is unknown or its
+/// wait has already ended. Ids no wait takes expire after a minute.
+Future<bool> cancelLockWait({required String waiterId}) =>
+    RustLib.instance.api.crateApiLockManagerCancelLockWait(waiterId: waiterId);
+
+/// Release the lock behind `handle`. Returns false if it was already released
+/// by `shutdown_app`.
+Future<bool> releaseLockHandle({required LockHandle handle}) =>
+    RustLib.instance.api.crateApiLockManagerReleaseLockHandle(handle: handle);
+
 /// Check if the current process holds the lock
 Future<bool> isLockHeld({required String path}) =>
     RustLib.instance.api.crateApiLockManagerIsLockHeld(path: path);
+
+/// Every lock handle this process holds, oldest first.
+Future<List<HeldLockInfo>> listHeldLocks() =>
+    RustLib.instance.api.crateApiLockManagerListHeldLocks();
+
+/// Report who holds the lock at `path`, in this or any other process, from
+/// the metadata holders leave next to it. An exclusive holder is reported
+/// first, otherwise the longest-standing shared one. Returns `None` if nobody
+/// holds it or the holder left no metadata. Does not touch the lock itself.
+Future<LockOwnerInfo?> lockOwnerInfo({required String path}) =>
+    RustLib.instance.api.crateApiLockManagerLockOwnerInfo(path: path);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>>
+abstract class LockHandle implements RustOpaqueInterface {
+  /// Identifies the handle in `list_held_locks`.
+  BigInt get id;
+}
+
+/// A lock held by this process, as reported by `list_held_locks`.
+class HeldLockInfo {
+  final BigInt handle;
+  final String path;
+  final bool shared;
+  final String owner;
+  final BigInt heldMillis;
+
+  const HeldLockInfo({
+    required this.handle,
+    required this.path,
+    required this.shared,
+    required this.owner,
+    required this.heldMillis,
+  });
+
+  @override
+  int get hashCode =>
+      handle.hashCode ^
+      path.hashCode ^
+      shared.hashCode ^
+      owner.hashCode ^
+      heldMillis.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HeldLockInfo &&
+          runtimeType == other.runtimeType &&
+          handle == other.handle &&
+          path == other.path &&
+          shared == other.shared &&
+          owner == other.owner &&
+          heldMillis == other.heldMillis;
+}
+
+/// Who is holding a lock file, as written by the holder when it acquired it.
+class LockOwnerInfo {
+  final int pid;
+  final String owner;
+  final bool shared;
+  final PlatformInt64 acquiredAtMillis;
+
+  const LockOwnerInfo({
+    required this.pid,
+    required this.owner,
+    required this.shared,
+    required this.acquiredAtMillis,
+  });
+
+  @override
+  int get hashCode =>
+      pid.hashCode ^
+      owner.hashCode ^
+      shared.hashCode ^
+      acquiredAtMillis.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LockOwnerInfo &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          owner == other.owner &&
+          shared == other.shared &&
+          acquiredAtMillis == other.acquiredAtMillis;
+}
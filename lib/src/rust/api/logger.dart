@@ -7,13 +7,28 @@ import '../frb_generated.dart';
 import '../lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `current_log_trace`, `record_to_entry`, `set_log_trace`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LogTraceGuard`, `SEND_TO_DART_LOGGER_STREAM_SINK`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `as_log`, `config`, `deref`, `drop`, `enabled`, `flush`, `initialize`, `level`, `log`
+// These functions are ignored because they are not marked as `pub`: `current_call_context`, `enabled`, `enter_call_context`, `level_to_i32`, `now_millis`, `push`, `record_to_entry`, `send`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CallContextGuard`, `DartSpanLayer`, `FieldFormatter`, `RecentLogs`, `SEND_TO_DART_LOGGER_STREAM_SINK`, `SpanTiming`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `as_log`, `assert_receiver_is_total_eq`, `clone`, `clone`, `config`, `deref`, `drop`, `enabled`, `eq`, `flush`, `fmt`, `initialize`, `level`, `log`, `on_close`, `on_new_span`, `on_record`, `record_debug`, `record_str`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `init_buffered_logger`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
 
 Stream<LogEntry> createLogStream() =>
     RustLib.instance.api.crateApiLoggerCreateLogStream();
 
+/// Entries still in the recent-log buffer, oldest first, that are at least
+/// `min_level`, not older than `since_millis` and, if given, from `trace_id`.
+/// Kept whether or not a log stream is attached.
+Future<List<LogEntry>> recentLogs({
+  required PlatformInt64 sinceMillis,
+  required int minLevel,
+  String? traceId,
+}) => RustLib.instance.api.crateApiLoggerRecentLogs(
+  sinceMillis: sinceMillis,
+  minLevel: minLevel,
+  traceId: traceId,
+);
+
 Future<void> rustSetUp() => RustLib.instance.api.crateApiLoggerRustSetUp();
 
 Future<void> rustShutdown() =>
@@ -31,12 +46,43 @@ abstract class SendToDartLogger implements RustOpaqueInterface {
       RustLib.instance.api.crateApiLoggerSendToDartLoggerSetStreamSink();
 }
 
+/// Where a bridge call comes from, attached to every log entry it produces.
+class CallContext {
+  final String? traceId;
+  /// What started the call, e.g. "fcm", "ui" or "background_task".
+  final String? source;
+  final String? parentSpan;
+
+  const CallContext({this.traceId, this.source, this.parentSpan});
+
+  static Future<CallContext> default_() =>
+      RustLib.instance.api.crateApiLoggerCallContextDefault();
+
+  @override
+  int get hashCode => traceId.hashCode ^ source.hashCode ^ parentSpan.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CallContext &&
+          runtimeType == other.runtimeType &&
+          traceId == other.traceId &&
+          source == other.source &&
+          parentSpan == other.parentSpan;
+}
+
 class LogEntry {
   final PlatformInt64 timeMillis;
   final int level;
   final String tag;
   final String msg;
   final String? traceId;
+  final String? source;
+  final String? parentSpan;
+  /// Set on span close entries, which report the span's start time in
+  /// `time_millis` and how long it was open in `duration_micros`.
+  final String? spanId;
+  final PlatformInt64 durationMicros;
 
   const LogEntry({
     required this.timeMillis,
@@ -44,6 +90,10 @@ class LogEntry {
     required this.tag,
     required this.msg,
     this.traceId,
+    this.source,
+    this.parentSpan,
+    this.spanId,
+    required this.durationMicros,
   });
 
   @override
@@ -52,7 +102,11 @@ class LogEntry {
       level.hashCode ^
       tag.hashCode ^
       msg.hashCode ^
-      traceId.hashCode;
+      traceId.hashCode ^
+      source.hashCode ^
+      parentSpan.hashCode ^
+      spanId.hashCode ^
+      durationMicros.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -63,5 +117,9 @@ class LogEntry {
           level == other.level &&
           tag == other.tag &&
           msg == other.msg &&
-          traceId == other.traceId;
+          traceId == other.traceId &&
+          source == other.source &&
+          parentSpan == other.parentSpan &&
+          spanId == other.spanId &&
+          durationMicros == other.durationMicros;
 }
@@ -0,0 +1,117 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `merge`, `now_millis`, `record_error`, `record`, `set_camera`, `to_metrics`, `track`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CallMetricsGuard`, `Counters`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+Future<MetricsSnapshot> getMetricsSnapshot() =>
+    RustLib.instance.api.crateApiMetricsGetMetricsSnapshot();
+
+Future<void> resetMetrics() =>
+    RustLib.instance.api.crateApiMetricsResetMetrics();
+
+class ErrorCount {
+  final String kind;
+  final BigInt count;
+
+  const ErrorCount({required this.kind, required this.count});
+
+  @override
+  int get hashCode => kind.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ErrorCount &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          count == other.count;
+}
+
+class MetricsSnapshot {
+  final PlatformInt64 sinceMillis;
+  final PlatformInt64 takenAtMillis;
+  final Uint64List latencyBucketBoundsMillis;
+  final List<OperationMetrics> byFunction;
+  final List<OperationMetrics> byCamera;
+
+  const MetricsSnapshot({
+    required this.sinceMillis,
+    required this.takenAtMillis,
+    required this.latencyBucketBoundsMillis,
+    required this.byFunction,
+    required this.byCamera,
+  });
+
+  @override
+  int get hashCode =>
+      sinceMillis.hashCode ^
+      takenAtMillis.hashCode ^
+      latencyBucketBoundsMillis.hashCode ^
+      byFunction.hashCode ^
+      byCamera.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MetricsSnapshot &&
+          runtimeType == other.runtimeType &&
+          sinceMillis == other.sinceMillis &&
+          takenAtMillis == other.takenAtMillis &&
+          latencyBucketBoundsMillis == other.latencyBucketBoundsMillis &&
+          byFunction == other.byFunction &&
+          byCamera == other.byCamera;
+}
+
+class OperationMetrics {
+  final String function;
+  /// Empty in the per-function totals.
+  final String camera;
+  final BigInt calls;
+  final List<ErrorCount> errors;
+  /// One count per bound in `MetricsSnapshot::latency_bucket_bounds_millis`,
+  /// plus a last one for slower calls.
+  final Uint64List latencyCounts;
+  final BigInt totalMicros;
+  final BigInt maxMicros;
+
+  const OperationMetrics({
+    required this.function,
+    required this.camera,
+    required this.calls,
+    required this.errors,
+    required this.latencyCounts,
+    required this.totalMicros,
+    required this.maxMicros,
+  });
+
+  @override
+  int get hashCode =>
+      function.hashCode ^
+      camera.hashCode ^
+      calls.hashCode ^
+      errors.hashCode ^
+      latencyCounts.hashCode ^
+      totalMicros.hashCode ^
+      maxMicros.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OperationMetrics &&
+          runtimeType == other.runtimeType &&
+          function == other.function &&
+          camera == other.camera &&
+          calls == other.calls &&
+          errors == other.errors &&
+          latencyCounts == other.latencyCounts &&
+          totalMicros == other.totalMicros &&
+          maxMicros == other.maxMicros;
+}
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'logger.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'notifications.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `decrypt_with_client`, `metric_kind`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `parse_notification`
+
+/// Decrypt a push notification from `camera_name` and decode what it says.
+/// Replaces `decrypt_message` for callers that want a typed result.
+Future<NotificationPayload> decryptNotification({
+  required String cameraName,
+  required String clientTag,
+  required List<int> data,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiNotificationsDecryptNotification(
+  cameraName: cameraName,
+  clientTag: clientTag,
+  data: data,
+  context: context,
+);
+
+@freezed
+sealed class NotificationError
+    with _$NotificationError
+    implements FrbException {
+  const NotificationError._();
+
+  /// The client lock was held by someone else past the timeout.
+  const factory NotificationError.busy() = NotificationError_Busy;
+  /// The client could not be created (missing init params or bad state on disk).
+  const factory NotificationError.notInitialized() =
+      NotificationError_NotInitialized;
+  /// This message was already decrypted once; FCM delivered it again. Safe to drop.
+  const factory NotificationError.duplicateMessage() =
+      NotificationError_DuplicateMessage;
+  /// Any other error reported by the native layer.
+  const factory NotificationError.failed(String field0) =
+      NotificationError_Failed;
+}
+
+@freezed
+sealed class NotificationPayload with _$NotificationPayload {
+  const NotificationPayload._();
+
+  /// The camera detected motion and is recording. `timestamp` names the
+  /// thumbnail and video that follow, so it is kept as sent.
+  const factory NotificationPayload.motion({required String timestamp}) =
+      NotificationPayload_Motion;
+  /// A motion video has been uploaded and can be downloaded.
+  const factory NotificationPayload.videoReady() =
+      NotificationPayload_VideoReady;
+  /// The message decrypted but carried nothing to act on.
+  const factory NotificationPayload.empty() = NotificationPayload_Empty;
+  /// A JSON object with a `type` key. No firmware sends these yet; `json` is
+  /// the whole decoded message.
+  const factory NotificationPayload.typed({
+    required String kind,
+    required String json,
+  }) = NotificationPayload_Typed;
+  /// The message decrypted to something this version does not understand.
+  const factory NotificationPayload.unknown({required String raw}) =
+      NotificationPayload_Unknown;
+}
@@ -0,0 +1,184 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'credentials.dart';
+import 'logger.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `advance`, `check_request`, `fail_attempt`, `forget`, `from_json`, `from_name`, `is_finished`, `missing_request`, `name`, `now_millis`, `publish`, `remove_slot`, `session_file`, `session_slot`, `step`, `to_json`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActiveSession`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Start pairing `request.camera_name`, replacing any earlier unfinished
+/// session. `initialize_camera` must have been called for it. Nothing is sent
+/// to the camera until the first `pairing_step`.
+Future<PairingSession> beginPairing({
+  required PairingRequest request,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiPairingBeginPairing(
+  request: request,
+  context: context,
+);
+
+/// Run the current phase of the pairing for `camera_name` once and return the
+/// new state. On failure the phase stays the same, so calling this again (or
+/// `resume_pairing` after a restart, then this) retries just that phase.
+///
+/// The server, not the camera, confirms the camera is online, so Dart passes
+/// what the server reports in `camera_online`; it only matters in
+/// `WaitingForCameraOnline`.
+Future<PairingSession> pairingStep({
+  required String cameraName,
+  required bool cameraOnline,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiPairingPairingStep(
+  cameraName: cameraName,
+  cameraOnline: cameraOnline,
+  context: context,
+);
+
+/// The unfinished pairing for `camera_name`, loaded from disk if this process
+/// has not seen it yet (e.g. after a restart). Sessions that have not finished
+/// their key exchange need `request` again, since it is never saved; later
+/// ones ignore it.
+Future<PairingSession> resumePairing({
+  required String cameraName,
+  PairingRequest? request,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiPairingResumePairing(
+  cameraName: cameraName,
+  request: request,
+  context: context,
+);
+
+/// Send every later state of the pairing for `camera_name` to `sink`,
+/// starting with the current one.
+Stream<PairingSession> watchPairing({
+  required String cameraName,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiPairingWatchPairing(
+  cameraName: cameraName,
+  context: context,
+);
+
+/// Abandon the pairing for `camera_name` and delete its saved state. If the key
+/// exchange already happened, the camera stays in the client state until it is
+/// deregistered.
+Future<PairingSession> cancelPairing({
+  required String cameraName,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiPairingCancelPairing(
+  cameraName: cameraName,
+  context: context,
+);
+
+enum PairingPhase {
+  /// Checking that the camera answers on its pairing port.
+  connecting,
+  /// Exchanging keys with the camera and handing over server (and Wi-Fi) credentials.
+  keyExchange,
+  /// Giving the camera time to read the Wi-Fi credentials before the phone
+  /// leaves its hotspot. Skipped when there are none.
+  wifiProvisioning,
+  /// Waiting for the server to report the camera paired.
+  waitingForCameraOnline,
+  done,
+  /// The camera refused to pair; starting over is the only option.
+  failed,
+  cancelled,
+}
+
+/// Everything `flutter_add_camera` needs, given once when pairing begins.
+class PairingRequest {
+  final String cameraName;
+  /// Any address `flutter_add_camera` accepts. It is resolved once in
+  /// `Connecting`, and the key exchange talks to the address that answered.
+  final String ip;
+  final Uint8List secret;
+  final bool standalone;
+  final String ssid;
+  final String wifiPassword;
+  final String pairingToken;
+  final ServerCredentials credentials;
+
+  const PairingRequest({
+    required this.cameraName,
+    required this.ip,
+    required this.secret,
+    required this.standalone,
+    required this.ssid,
+    required this.wifiPassword,
+    required this.pairingToken,
+    required this.credentials,
+  });
+
+  @override
+  int get hashCode =>
+      cameraName.hashCode ^
+      ip.hashCode ^
+      secret.hashCode ^
+      standalone.hashCode ^
+      ssid.hashCode ^
+      wifiPassword.hashCode ^
+      pairingToken.hashCode ^
+      credentials.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PairingRequest &&
+          runtimeType == other.runtimeType &&
+          cameraName == other.cameraName &&
+          ip == other.ip &&
+          secret == other.secret &&
+          standalone == other.standalone &&
+          ssid == other.ssid &&
+          wifiPassword == other.wifiPassword &&
+          pairingToken == other.pairingToken &&
+          credentials == other.credentials;
+}
+
+/// Where a pairing session stands, as returned by every call and sent to watchers.
+class PairingSession {
+  final String cameraName;
+  final PairingPhase phase;
+  /// Set once the key exchange succeeded.
+  final String? firmwareVersion;
+  /// Failed attempts at the current phase.
+  final int attempts;
+  final String? lastError;
+  final PlatformInt64 updatedAtMillis;
+
+  const PairingSession({
+    required this.cameraName,
+    required this.phase,
+    this.firmwareVersion,
+    required this.attempts,
+    this.lastError,
+    required this.updatedAtMillis,
+  });
+
+  @override
+  int get hashCode =>
+      cameraName.hashCode ^
+      phase.hashCode ^
+      firmwareVersion.hashCode ^
+      attempts.hashCode ^
+      lastError.hashCode ^
+      updatedAtMillis.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PairingSession &&
+          runtimeType == other.runtimeType &&
+          cameraName == other.cameraName &&
+          phase == other.phase &&
+          firmwareVersion == other.firmwareVersion &&
+          attempts == other.attempts &&
+          lastError == other.lastError &&
+          updatedAtMillis == other.updatedAtMillis;
+}
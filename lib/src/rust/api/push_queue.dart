@@ -0,0 +1,122 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'logger.dart';
+import 'notifications.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `content_hash`, `encode`, `list_entries`, `lock_queue`, `now_millis`, `order_by_generation`, `order_by_sent_time`, `queue_dir`, `read_queue`, `read`, `remove_entry`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `QueuedMessage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Store a push message for `camera_name` until the next `drain_push_messages`.
+/// `sent_at_millis` is the push service's send time (FCM `sentTime`), used to
+/// order the drain. Returns false if the same message is already queued.
+Future<bool> enqueuePushMessage({
+  required String cameraName,
+  required String clientTag,
+  required List<int> data,
+  PlatformInt64? sentAtMillis,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiPushQueueEnqueuePushMessage(
+  cameraName: cameraName,
+  clientTag: clientTag,
+  data: data,
+  sentAtMillis: sentAtMillis,
+  context: context,
+);
+
+/// Decrypt everything queued for `camera_name`, oldest generation first, and
+/// remove what was handled. Messages that fail to decrypt stay queued for the
+/// next drain, up to `MAX_DECRYPT_ATTEMPTS` tries.
+Future<PushDrainReport> drainPushMessages({
+  required String cameraName,
+  CallContext? context,
+}) => RustLib.instance.api.crateApiPushQueueDrainPushMessages(
+  cameraName: cameraName,
+  context: context,
+);
+
+class DrainedPushMessage {
+  /// Hex SHA-256 of the encrypted message, as used for deduplication.
+  final String contentHash;
+  final PlatformInt64 receivedAtMillis;
+  /// When the push service says the message was sent, if the caller knew.
+  final PlatformInt64? sentAtMillis;
+  /// Ratchet generation the message was ordered by, when the client could read it.
+  final BigInt? generation;
+  final NotificationPayload payload;
+
+  const DrainedPushMessage({
+    required this.contentHash,
+    required this.receivedAtMillis,
+    this.sentAtMillis,
+    this.generation,
+    required this.payload,
+  });
+
+  @override
+  int get hashCode =>
+      contentHash.hashCode ^
+      receivedAtMillis.hashCode ^
+      sentAtMillis.hashCode ^
+      generation.hashCode ^
+      payload.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DrainedPushMessage &&
+          runtimeType == other.runtimeType &&
+          contentHash == other.contentHash &&
+          receivedAtMillis == other.receivedAtMillis &&
+          sentAtMillis == other.sentAtMillis &&
+          generation == other.generation &&
+          payload == other.payload;
+}
+
+class PushDrainReport {
+  /// Messages that decrypted, in the order they were decrypted.
+  final List<DrainedPushMessage> messages;
+  /// Messages the client had already seen, dropped from the queue.
+  final int duplicates;
+  /// Messages dropped after failing `MAX_DECRYPT_ATTEMPTS` drains.
+  final int dropped;
+  /// Messages still queued for a later drain.
+  final int remaining;
+  /// The client lock stayed busy, so nothing was decrypted.
+  final bool busy;
+
+  const PushDrainReport({
+    required this.messages,
+    required this.duplicates,
+    required this.dropped,
+    required this.remaining,
+    required this.busy,
+  });
+
+  static Future<PushDrainReport> default_() =>
+      RustLib.instance.api.crateApiPushQueuePushDrainReportDefault();
+
+  @override
+  int get hashCode =>
+      messages.hashCode ^
+      duplicates.hashCode ^
+      dropped.hashCode ^
+      remaining.hashCode ^
+      busy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PushDrainReport &&
+          runtimeType == other.runtimeType &&
+          messages == other.messages &&
+          duplicates == other.duplicates &&
+          dropped == other.dropped &&
+          remaining == other.remaining &&
+          busy == other.busy;
+}
@@ -4,8 +4,19 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api.dart';
+import 'api/backup.dart';
+import 'api/camera_events.dart';
+import 'api/credentials.dart';
+import 'api/hotspot.dart';
+import 'api/livestream_recording.dart';
+import 'api/livestream_session.dart';
+import 'api/livestream_stats.dart';
 import 'api/lock_manager.dart';
 import 'api/logger.dart';
+import 'api/metrics.dart';
+import 'api/notifications.dart';
+import 'api/pairing.dart';
+import 'api/push_queue.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -70,7 +81,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1355579577;
+  int get rustContentHash => 444440919;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -81,6 +92,8 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  BigInt crateApiLockManagerLockHandleId({required LockHandle that});
+
   Future<SendToDartLogger> crateApiLoggerSendToDartLoggerNew({
     required LevelFilter level,
   });
@@ -89,12 +102,63 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiLockManagerAcquireLock({required String path});
 
+  Future<LockHandle> crateApiLockManagerAcquireLockAs({
+    required String path,
+    String? owner,
+  });
+
+  Future<LockHandle?> crateApiLockManagerAcquireLockWithTimeout({
+    required String path,
+    required BigInt timeoutMillis,
+    String? owner,
+  });
+
+  Future<LockHandle> crateApiLockManagerAcquireSharedLock({
+    required String path,
+    String? owner,
+  });
+
+  Future<PairingSession> crateApiPairingBeginPairing({
+    required PairingRequest request,
+    CallContext? context,
+  });
+
+  Future<CallContext> crateApiLoggerCallContextDefault();
+
+  Future<bool> crateApiHotspotCancelDeviceWait({required String waiterId});
+
+  Future<bool> crateApiLockManagerCancelLockWait({required String waiterId});
+
+  Future<PairingSession> crateApiPairingCancelPairing({
+    required String cameraName,
+    CallContext? context,
+  });
+
+  Future<LivestreamSession> crateApiLivestreamSessionCloseLivestreamSession({
+    required String cameraName,
+    required BigInt sessionId,
+    CallContext? context,
+  });
+
   Stream<LogEntry> crateApiLoggerCreateLogStream();
 
+  Future<LivestreamSession?> crateApiLivestreamSessionCurrentLivestreamSession({
+    required String cameraName,
+    CallContext? context,
+  });
+
   Future<String> crateApiDecryptMessage({
     required String clientTag,
     required String cameraName,
     required List<int> data,
+    CallContext? context,
+  });
+
+  Future<NotificationPayload> crateApiNotificationsDecryptNotification({
+    required String cameraName,
+    required String clientTag,
+    required List<int> data,
+    CallContext? context,
   });
 
   Future<String> crateApiDecryptThumbnail({
@@ -102,19 +166,72 @@ abstract class RustLibApi extends BaseApi {
     required String encFilename,
     required String pendingMetaDirectory,
     required BigInt assumedEpoch,
+    CallContext? context,
+  });
+
+  Future<DecryptedFile> crateApiDecryptThumbnailAtEpoch({
+    required String cameraName,
+    required String encFilename,
+    required String pendingMetaDirectory,
+    required BigInt assumedEpoch,
+    CallContext? context,
+  });
+
+  Future<List<DecryptOutcome>> crateApiDecryptThumbnailsBatch({
+    required String cameraName,
+    required List<String> encFilenames,
+    required String pendingMetaDirectory,
+    CallContext? context,
   });
 
   Future<String> crateApiDecryptVideo({
     required String cameraName,
     required String encFilename,
     required BigInt assumedEpoch,
+    CallContext? context,
+  });
+
+  Future<DecryptedFile> crateApiDecryptVideoAtEpoch({
+    required String cameraName,
+    required String encFilename,
+    required BigInt assumedEpoch,
+    CallContext? context,
+  });
+
+  Future<List<DecryptOutcome>> crateApiDecryptVideosBatch({
+    required String cameraName,
+    required List<String> encFilenames,
+    CallContext? context,
   });
 
-  Future<void> crateApiDeregisterCamera({required String cameraName});
+  Future<void> crateApiDeregisterCamera({
+    required String cameraName,
+    CallContext? context,
+  });
+
+  Future<PushDrainReport> crateApiPushQueueDrainPushMessages({
+    required String cameraName,
+    CallContext? context,
+  });
 
   Future<Uint8List> crateApiEncryptSettingsMessage({
     required String cameraName,
     required List<int> data,
+    CallContext? context,
+  });
+
+  Future<bool> crateApiPushQueueEnqueuePushMessage({
+    required String cameraName,
+    required String clientTag,
+    required List<int> data,
+    PlatformInt64? sentAtMillis,
+    CallContext? context,
+  });
+
+  Future<Uint8List> crateApiBackupExportCameraBackup({
+    required String cameraName,
+    required String passphrase,
+    CallContext? context,
   });
 
   Future<String> crateApiFlutterAddCamera({
@@ -125,21 +242,33 @@ abstract class RustLibApi extends BaseApi {
     required String ssid,
     required String password,
     required String pairingToken,
-    required String credentialsFull,
+    required ServerCredentials credentials,
+    CallContext? context,
   });
 
   Future<Uint8List> crateApiGenerateHeartbeatRequestConfigCommand({
     required String cameraName,
     required BigInt timestamp,
+    CallContext? context,
   });
 
   Future<String> crateApiGetGroupName({
     required String clientTag,
     required String cameraName,
+    CallContext? context,
   });
 
+  Future<MetricsSnapshot> crateApiMetricsGetMetricsSnapshot();
+
   String crateApiSimpleGreet({required String name});
 
+  Future<String> crateApiBackupImportCameraBackup({
+    required List<int> backup,
+    required String passphrase,
+    required String fileDir,
+    CallContext? context,
+  });
+
   Future<void> crateApiInitApp();
 
   Future<void> crateApiLoggerInitLogger();
@@ -148,41 +277,168 @@ abstract class RustLibApi extends BaseApi {
     required String cameraName,
     required String fileDir,
     required bool firstTime,
+    CallContext? context,
   });
 
   Future<bool> crateApiLockManagerIsLockHeld({required String path});
 
+  Future<List<HeldLockInfo>> crateApiLockManagerListHeldLocks();
+
   Future<Uint8List> crateApiLivestreamDecrypt({
     required String cameraName,
     required List<int> data,
     required BigInt expectedChunkNumber,
+    CallContext? context,
+  });
+
+  Future<Uint8List> crateApiLivestreamSessionLivestreamSessionDecrypt({
+    required String cameraName,
+    required BigInt sessionId,
+    required List<int> data,
+    required BigInt expectedChunkNumber,
+    CallContext? context,
+  });
+
+  Future<void> crateApiLivestreamSessionLivestreamSessionUpdate({
+    required String cameraName,
+    required BigInt sessionId,
+    required List<int> msg,
+    CallContext? context,
+  });
+
+  Future<LivestreamStats> crateApiLivestreamStatsLivestreamStats({
+    required String cameraName,
+    CallContext? context,
   });
 
+  Future<LivestreamStats> crateApiLivestreamStatsLivestreamStatsDefault();
+
   Future<bool> crateApiLivestreamUpdate({
     required String cameraName,
     required List<int> msg,
+    CallContext? context,
+  });
+
+  Future<LockOwnerInfo?> crateApiLockManagerLockOwnerInfo({
+    required String path,
+  });
+
+  Future<String> crateApiHotspotNewDeviceWaiter();
+
+  Future<String> crateApiLockManagerNewLockWaiter();
+
+  Future<LivestreamSession> crateApiLivestreamSessionOpenLivestreamSession({
+    required String cameraName,
+    required bool takeOver,
+    CallContext? context,
+  });
+
+  Future<PairingSession> crateApiPairingPairingStep({
+    required String cameraName,
+    required bool cameraOnline,
+    CallContext? context,
   });
 
   Future<bool> crateApiPingProprietaryDevice({required String cameraIp});
 
+  Future<bool> crateApiHotspotProbeProprietaryDevice({
+    required String address,
+    int? port,
+  });
+
   Future<String> crateApiProcessHeartbeatConfigResponse({
     required String cameraName,
     required List<int> configResponse,
     required BigInt expectedTimestamp,
+    CallContext? context,
+  });
+
+  Future<PushDrainReport> crateApiPushQueuePushDrainReportDefault();
+
+  Future<List<LogEntry>> crateApiLoggerRecentLogs({
+    required PlatformInt64 sinceMillis,
+    required int minLevel,
+    String? traceId,
   });
 
   Future<void> crateApiLockManagerReleaseLock({required String path});
 
+  Future<bool> crateApiLockManagerReleaseLockHandle({
+    required LockHandle handle,
+  });
+
+  Future<ClientReloadReport> crateApiReloadCameraClient({
+    required String cameraName,
+    CallContext? context,
+  });
+
+  Future<void> crateApiMetricsResetMetrics();
+
+  Future<PairingSession> crateApiPairingResumePairing({
+    required String cameraName,
+    PairingRequest? request,
+    CallContext? context,
+  });
+
   Future<String> crateApiRustLibVersion();
 
   Future<void> crateApiLoggerRustSetUp();
 
   Future<void> crateApiLoggerRustShutdown();
 
+  Future<void> crateApiCredentialsServerCredentialsValidate({
+    required ServerCredentials that,
+  });
+
   Future<void> crateApiShutdownApp();
 
+  Future<void> crateApiLivestreamRecordingStartLivestreamRecording({
+    required String cameraName,
+    required String outPath,
+    CallContext? context,
+  });
+
+  Future<RecordingSummary> crateApiLivestreamRecordingStopLivestreamRecording({
+    required String cameraName,
+    CallContext? context,
+  });
+
+  Stream<CameraEvent> crateApiCameraEventsSubscribeCameraEvents();
+
   Future<bool> crateApiLockManagerTryAcquireLock({required String path});
 
+  Future<LockHandle?> crateApiLockManagerTryAcquireLockAs({
+    required String path,
+    String? owner,
+  });
+
+  Future<LockHandle?> crateApiLockManagerTryAcquireSharedLock({
+    required String path,
+    String? owner,
+  });
+
+  Future<LockHandle> crateApiLockManagerWaitForLock({
+    required String path,
+    required BigInt timeoutMillis,
+    String? owner,
+    required String waiterId,
+  });
+
+  Future<WaitOutcome> crateApiHotspotWaitForProprietaryDevice({
+    required String cameraIp,
+    int? port,
+    required BigInt timeoutMillis,
+    required BigInt pollIntervalMillis,
+    required int requiredStablePolls,
+    required BigInt settleDelayMillis,
+    required String waiterId,
+  });
+
+  Stream<PairingSession> crateApiPairingWatchPairing({
+    required String cameraName,
+    CallContext? context,
+  });
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_LevelFilter;
 
@@ -191,6 +447,14 @@ abstract class RustLibApi extends BaseApi {
 
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LevelFilterPtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_LockHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_LockHandle;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LockHandlePtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_SendToDartLogger;
 
@@ -209,6 +473,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  BigInt crateApiLockManagerLockHandleId({required LockHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLockManagerLockHandleIdConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerLockHandleIdConstMeta =>
+      const TaskConstMeta(debugName: "LockHandle_id", argNames: ["that"]);
+
   @override
   Future<SendToDartLogger> crateApiLoggerSendToDartLoggerNew({
     required LevelFilter level,
@@ -224,7 +514,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 1,
+            funcId: 2,
             port: port_,
           );
         },
@@ -258,7 +548,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 2,
+              funcId: 3,
               port: port_,
             );
           },
@@ -291,7 +581,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 4,
             port: port_,
           );
         },
@@ -310,50 +600,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "acquire_lock", argNames: ["path"]);
 
   @override
-  Stream<LogEntry> crateApiLoggerCreateLogStream() {
-    final s = RustStreamSink<LogEntry>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_log_entry_Sse(s, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 4,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiLoggerCreateLogStreamConstMeta,
-          argValues: [s],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return s.stream;
-  }
-
-  TaskConstMeta get kCrateApiLoggerCreateLogStreamConstMeta =>
-      const TaskConstMeta(debugName: "create_log_stream", argNames: ["s"]);
-
-  @override
-  Future<String> crateApiDecryptMessage({
-    required String clientTag,
-    required String cameraName,
-    required List<int> data,
+  Future<LockHandle> crateApiLockManagerAcquireLockAs({
+    required String path,
+    String? owner,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(clientTag, serializer);
-          sse_encode_String(cameraName, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(owner, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -362,36 +618,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDecryptMessageConstMeta,
-        argValues: [clientTag, cameraName, data],
+        constMeta: kCrateApiLockManagerAcquireLockAsConstMeta,
+        argValues: [path, owner],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDecryptMessageConstMeta => const TaskConstMeta(
-    debugName: "decrypt_message",
-    argNames: ["clientTag", "cameraName", "data"],
-  );
+  TaskConstMeta get kCrateApiLockManagerAcquireLockAsConstMeta =>
+      const TaskConstMeta(
+        debugName: "acquire_lock_as",
+        argNames: ["path", "owner"],
+      );
 
   @override
-  Future<String> crateApiDecryptThumbnail({
-    required String cameraName,
-    required String encFilename,
-    required String pendingMetaDirectory,
-    required BigInt assumedEpoch,
+  Future<LockHandle?> crateApiLockManagerAcquireLockWithTimeout({
+    required String path,
+    required BigInt timeoutMillis,
+    String? owner,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cameraName, serializer);
-          sse_encode_String(encFilename, serializer);
-          sse_encode_String(pendingMetaDirectory, serializer);
-          sse_encode_u_64(assumedEpoch, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_u_64(timeoutMillis, serializer);
+          sse_encode_opt_String(owner, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -400,44 +656,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDecryptThumbnailConstMeta,
-        argValues: [
-          cameraName,
-          encFilename,
-          pendingMetaDirectory,
-          assumedEpoch,
-        ],
+        constMeta: kCrateApiLockManagerAcquireLockWithTimeoutConstMeta,
+        argValues: [path, timeoutMillis, owner],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDecryptThumbnailConstMeta => const TaskConstMeta(
-    debugName: "decrypt_thumbnail",
-    argNames: [
-      "cameraName",
-      "encFilename",
-      "pendingMetaDirectory",
-      "assumedEpoch",
-    ],
-  );
+  TaskConstMeta get kCrateApiLockManagerAcquireLockWithTimeoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "acquire_lock_with_timeout",
+        argNames: ["path", "timeoutMillis", "owner"],
+      );
 
   @override
-  Future<String> crateApiDecryptVideo({
-    required String cameraName,
-    required String encFilename,
-    required BigInt assumedEpoch,
+  Future<LockHandle> crateApiLockManagerAcquireSharedLock({
+    required String path,
+    String? owner,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cameraName, serializer);
-          sse_encode_String(encFilename, serializer);
-          sse_encode_u_64(assumedEpoch, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(owner, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -446,28 +692,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDecryptVideoConstMeta,
-        argValues: [cameraName, encFilename, assumedEpoch],
+        constMeta: kCrateApiLockManagerAcquireSharedLockConstMeta,
+        argValues: [path, owner],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDecryptVideoConstMeta => const TaskConstMeta(
-    debugName: "decrypt_video",
-    argNames: ["cameraName", "encFilename", "assumedEpoch"],
-  );
+  TaskConstMeta get kCrateApiLockManagerAcquireSharedLockConstMeta =>
+      const TaskConstMeta(
+        debugName: "acquire_shared_lock",
+        argNames: ["path", "owner"],
+      );
 
   @override
-  Future<void> crateApiDeregisterCamera({required String cameraName}) {
+  Future<PairingSession> crateApiPairingBeginPairing({
+    required PairingRequest request,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cameraName, serializer);
+          sse_encode_box_autoadd_pairing_request(request, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -476,32 +728,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_pairing_session,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDeregisterCameraConstMeta,
-        argValues: [cameraName],
+        constMeta: kCrateApiPairingBeginPairingConstMeta,
+        argValues: [request, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDeregisterCameraConstMeta => const TaskConstMeta(
-    debugName: "deregister_camera",
-    argNames: ["cameraName"],
-  );
+  TaskConstMeta get kCrateApiPairingBeginPairingConstMeta =>
+      const TaskConstMeta(
+        debugName: "begin_pairing",
+        argNames: ["request", "context"],
+      );
 
   @override
-  Future<Uint8List> crateApiEncryptSettingsMessage({
-    required String cameraName,
-    required List<int> data,
-  }) {
+  Future<CallContext> crateApiLoggerCallContextDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cameraName, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -510,45 +758,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_call_context,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiEncryptSettingsMessageConstMeta,
-        argValues: [cameraName, data],
+        constMeta: kCrateApiLoggerCallContextDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEncryptSettingsMessageConstMeta =>
-      const TaskConstMeta(
-        debugName: "encrypt_settings_message",
-        argNames: ["cameraName", "data"],
-      );
+  TaskConstMeta get kCrateApiLoggerCallContextDefaultConstMeta =>
+      const TaskConstMeta(debugName: "call_context_default", argNames: []);
 
   @override
-  Future<String> crateApiFlutterAddCamera({
-    required String cameraName,
-    required String ip,
-    required List<int> secret,
-    required bool standalone,
-    required String ssid,
-    required String password,
-    required String pairingToken,
-    required String credentialsFull,
-  }) {
+  Future<bool> crateApiHotspotCancelDeviceWait({required String waiterId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cameraName, serializer);
-          sse_encode_String(ip, serializer);
-          sse_encode_list_prim_u_8_loose(secret, serializer);
-          sse_encode_bool(standalone, serializer);
-          sse_encode_String(ssid, serializer);
-          sse_encode_String(password, serializer);
-          sse_encode_String(pairingToken, serializer);
-          sse_encode_String(credentialsFull, serializer);
+          sse_encode_String(waiterId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -557,50 +786,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiFlutterAddCameraConstMeta,
-        argValues: [
-          cameraName,
-          ip,
-          secret,
-          standalone,
-          ssid,
-          password,
-          pairingToken,
-          credentialsFull,
-        ],
+        constMeta: kCrateApiHotspotCancelDeviceWaitConstMeta,
+        argValues: [waiterId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiFlutterAddCameraConstMeta => const TaskConstMeta(
-    debugName: "flutter_add_camera",
-    argNames: [
-      "cameraName",
-      "ip",
-      "secret",
-      "standalone",
-      "ssid",
-      "password",
-      "pairingToken",
-      "credentialsFull",
-    ],
-  );
+  TaskConstMeta get kCrateApiHotspotCancelDeviceWaitConstMeta =>
+      const TaskConstMeta(
+        debugName: "cancel_device_wait",
+        argNames: ["waiterId"],
+      );
 
   @override
-  Future<Uint8List> crateApiGenerateHeartbeatRequestConfigCommand({
-    required String cameraName,
-    required BigInt timestamp,
-  }) {
+  Future<bool> crateApiLockManagerCancelLockWait({required String waiterId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cameraName, serializer);
-          sse_encode_u_64(timestamp, serializer);
+          sse_encode_String(waiterId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -609,33 +817,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiGenerateHeartbeatRequestConfigCommandConstMeta,
-        argValues: [cameraName, timestamp],
+        constMeta: kCrateApiLockManagerCancelLockWaitConstMeta,
+        argValues: [waiterId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGenerateHeartbeatRequestConfigCommandConstMeta =>
+  TaskConstMeta get kCrateApiLockManagerCancelLockWaitConstMeta =>
       const TaskConstMeta(
-        debugName: "generate_heartbeat_request_config_command",
-        argNames: ["cameraName", "timestamp"],
+        debugName: "cancel_lock_wait",
+        argNames: ["waiterId"],
       );
 
   @override
-  Future<String> crateApiGetGroupName({
-    required String clientTag,
+  Future<PairingSession> crateApiPairingCancelPairing({
     required String cameraName,
+    CallContext? context,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(clientTag, serializer);
           sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -644,77 +852,102 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetGroupNameConstMeta,
-        argValues: [clientTag, cameraName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetGroupNameConstMeta => const TaskConstMeta(
-    debugName: "get_group_name",
-    argNames: ["clientTag", "cameraName"],
-  );
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_pairing_session,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiPairingCancelPairingConstMeta,
+        argValues: [cameraName, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiPairingCancelPairingConstMeta =>
+      const TaskConstMeta(
+        debugName: "cancel_pairing",
+        argNames: ["cameraName", "context"],
+      );
 
   @override
-  Future<void> crateApiInitApp() {
+  Future<LivestreamSession> crateApiLivestreamSessionCloseLivestreamSession({
+    required String cameraName,
+    required BigInt sessionId,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_u_64(sessionId, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 13,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_livestream_session,
+          decodeErrorData: sse_decode_livestream_error,
         ),
-        constMeta: kCrateApiInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiLivestreamSessionCloseLivestreamSessionConstMeta,
+        argValues: [cameraName, sessionId, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiLivestreamSessionCloseLivestreamSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "close_livestream_session",
+        argNames: ["cameraName", "sessionId", "context"],
+      );
 
   @override
-  Future<void> crateApiLoggerInitLogger() {
+  Stream<LogEntry> crateApiLoggerCreateLogStream() {
+    final s = RustStreamSink<LogEntry>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_log_entry_Sse(s, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 14,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiLoggerCreateLogStreamConstMeta,
+          argValues: [s],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return s.stream;
+  }
+
+  TaskConstMeta get kCrateApiLoggerCreateLogStreamConstMeta =>
+      const TaskConstMeta(debugName: "create_log_stream", argNames: ["s"]);
+
+  @override
+  Future<LivestreamSession?> crateApiLivestreamSessionCurrentLivestreamSession({
+    required String cameraName,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -723,32 +956,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_livestream_session,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiLoggerInitLoggerConstMeta,
-        argValues: [],
+        constMeta: kCrateApiLivestreamSessionCurrentLivestreamSessionConstMeta,
+        argValues: [cameraName, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerInitLoggerConstMeta =>
-      const TaskConstMeta(debugName: "init_logger", argNames: []);
+  TaskConstMeta
+  get kCrateApiLivestreamSessionCurrentLivestreamSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "current_livestream_session",
+        argNames: ["cameraName", "context"],
+      );
 
   @override
-  Future<bool> crateApiInitializeCamera({
+  Future<String> crateApiDecryptMessage({
+    required String clientTag,
     required String cameraName,
-    required String fileDir,
-    required bool firstTime,
+    required List<int> data,
+    CallContext? context,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(clientTag, serializer);
           sse_encode_String(cameraName, serializer);
-          sse_encode_String(fileDir, serializer);
-          sse_encode_bool(firstTime, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -757,28 +996,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitializeCameraConstMeta,
-        argValues: [cameraName, fileDir, firstTime],
+        constMeta: kCrateApiDecryptMessageConstMeta,
+        argValues: [clientTag, cameraName, data, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitializeCameraConstMeta => const TaskConstMeta(
-    debugName: "initialize_camera",
-    argNames: ["cameraName", "fileDir", "firstTime"],
+  TaskConstMeta get kCrateApiDecryptMessageConstMeta => const TaskConstMeta(
+    debugName: "decrypt_message",
+    argNames: ["clientTag", "cameraName", "data", "context"],
   );
 
   @override
-  Future<bool> crateApiLockManagerIsLockHeld({required String path}) {
+  Future<NotificationPayload> crateApiNotificationsDecryptNotification({
+    required String cameraName,
+    required String clientTag,
+    required List<int> data,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_String(clientTag, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -787,32 +1034,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_notification_payload,
+          decodeErrorData: sse_decode_notification_error,
         ),
-        constMeta: kCrateApiLockManagerIsLockHeldConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiNotificationsDecryptNotificationConstMeta,
+        argValues: [cameraName, clientTag, data, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLockManagerIsLockHeldConstMeta =>
-      const TaskConstMeta(debugName: "is_lock_held", argNames: ["path"]);
+  TaskConstMeta get kCrateApiNotificationsDecryptNotificationConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypt_notification",
+        argNames: ["cameraName", "clientTag", "data", "context"],
+      );
 
   @override
-  Future<Uint8List> crateApiLivestreamDecrypt({
+  Future<String> crateApiDecryptThumbnail({
     required String cameraName,
-    required List<int> data,
-    required BigInt expectedChunkNumber,
+    required String encFilename,
+    required String pendingMetaDirectory,
+    required BigInt assumedEpoch,
+    CallContext? context,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(cameraName, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          sse_encode_u_64(expectedChunkNumber, serializer);
+          sse_encode_String(encFilename, serializer);
+          sse_encode_String(pendingMetaDirectory, serializer);
+          sse_encode_u_64(assumedEpoch, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -821,32 +1075,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiLivestreamDecryptConstMeta,
-        argValues: [cameraName, data, expectedChunkNumber],
+        constMeta: kCrateApiDecryptThumbnailConstMeta,
+        argValues: [
+          cameraName,
+          encFilename,
+          pendingMetaDirectory,
+          assumedEpoch,
+          context,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLivestreamDecryptConstMeta => const TaskConstMeta(
-    debugName: "livestream_decrypt",
-    argNames: ["cameraName", "data", "expectedChunkNumber"],
+  TaskConstMeta get kCrateApiDecryptThumbnailConstMeta => const TaskConstMeta(
+    debugName: "decrypt_thumbnail",
+    argNames: [
+      "cameraName",
+      "encFilename",
+      "pendingMetaDirectory",
+      "assumedEpoch",
+      "context",
+    ],
   );
 
   @override
-  Future<bool> crateApiLivestreamUpdate({
+  Future<DecryptedFile> crateApiDecryptThumbnailAtEpoch({
     required String cameraName,
-    required List<int> msg,
+    required String encFilename,
+    required String pendingMetaDirectory,
+    required BigInt assumedEpoch,
+    CallContext? context,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(cameraName, serializer);
-          sse_encode_list_prim_u_8_loose(msg, serializer);
+          sse_encode_String(encFilename, serializer);
+          sse_encode_String(pendingMetaDirectory, serializer);
+          sse_encode_u_64(assumedEpoch, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -855,28 +1127,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_decrypted_file,
+          decodeErrorData: sse_decode_decrypt_error,
         ),
-        constMeta: kCrateApiLivestreamUpdateConstMeta,
-        argValues: [cameraName, msg],
+        constMeta: kCrateApiDecryptThumbnailAtEpochConstMeta,
+        argValues: [
+          cameraName,
+          encFilename,
+          pendingMetaDirectory,
+          assumedEpoch,
+          context,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLivestreamUpdateConstMeta => const TaskConstMeta(
-    debugName: "livestream_update",
-    argNames: ["cameraName", "msg"],
-  );
+  TaskConstMeta get kCrateApiDecryptThumbnailAtEpochConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypt_thumbnail_at_epoch",
+        argNames: [
+          "cameraName",
+          "encFilename",
+          "pendingMetaDirectory",
+          "assumedEpoch",
+          "context",
+        ],
+      );
 
   @override
-  Future<bool> crateApiPingProprietaryDevice({required String cameraIp}) {
+  Future<List<DecryptOutcome>> crateApiDecryptThumbnailsBatch({
+    required String cameraName,
+    required List<String> encFilenames,
+    required String pendingMetaDirectory,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(cameraIp, serializer);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_list_String(encFilenames, serializer);
+          sse_encode_String(pendingMetaDirectory, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -885,35 +1178,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_list_decrypt_outcome,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiPingProprietaryDeviceConstMeta,
-        argValues: [cameraIp],
+        constMeta: kCrateApiDecryptThumbnailsBatchConstMeta,
+        argValues: [cameraName, encFilenames, pendingMetaDirectory, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPingProprietaryDeviceConstMeta =>
+  TaskConstMeta get kCrateApiDecryptThumbnailsBatchConstMeta =>
       const TaskConstMeta(
-        debugName: "ping_proprietary_device",
-        argNames: ["cameraIp"],
+        debugName: "decrypt_thumbnails_batch",
+        argNames: [
+          "cameraName",
+          "encFilenames",
+          "pendingMetaDirectory",
+          "context",
+        ],
       );
 
   @override
-  Future<String> crateApiProcessHeartbeatConfigResponse({
+  Future<String> crateApiDecryptVideo({
     required String cameraName,
-    required List<int> configResponse,
-    required BigInt expectedTimestamp,
+    required String encFilename,
+    required BigInt assumedEpoch,
+    CallContext? context,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(cameraName, serializer);
-          sse_encode_list_prim_u_8_loose(configResponse, serializer);
-          sse_encode_u_64(expectedTimestamp, serializer);
+          sse_encode_String(encFilename, serializer);
+          sse_encode_u_64(assumedEpoch, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -925,26 +1225,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiProcessHeartbeatConfigResponseConstMeta,
-        argValues: [cameraName, configResponse, expectedTimestamp],
+        constMeta: kCrateApiDecryptVideoConstMeta,
+        argValues: [cameraName, encFilename, assumedEpoch, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProcessHeartbeatConfigResponseConstMeta =>
-      const TaskConstMeta(
-        debugName: "process_heartbeat_config_response",
-        argNames: ["cameraName", "configResponse", "expectedTimestamp"],
-      );
+  TaskConstMeta get kCrateApiDecryptVideoConstMeta => const TaskConstMeta(
+    debugName: "decrypt_video",
+    argNames: ["cameraName", "encFilename", "assumedEpoch", "context"],
+  );
 
   @override
-  Future<void> crateApiLockManagerReleaseLock({required String path}) {
+  Future<DecryptedFile> crateApiDecryptVideoAtEpoch({
+    required String cameraName,
+    required String encFilename,
+    required BigInt assumedEpoch,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_String(encFilename, serializer);
+          sse_encode_u_64(assumedEpoch, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -953,25 +1260,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_decrypted_file,
+          decodeErrorData: sse_decode_decrypt_error,
         ),
-        constMeta: kCrateApiLockManagerReleaseLockConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiDecryptVideoAtEpochConstMeta,
+        argValues: [cameraName, encFilename, assumedEpoch, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLockManagerReleaseLockConstMeta =>
-      const TaskConstMeta(debugName: "release_lock", argNames: ["path"]);
+  TaskConstMeta get kCrateApiDecryptVideoAtEpochConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypt_video_at_epoch",
+        argNames: ["cameraName", "encFilename", "assumedEpoch", "context"],
+      );
 
   @override
-  Future<String> crateApiRustLibVersion() {
+  Future<List<DecryptOutcome>> crateApiDecryptVideosBatch({
+    required String cameraName,
+    required List<String> encFilenames,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_list_String(encFilenames, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -980,25 +1297,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_decrypt_outcome,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiRustLibVersionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDecryptVideosBatchConstMeta,
+        argValues: [cameraName, encFilenames, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRustLibVersionConstMeta =>
-      const TaskConstMeta(debugName: "rust_lib_version", argNames: []);
+  TaskConstMeta get kCrateApiDecryptVideosBatchConstMeta => const TaskConstMeta(
+    debugName: "decrypt_videos_batch",
+    argNames: ["cameraName", "encFilenames", "context"],
+  );
 
   @override
-  Future<void> crateApiLoggerRustSetUp() {
+  Future<void> crateApiDeregisterCamera({
+    required String cameraName,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1010,22 +1334,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiLoggerRustSetUpConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDeregisterCameraConstMeta,
+        argValues: [cameraName, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerRustSetUpConstMeta =>
-      const TaskConstMeta(debugName: "rust_set_up", argNames: []);
+  TaskConstMeta get kCrateApiDeregisterCameraConstMeta => const TaskConstMeta(
+    debugName: "deregister_camera",
+    argNames: ["cameraName", "context"],
+  );
 
   @override
-  Future<void> crateApiLoggerRustShutdown() {
+  Future<PushDrainReport> crateApiPushQueueDrainPushMessages({
+    required String cameraName,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1034,25 +1365,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_push_drain_report,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiLoggerRustShutdownConstMeta,
-        argValues: [],
+        constMeta: kCrateApiPushQueueDrainPushMessagesConstMeta,
+        argValues: [cameraName, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerRustShutdownConstMeta =>
-      const TaskConstMeta(debugName: "rust_shutdown", argNames: []);
+  TaskConstMeta get kCrateApiPushQueueDrainPushMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "drain_push_messages",
+        argNames: ["cameraName", "context"],
+      );
 
   @override
-  Future<void> crateApiShutdownApp() {
+  Future<Uint8List> crateApiEncryptSettingsMessage({
+    required String cameraName,
+    required List<int> data,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1061,26 +1402,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiShutdownAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiEncryptSettingsMessageConstMeta,
+        argValues: [cameraName, data, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiShutdownAppConstMeta =>
-      const TaskConstMeta(debugName: "shutdown_app", argNames: []);
+  TaskConstMeta get kCrateApiEncryptSettingsMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "encrypt_settings_message",
+        argNames: ["cameraName", "data", "context"],
+      );
 
   @override
-  Future<bool> crateApiLockManagerTryAcquireLock({required String path}) {
+  Future<bool> crateApiPushQueueEnqueuePushMessage({
+    required String cameraName,
+    required String clientTag,
+    required List<int> data,
+    PlatformInt64? sentAtMillis,
+    CallContext? context,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_String(clientTag, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_opt_box_autoadd_i_64(sentAtMillis, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1092,456 +1446,4307 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiLockManagerTryAcquireLockConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiPushQueueEnqueuePushMessageConstMeta,
+        argValues: [cameraName, clientTag, data, sentAtMillis, context],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLockManagerTryAcquireLockConstMeta =>
-      const TaskConstMeta(debugName: "try_acquire_lock", argNames: ["path"]);
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_LevelFilter =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_LevelFilter =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SendToDartLogger =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SendToDartLogger =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger;
+  TaskConstMeta get kCrateApiPushQueueEnqueuePushMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "enqueue_push_message",
+        argNames: [
+          "cameraName",
+          "clientTag",
+          "data",
+          "sentAtMillis",
+          "context",
+        ],
+      );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<Uint8List> crateApiBackupExportCameraBackup({
+    required String cameraName,
+    required String passphrase,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_String(passphrase, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBackupExportCameraBackupConstMeta,
+        argValues: [cameraName, passphrase, context],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  LevelFilter
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LevelFilterImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
+  TaskConstMeta get kCrateApiBackupExportCameraBackupConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_camera_backup",
+        argNames: ["cameraName", "passphrase", "context"],
+      );
 
-  @protected
-  SendToDartLogger
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SendToDartLoggerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  @override
+  Future<String> crateApiFlutterAddCamera({
+    required String cameraName,
+    required String ip,
+    required List<int> secret,
+    required bool standalone,
+    required String ssid,
+    required String password,
+    required String pairingToken,
+    required ServerCredentials credentials,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_String(ip, serializer);
+          sse_encode_list_prim_u_8_loose(secret, serializer);
+          sse_encode_bool(standalone, serializer);
+          sse_encode_String(ssid, serializer);
+          sse_encode_String(password, serializer);
+          sse_encode_String(pairingToken, serializer);
+          sse_encode_box_autoadd_server_credentials(credentials, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiFlutterAddCameraConstMeta,
+        argValues: [
+          cameraName,
+          ip,
+          secret,
+          standalone,
+          ssid,
+          password,
+          pairingToken,
+          credentials,
+          context,
+        ],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  LevelFilter
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LevelFilterImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
+  TaskConstMeta get kCrateApiFlutterAddCameraConstMeta => const TaskConstMeta(
+    debugName: "flutter_add_camera",
+    argNames: [
+      "cameraName",
+      "ip",
+      "secret",
+      "standalone",
+      "ssid",
+      "password",
+      "pairingToken",
+      "credentials",
+      "context",
+    ],
+  );
 
-  @protected
-  SendToDartLogger
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SendToDartLoggerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  @override
+  Future<Uint8List> crateApiGenerateHeartbeatRequestConfigCommand({
+    required String cameraName,
+    required BigInt timestamp,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_u_64(timestamp, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGenerateHeartbeatRequestConfigCommandConstMeta,
+        argValues: [cameraName, timestamp, context],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
+  TaskConstMeta get kCrateApiGenerateHeartbeatRequestConfigCommandConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_heartbeat_request_config_command",
+        argNames: ["cameraName", "timestamp", "context"],
+      );
+
+  @override
+  Future<String> crateApiGetGroupName({
+    required String clientTag,
+    required String cameraName,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(clientTag, serializer);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetGroupNameConstMeta,
+        argValues: [clientTag, cameraName, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetGroupNameConstMeta => const TaskConstMeta(
+    debugName: "get_group_name",
+    argNames: ["clientTag", "cameraName", "context"],
+  );
+
+  @override
+  Future<MetricsSnapshot> crateApiMetricsGetMetricsSnapshot() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_metrics_snapshot,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMetricsGetMetricsSnapshotConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetricsGetMetricsSnapshotConstMeta =>
+      const TaskConstMeta(debugName: "get_metrics_snapshot", argNames: []);
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<String> crateApiBackupImportCameraBackup({
+    required List<int> backup,
+    required String passphrase,
+    required String fileDir,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(backup, serializer);
+          sse_encode_String(passphrase, serializer);
+          sse_encode_String(fileDir, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBackupImportCameraBackupConstMeta,
+        argValues: [backup, passphrase, fileDir, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupImportCameraBackupConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_camera_backup",
+        argNames: ["backup", "passphrase", "fileDir", "context"],
+      );
+
+  @override
+  Future<void> crateApiInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiLoggerInitLogger() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggerInitLoggerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerInitLoggerConstMeta =>
+      const TaskConstMeta(debugName: "init_logger", argNames: []);
+
+  @override
+  Future<bool> crateApiInitializeCamera({
+    required String cameraName,
+    required String fileDir,
+    required bool firstTime,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_String(fileDir, serializer);
+          sse_encode_bool(firstTime, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitializeCameraConstMeta,
+        argValues: [cameraName, fileDir, firstTime, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitializeCameraConstMeta => const TaskConstMeta(
+    debugName: "initialize_camera",
+    argNames: ["cameraName", "fileDir", "firstTime", "context"],
+  );
+
+  @override
+  Future<bool> crateApiLockManagerIsLockHeld({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLockManagerIsLockHeldConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerIsLockHeldConstMeta =>
+      const TaskConstMeta(debugName: "is_lock_held", argNames: ["path"]);
+
+  @override
+  Future<List<HeldLockInfo>> crateApiLockManagerListHeldLocks() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_held_lock_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLockManagerListHeldLocksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerListHeldLocksConstMeta =>
+      const TaskConstMeta(debugName: "list_held_locks", argNames: []);
+
+  @override
+  Future<Uint8List> crateApiLivestreamDecrypt({
+    required String cameraName,
+    required List<int> data,
+    required BigInt expectedChunkNumber,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_u_64(expectedChunkNumber, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLivestreamDecryptConstMeta,
+        argValues: [cameraName, data, expectedChunkNumber, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLivestreamDecryptConstMeta => const TaskConstMeta(
+    debugName: "livestream_decrypt",
+    argNames: ["cameraName", "data", "expectedChunkNumber", "context"],
+  );
+
+  @override
+  Future<Uint8List> crateApiLivestreamSessionLivestreamSessionDecrypt({
+    required String cameraName,
+    required BigInt sessionId,
+    required List<int> data,
+    required BigInt expectedChunkNumber,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_u_64(sessionId, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_u_64(expectedChunkNumber, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_livestream_error,
+        ),
+        constMeta: kCrateApiLivestreamSessionLivestreamSessionDecryptConstMeta,
+        argValues: [cameraName, sessionId, data, expectedChunkNumber, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiLivestreamSessionLivestreamSessionDecryptConstMeta =>
+      const TaskConstMeta(
+        debugName: "livestream_session_decrypt",
+        argNames: [
+          "cameraName",
+          "sessionId",
+          "data",
+          "expectedChunkNumber",
+          "context",
+        ],
+      );
+
+  @override
+  Future<void> crateApiLivestreamSessionLivestreamSessionUpdate({
+    required String cameraName,
+    required BigInt sessionId,
+    required List<int> msg,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_u_64(sessionId, serializer);
+          sse_encode_list_prim_u_8_loose(msg, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_livestream_error,
+        ),
+        constMeta: kCrateApiLivestreamSessionLivestreamSessionUpdateConstMeta,
+        argValues: [cameraName, sessionId, msg, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiLivestreamSessionLivestreamSessionUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "livestream_session_update",
+        argNames: ["cameraName", "sessionId", "msg", "context"],
+      );
+
+  @override
+  Future<LivestreamStats> crateApiLivestreamStatsLivestreamStats({
+    required String cameraName,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_livestream_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLivestreamStatsLivestreamStatsConstMeta,
+        argValues: [cameraName, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLivestreamStatsLivestreamStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "livestream_stats",
+        argNames: ["cameraName", "context"],
+      );
+
+  @override
+  Future<LivestreamStats> crateApiLivestreamStatsLivestreamStatsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_livestream_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLivestreamStatsLivestreamStatsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLivestreamStatsLivestreamStatsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "livestream_stats_default", argNames: []);
+
+  @override
+  Future<bool> crateApiLivestreamUpdate({
+    required String cameraName,
+    required List<int> msg,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_list_prim_u_8_loose(msg, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLivestreamUpdateConstMeta,
+        argValues: [cameraName, msg, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLivestreamUpdateConstMeta => const TaskConstMeta(
+    debugName: "livestream_update",
+    argNames: ["cameraName", "msg", "context"],
+  );
+
+  @override
+  Future<LockOwnerInfo?> crateApiLockManagerLockOwnerInfo({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lock_owner_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLockManagerLockOwnerInfoConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerLockOwnerInfoConstMeta =>
+      const TaskConstMeta(debugName: "lock_owner_info", argNames: ["path"]);
+
+  @override
+  Future<String> crateApiHotspotNewDeviceWaiter() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHotspotNewDeviceWaiterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHotspotNewDeviceWaiterConstMeta =>
+      const TaskConstMeta(debugName: "new_device_waiter", argNames: []);
+
+  @override
+  Future<String> crateApiLockManagerNewLockWaiter() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLockManagerNewLockWaiterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerNewLockWaiterConstMeta =>
+      const TaskConstMeta(debugName: "new_lock_waiter", argNames: []);
+
+  @override
+  Future<LivestreamSession> crateApiLivestreamSessionOpenLivestreamSession({
+    required String cameraName,
+    required bool takeOver,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_bool(takeOver, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_livestream_session,
+          decodeErrorData: sse_decode_livestream_error,
+        ),
+        constMeta: kCrateApiLivestreamSessionOpenLivestreamSessionConstMeta,
+        argValues: [cameraName, takeOver, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLivestreamSessionOpenLivestreamSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_livestream_session",
+        argNames: ["cameraName", "takeOver", "context"],
+      );
+
+  @override
+  Future<PairingSession> crateApiPairingPairingStep({
+    required String cameraName,
+    required bool cameraOnline,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_bool(cameraOnline, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_pairing_session,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPairingPairingStepConstMeta,
+        argValues: [cameraName, cameraOnline, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPairingPairingStepConstMeta => const TaskConstMeta(
+    debugName: "pairing_step",
+    argNames: ["cameraName", "cameraOnline", "context"],
+  );
+
+  @override
+  Future<bool> crateApiPingProprietaryDevice({required String cameraIp}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraIp, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPingProprietaryDeviceConstMeta,
+        argValues: [cameraIp],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPingProprietaryDeviceConstMeta =>
+      const TaskConstMeta(
+        debugName: "ping_proprietary_device",
+        argNames: ["cameraIp"],
+      );
+
+  @override
+  Future<bool> crateApiHotspotProbeProprietaryDevice({
+    required String address,
+    int? port,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(address, serializer);
+          sse_encode_opt_box_autoadd_u_16(port, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiHotspotProbeProprietaryDeviceConstMeta,
+        argValues: [address, port],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHotspotProbeProprietaryDeviceConstMeta =>
+      const TaskConstMeta(
+        debugName: "probe_proprietary_device",
+        argNames: ["address", "port"],
+      );
+
+  @override
+  Future<String> crateApiProcessHeartbeatConfigResponse({
+    required String cameraName,
+    required List<int> configResponse,
+    required BigInt expectedTimestamp,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_list_prim_u_8_loose(configResponse, serializer);
+          sse_encode_u_64(expectedTimestamp, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProcessHeartbeatConfigResponseConstMeta,
+        argValues: [cameraName, configResponse, expectedTimestamp, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProcessHeartbeatConfigResponseConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_heartbeat_config_response",
+        argNames: [
+          "cameraName",
+          "configResponse",
+          "expectedTimestamp",
+          "context",
+        ],
+      );
+
+  @override
+  Future<PushDrainReport> crateApiPushQueuePushDrainReportDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_push_drain_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPushQueuePushDrainReportDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPushQueuePushDrainReportDefaultConstMeta =>
+      const TaskConstMeta(debugName: "push_drain_report_default", argNames: []);
+
+  @override
+  Future<List<LogEntry>> crateApiLoggerRecentLogs({
+    required PlatformInt64 sinceMillis,
+    required int minLevel,
+    String? traceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sinceMillis, serializer);
+          sse_encode_i_32(minLevel, serializer);
+          sse_encode_opt_String(traceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_log_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggerRecentLogsConstMeta,
+        argValues: [sinceMillis, minLevel, traceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerRecentLogsConstMeta => const TaskConstMeta(
+    debugName: "recent_logs",
+    argNames: ["sinceMillis", "minLevel", "traceId"],
+  );
+
+  @override
+  Future<void> crateApiLockManagerReleaseLock({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLockManagerReleaseLockConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerReleaseLockConstMeta =>
+      const TaskConstMeta(debugName: "release_lock", argNames: ["path"]);
+
+  @override
+  Future<bool> crateApiLockManagerReleaseLockHandle({
+    required LockHandle handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+            handle,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLockManagerReleaseLockHandleConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerReleaseLockHandleConstMeta =>
+      const TaskConstMeta(
+        debugName: "release_lock_handle",
+        argNames: ["handle"],
+      );
+
+  @override
+  Future<ClientReloadReport> crateApiReloadCameraClient({
+    required String cameraName,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_client_reload_report,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiReloadCameraClientConstMeta,
+        argValues: [cameraName, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReloadCameraClientConstMeta => const TaskConstMeta(
+    debugName: "reload_camera_client",
+    argNames: ["cameraName", "context"],
+  );
+
+  @override
+  Future<void> crateApiMetricsResetMetrics() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMetricsResetMetricsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetricsResetMetricsConstMeta =>
+      const TaskConstMeta(debugName: "reset_metrics", argNames: []);
+
+  @override
+  Future<PairingSession> crateApiPairingResumePairing({
+    required String cameraName,
+    PairingRequest? request,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_pairing_request(request, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_pairing_session,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPairingResumePairingConstMeta,
+        argValues: [cameraName, request, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPairingResumePairingConstMeta =>
+      const TaskConstMeta(
+        debugName: "resume_pairing",
+        argNames: ["cameraName", "request", "context"],
+      );
+
+  @override
+  Future<String> crateApiRustLibVersion() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRustLibVersionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRustLibVersionConstMeta =>
+      const TaskConstMeta(debugName: "rust_lib_version", argNames: []);
+
+  @override
+  Future<void> crateApiLoggerRustSetUp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggerRustSetUpConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerRustSetUpConstMeta =>
+      const TaskConstMeta(debugName: "rust_set_up", argNames: []);
+
+  @override
+  Future<void> crateApiLoggerRustShutdown() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLoggerRustShutdownConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerRustShutdownConstMeta =>
+      const TaskConstMeta(debugName: "rust_shutdown", argNames: []);
+
+  @override
+  Future<void> crateApiCredentialsServerCredentialsValidate({
+    required ServerCredentials that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_server_credentials(that, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialsServerCredentialsValidateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialsServerCredentialsValidateConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_credentials_validate",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiShutdownApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiShutdownAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiShutdownAppConstMeta =>
+      const TaskConstMeta(debugName: "shutdown_app", argNames: []);
+
+  @override
+  Future<void> crateApiLivestreamRecordingStartLivestreamRecording({
+    required String cameraName,
+    required String outPath,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_String(outPath, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiLivestreamRecordingStartLivestreamRecordingConstMeta,
+        argValues: [cameraName, outPath, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiLivestreamRecordingStartLivestreamRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_livestream_recording",
+        argNames: ["cameraName", "outPath", "context"],
+      );
+
+  @override
+  Future<RecordingSummary> crateApiLivestreamRecordingStopLivestreamRecording({
+    required String cameraName,
+    CallContext? context,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraName, serializer);
+          sse_encode_opt_box_autoadd_call_context(context, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_recording_summary,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLivestreamRecordingStopLivestreamRecordingConstMeta,
+        argValues: [cameraName, context],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiLivestreamRecordingStopLivestreamRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_livestream_recording",
+        argNames: ["cameraName", "context"],
+      );
+
+  @override
+  Stream<CameraEvent> crateApiCameraEventsSubscribeCameraEvents() {
+    final sink = RustStreamSink<CameraEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_camera_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 68,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCameraEventsSubscribeCameraEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCameraEventsSubscribeCameraEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_camera_events",
+        argNames: ["sink"],
+      );
+
+  @override
+  Future<bool> crateApiLockManagerTryAcquireLock({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLockManagerTryAcquireLockConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerTryAcquireLockConstMeta =>
+      const TaskConstMeta(debugName: "try_acquire_lock", argNames: ["path"]);
+
+  @override
+  Future<LockHandle?> crateApiLockManagerTryAcquireLockAs({
+    required String path,
+    String? owner,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(owner, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLockManagerTryAcquireLockAsConstMeta,
+        argValues: [path, owner],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerTryAcquireLockAsConstMeta =>
+      const TaskConstMeta(
+        debugName: "try_acquire_lock_as",
+        argNames: ["path", "owner"],
+      );
+
+  @override
+  Future<LockHandle?> crateApiLockManagerTryAcquireSharedLock({
+    required String path,
+    String? owner,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(owner, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLockManagerTryAcquireSharedLockConstMeta,
+        argValues: [path, owner],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerTryAcquireSharedLockConstMeta =>
+      const TaskConstMeta(
+        debugName: "try_acquire_shared_lock",
+        argNames: ["path", "owner"],
+      );
+
+  @override
+  Future<LockHandle> crateApiLockManagerWaitForLock({
+    required String path,
+    required BigInt timeoutMillis,
+    String? owner,
+    required String waiterId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_u_64(timeoutMillis, serializer);
+          sse_encode_opt_String(owner, serializer);
+          sse_encode_String(waiterId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLockManagerWaitForLockConstMeta,
+        argValues: [path, timeoutMillis, owner, waiterId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLockManagerWaitForLockConstMeta =>
+      const TaskConstMeta(
+        debugName: "wait_for_lock",
+        argNames: ["path", "timeoutMillis", "owner", "waiterId"],
+      );
+
+  @override
+  Future<WaitOutcome> crateApiHotspotWaitForProprietaryDevice({
+    required String cameraIp,
+    int? port,
+    required BigInt timeoutMillis,
+    required BigInt pollIntervalMillis,
+    required int requiredStablePolls,
+    required BigInt settleDelayMillis,
+    required String waiterId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cameraIp, serializer);
+          sse_encode_opt_box_autoadd_u_16(port, serializer);
+          sse_encode_u_64(timeoutMillis, serializer);
+          sse_encode_u_64(pollIntervalMillis, serializer);
+          sse_encode_u_32(requiredStablePolls, serializer);
+          sse_encode_u_64(settleDelayMillis, serializer);
+          sse_encode_String(waiterId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_wait_outcome,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiHotspotWaitForProprietaryDeviceConstMeta,
+        argValues: [
+          cameraIp,
+          port,
+          timeoutMillis,
+          pollIntervalMillis,
+          requiredStablePolls,
+          settleDelayMillis,
+          waiterId,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHotspotWaitForProprietaryDeviceConstMeta =>
+      const TaskConstMeta(
+        debugName: "wait_for_proprietary_device",
+        argNames: [
+          "cameraIp",
+          "port",
+          "timeoutMillis",
+          "pollIntervalMillis",
+          "requiredStablePolls",
+          "settleDelayMillis",
+          "waiterId",
+        ],
+      );
+
+  @override
+  Stream<PairingSession> crateApiPairingWatchPairing({
+    required String cameraName,
+    CallContext? context,
+  }) {
+    final sink = RustStreamSink<PairingSession>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(cameraName, serializer);
+            sse_encode_StreamSink_pairing_session_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_call_context(context, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 74,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiPairingWatchPairingConstMeta,
+          argValues: [cameraName, sink, context],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiPairingWatchPairingConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_pairing",
+        argNames: ["cameraName", "sink", "context"],
+      );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_LevelFilter =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_LevelFilter =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_LockHandle =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_LockHandle =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SendToDartLogger =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SendToDartLogger =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  LevelFilter
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LevelFilterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LockHandle
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LockHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SendToDartLogger
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SendToDartLoggerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LockHandle
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LockHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LevelFilter
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LevelFilterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LockHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LockHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SendToDartLogger
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SendToDartLoggerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<CameraEvent> dco_decode_StreamSink_camera_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
   RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<PairingSession> dco_decode_StreamSink_pairing_session_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  LockHandle
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+      raw,
+    );
+  }
+
+  @protected
+  CallContext dco_decode_box_autoadd_call_context(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_call_context(raw);
+  }
+
+  @protected
+  DecryptError dco_decode_box_autoadd_decrypt_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_decrypt_error(raw);
+  }
+
+  @protected
+  DecryptedFile dco_decode_box_autoadd_decrypted_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_decrypted_file(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  LivestreamSession dco_decode_box_autoadd_livestream_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_livestream_session(raw);
+  }
+
+  @protected
+  LockOwnerInfo dco_decode_box_autoadd_lock_owner_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_lock_owner_info(raw);
+  }
+
+  @protected
+  PairingRequest dco_decode_box_autoadd_pairing_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pairing_request(raw);
+  }
+
+  @protected
+  ServerCredentials dco_decode_box_autoadd_server_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_server_credentials(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  CallContext dco_decode_call_context(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CallContext(
+      traceId: dco_decode_opt_String(arr[0]),
+      source: dco_decode_opt_String(arr[1]),
+      parentSpan: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  CameraEvent dco_decode_camera_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CameraEvent(
+      timeMillis: dco_decode_i_64(arr[0]),
+      cameraName: dco_decode_String(arr[1]),
+      kind: dco_decode_camera_event_kind(arr[2]),
+      detail: dco_decode_opt_String(arr[3]),
+      traceId: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  CameraEventKind dco_decode_camera_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CameraEventKind.values[raw as int];
+  }
+
+  @protected
+  ClientReloadReport dco_decode_client_reload_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ClientReloadReport(
+      wasLoaded: dco_decode_bool(arr[0]),
+      consistent: dco_decode_bool(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  DecryptError dco_decode_decrypt_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DecryptError_Busy();
+      case 1:
+        return DecryptError_NotInitialized();
+      case 2:
+        return DecryptError_EpochMismatch(
+          expected: dco_decode_opt_box_autoadd_u_64(raw[1]),
+          found: dco_decode_opt_box_autoadd_u_64(raw[2]),
+        );
+      case 3:
+        return DecryptError_Failed(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DecryptOutcome dco_decode_decrypt_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DecryptOutcome_Decrypted(
+          dco_decode_box_autoadd_decrypted_file(raw[1]),
+        );
+      case 1:
+        return DecryptOutcome_Failed(
+          dco_decode_box_autoadd_decrypt_error(raw[1]),
+        );
+      case 2:
+        return DecryptOutcome_Skipped();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DecryptedFile dco_decode_decrypted_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DecryptedFile(
+      filename: dco_decode_String(arr[0]),
+      epoch: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      caughtUp: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  DrainedPushMessage dco_decode_drained_push_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DrainedPushMessage(
+      contentHash: dco_decode_String(arr[0]),
+      receivedAtMillis: dco_decode_i_64(arr[1]),
+      sentAtMillis: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      generation: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      payload: dco_decode_notification_payload(arr[4]),
+    );
+  }
+
+  @protected
+  ErrorCount dco_decode_error_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ErrorCount(
+      kind: dco_decode_String(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  HeldLockInfo dco_decode_held_lock_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return HeldLockInfo(
+      handle: dco_decode_u_64(arr[0]),
+      path: dco_decode_String(arr[1]),
+      shared: dco_decode_bool(arr[2]),
+      owner: dco_decode_String(arr[3]),
+      heldMillis: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<DecryptOutcome> dco_decode_list_decrypt_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_decrypt_outcome).toList();
+  }
+
+  @protected
+  List<DrainedPushMessage> dco_decode_list_drained_push_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_drained_push_message).toList();
+  }
+
+  @protected
+  List<ErrorCount> dco_decode_list_error_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_error_count).toList();
+  }
+
+  @protected
+  List<HeldLockInfo> dco_decode_list_held_lock_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_held_lock_info).toList();
+  }
+
+  @protected
+  List<LogEntry> dco_decode_list_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_log_entry).toList();
+  }
+
+  @protected
+  List<OperationMetrics> dco_decode_list_operation_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_operation_metrics).toList();
+  }
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeUint64List(raw);
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  LivestreamError dco_decode_livestream_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return LivestreamError_Busy();
+      case 1:
+        return LivestreamError_NotInitialized();
+      case 2:
+        return LivestreamError_NoSession(sessionId: dco_decode_u_64(raw[1]));
+      case 3:
+        return LivestreamError_AlreadyOpen(sessionId: dco_decode_u_64(raw[1]));
+      case 4:
+        return LivestreamError_SessionMismatch(
+          expected: dco_decode_u_64(raw[1]),
+          got: dco_decode_u_64(raw[2]),
+        );
+      case 5:
+        return LivestreamError_SessionClosed(
+          sessionId: dco_decode_u_64(raw[1]),
+        );
+      case 6:
+        return LivestreamError_AwaitingKeyUpdate();
+      case 7:
+        return LivestreamError_EpochMismatch(
+          expected: dco_decode_opt_box_autoadd_u_64(raw[1]),
+          found: dco_decode_opt_box_autoadd_u_64(raw[2]),
+        );
+      case 8:
+        return LivestreamError_Failed(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  LivestreamSession dco_decode_livestream_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LivestreamSession(
+      sessionId: dco_decode_u_64(arr[0]),
+      cameraName: dco_decode_String(arr[1]),
+      openedAtMillis: dco_decode_i_64(arr[2]),
+      keyUpdated: dco_decode_bool(arr[3]),
+      chunksDecrypted: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  LivestreamStats dco_decode_livestream_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return LivestreamStats(
+      sessionStartedAtMillis: dco_decode_opt_box_autoadd_i_64(arr[0]),
+      updateFailures: dco_decode_u_64(arr[1]),
+      chunksReceived: dco_decode_u_64(arr[2]),
+      chunksDecrypted: dco_decode_u_64(arr[3]),
+      chunksFailed: dco_decode_u_64(arr[4]),
+      chunksOutOfOrder: dco_decode_u_64(arr[5]),
+      bytesReceived: dco_decode_u_64(arr[6]),
+      bytesDecrypted: dco_decode_u_64(arr[7]),
+      lastChunkNumber: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      lastChunkAtMillis: dco_decode_opt_box_autoadd_i_64(arr[9]),
+      decryptP50Micros: dco_decode_u_64(arr[10]),
+      decryptP90Micros: dco_decode_u_64(arr[11]),
+      decryptP99Micros: dco_decode_u_64(arr[12]),
+      decryptMaxMicros: dco_decode_u_64(arr[13]),
+      lockWaitTotalMicros: dco_decode_u_64(arr[14]),
+      lockWaitMaxMicros: dco_decode_u_64(arr[15]),
+      lockTimeouts: dco_decode_u_64(arr[16]),
+    );
+  }
+
+  @protected
+  LockOwnerInfo dco_decode_lock_owner_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LockOwnerInfo(
+      pid: dco_decode_u_32(arr[0]),
+      owner: dco_decode_String(arr[1]),
+      shared: dco_decode_bool(arr[2]),
+      acquiredAtMillis: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return LogEntry(
+      timeMillis: dco_decode_i_64(arr[0]),
+      level: dco_decode_i_32(arr[1]),
+      tag: dco_decode_String(arr[2]),
+      msg: dco_decode_String(arr[3]),
+      traceId: dco_decode_opt_String(arr[4]),
+      source: dco_decode_opt_String(arr[5]),
+      parentSpan: dco_decode_opt_String(arr[6]),
+      spanId: dco_decode_opt_String(arr[7]),
+      durationMicros: dco_decode_i_64(arr[8]),
+    );
+  }
+
+  @protected
+  MetricsSnapshot dco_decode_metrics_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MetricsSnapshot(
+      sinceMillis: dco_decode_i_64(arr[0]),
+      takenAtMillis: dco_decode_i_64(arr[1]),
+      latencyBucketBoundsMillis: dco_decode_list_prim_u_64_strict(arr[2]),
+      byFunction: dco_decode_list_operation_metrics(arr[3]),
+      byCamera: dco_decode_list_operation_metrics(arr[4]),
+    );
+  }
+
+  @protected
+  NotificationError dco_decode_notification_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return NotificationError_Busy();
+      case 1:
+        return NotificationError_NotInitialized();
+      case 2:
+        return NotificationError_DuplicateMessage();
+      case 3:
+        return NotificationError_Failed(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  NotificationPayload dco_decode_notification_payload(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return NotificationPayload_Motion(timestamp: dco_decode_String(raw[1]));
+      case 1:
+        return NotificationPayload_VideoReady();
+      case 2:
+        return NotificationPayload_Empty();
+      case 3:
+        return NotificationPayload_Typed(
+          kind: dco_decode_String(raw[1]),
+          json: dco_decode_String(raw[2]),
+        );
+      case 4:
+        return NotificationPayload_Unknown(raw: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  OperationMetrics dco_decode_operation_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return OperationMetrics(
+      function: dco_decode_String(arr[0]),
+      camera: dco_decode_String(arr[1]),
+      calls: dco_decode_u_64(arr[2]),
+      errors: dco_decode_list_error_count(arr[3]),
+      latencyCounts: dco_decode_list_prim_u_64_strict(arr[4]),
+      totalMicros: dco_decode_u_64(arr[5]),
+      maxMicros: dco_decode_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  LockHandle?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+            raw,
+          );
+  }
+
+  @protected
+  CallContext? dco_decode_opt_box_autoadd_call_context(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_call_context(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  LivestreamSession? dco_decode_opt_box_autoadd_livestream_session(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_livestream_session(raw);
+  }
+
+  @protected
+  LockOwnerInfo? dco_decode_opt_box_autoadd_lock_owner_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_lock_owner_info(raw);
+  }
+
+  @protected
+  PairingRequest? dco_decode_opt_box_autoadd_pairing_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pairing_request(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  PairingPhase dco_decode_pairing_phase(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PairingPhase.values[raw as int];
+  }
+
+  @protected
+  PairingRequest dco_decode_pairing_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PairingRequest(
+      cameraName: dco_decode_String(arr[0]),
+      ip: dco_decode_String(arr[1]),
+      secret: dco_decode_list_prim_u_8_strict(arr[2]),
+      standalone: dco_decode_bool(arr[3]),
+      ssid: dco_decode_String(arr[4]),
+      wifiPassword: dco_decode_String(arr[5]),
+      pairingToken: dco_decode_String(arr[6]),
+      credentials: dco_decode_server_credentials(arr[7]),
+    );
+  }
+
+  @protected
+  PairingSession dco_decode_pairing_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PairingSession(
+      cameraName: dco_decode_String(arr[0]),
+      phase: dco_decode_pairing_phase(arr[1]),
+      firmwareVersion: dco_decode_opt_String(arr[2]),
+      attempts: dco_decode_u_32(arr[3]),
+      lastError: dco_decode_opt_String(arr[4]),
+      updatedAtMillis: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  PushDrainReport dco_decode_push_drain_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PushDrainReport(
+      messages: dco_decode_list_drained_push_message(arr[0]),
+      duplicates: dco_decode_u_32(arr[1]),
+      dropped: dco_decode_u_32(arr[2]),
+      remaining: dco_decode_u_32(arr[3]),
+      busy: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  RecordingSummary dco_decode_recording_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RecordingSummary(
+      path: dco_decode_String(arr[0]),
+      fragments: dco_decode_u_32(arr[1]),
+      frames: dco_decode_u_32(arr[2]),
+      durationMillis: dco_decode_u_64(arr[3]),
+      bytes: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  ServerCredentials dco_decode_server_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ServerCredentials(
+      address: dco_decode_String(arr[0]),
+      username: dco_decode_String(arr[1]),
+      password: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WaitOutcome dco_decode_wait_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WaitOutcome(
+      status: dco_decode_wait_status(arr[0]),
+      attempts: dco_decode_u_32(arr[1]),
+      stablePolls: dco_decode_u_32(arr[2]),
+      probeLatenciesMicros: dco_decode_list_prim_u_64_strict(arr[3]),
+      elapsedMillis: dco_decode_u_64(arr[4]),
+      lastError: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  WaitStatus dco_decode_wait_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WaitStatus.values[raw as int];
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  LevelFilter
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LevelFilterImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LockHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LockHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  SendToDartLogger
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SendToDartLoggerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LockHandle
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LockHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LevelFilter
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LevelFilterImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LockHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LockHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  SendToDartLogger
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SendToDartLoggerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<CameraEvent> sse_decode_StreamSink_camera_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<PairingSession> sse_decode_StreamSink_pairing_session_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  LockHandle
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+      deserializer,
+    ));
+  }
+
+  @protected
+  CallContext sse_decode_box_autoadd_call_context(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_call_context(deserializer));
+  }
+
+  @protected
+  DecryptError sse_decode_box_autoadd_decrypt_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_decrypt_error(deserializer));
+  }
+
+  @protected
+  DecryptedFile sse_decode_box_autoadd_decrypted_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_decrypted_file(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  LivestreamSession sse_decode_box_autoadd_livestream_session(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_livestream_session(deserializer));
+  }
+
+  @protected
+  LockOwnerInfo sse_decode_box_autoadd_lock_owner_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_lock_owner_info(deserializer));
+  }
+
+  @protected
+  PairingRequest sse_decode_box_autoadd_pairing_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pairing_request(deserializer));
+  }
+
+  @protected
+  ServerCredentials sse_decode_box_autoadd_server_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_server_credentials(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  CallContext sse_decode_call_context(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_traceId = sse_decode_opt_String(deserializer);
+    var var_source = sse_decode_opt_String(deserializer);
+    var var_parentSpan = sse_decode_opt_String(deserializer);
+    return CallContext(
+      traceId: var_traceId,
+      source: var_source,
+      parentSpan: var_parentSpan,
+    );
+  }
+
+  @protected
+  CameraEvent sse_decode_camera_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeMillis = sse_decode_i_64(deserializer);
+    var var_cameraName = sse_decode_String(deserializer);
+    var var_kind = sse_decode_camera_event_kind(deserializer);
+    var var_detail = sse_decode_opt_String(deserializer);
+    var var_traceId = sse_decode_opt_String(deserializer);
+    return CameraEvent(
+      timeMillis: var_timeMillis,
+      cameraName: var_cameraName,
+      kind: var_kind,
+      detail: var_detail,
+      traceId: var_traceId,
+    );
+  }
+
+  @protected
+  CameraEventKind sse_decode_camera_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CameraEventKind.values[inner];
+  }
+
+  @protected
+  ClientReloadReport sse_decode_client_reload_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_wasLoaded = sse_decode_bool(deserializer);
+    var var_consistent = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ClientReloadReport(
+      wasLoaded: var_wasLoaded,
+      consistent: var_consistent,
+      error: var_error,
+    );
+  }
+
+  @protected
+  DecryptError sse_decode_decrypt_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return DecryptError_Busy();
+      case 1:
+        return DecryptError_NotInitialized();
+      case 2:
+        var var_expected = sse_decode_opt_box_autoadd_u_64(deserializer);
+        var var_found = sse_decode_opt_box_autoadd_u_64(deserializer);
+        return DecryptError_EpochMismatch(
+          expected: var_expected,
+          found: var_found,
+        );
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return DecryptError_Failed(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DecryptOutcome sse_decode_decrypt_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_decrypted_file(deserializer);
+        return DecryptOutcome_Decrypted(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_decrypt_error(deserializer);
+        return DecryptOutcome_Failed(var_field0);
+      case 2:
+        return DecryptOutcome_Skipped();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DecryptedFile sse_decode_decrypted_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filename = sse_decode_String(deserializer);
+    var var_epoch = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_caughtUp = sse_decode_bool(deserializer);
+    return DecryptedFile(
+      filename: var_filename,
+      epoch: var_epoch,
+      caughtUp: var_caughtUp,
+    );
+  }
+
+  @protected
+  DrainedPushMessage sse_decode_drained_push_message(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_contentHash = sse_decode_String(deserializer);
+    var var_receivedAtMillis = sse_decode_i_64(deserializer);
+    var var_sentAtMillis = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_generation = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_payload = sse_decode_notification_payload(deserializer);
+    return DrainedPushMessage(
+      contentHash: var_contentHash,
+      receivedAtMillis: var_receivedAtMillis,
+      sentAtMillis: var_sentAtMillis,
+      generation: var_generation,
+      payload: var_payload,
+    );
+  }
+
+  @protected
+  ErrorCount sse_decode_error_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return ErrorCount(kind: var_kind, count: var_count);
+  }
+
+  @protected
+  HeldLockInfo sse_decode_held_lock_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_handle = sse_decode_u_64(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_shared = sse_decode_bool(deserializer);
+    var var_owner = sse_decode_String(deserializer);
+    var var_heldMillis = sse_decode_u_64(deserializer);
+    return HeldLockInfo(
+      handle: var_handle,
+      path: var_path,
+      shared: var_shared,
+      owner: var_owner,
+      heldMillis: var_heldMillis,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DecryptOutcome> sse_decode_list_decrypt_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DecryptOutcome>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_decrypt_outcome(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DrainedPushMessage> sse_decode_list_drained_push_message(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DrainedPushMessage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_drained_push_message(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ErrorCount> sse_decode_list_error_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ErrorCount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_error_count(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HeldLockInfo> sse_decode_list_held_lock_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HeldLockInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_held_lock_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LogEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_log_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OperationMetrics> sse_decode_list_operation_metrics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OperationMetrics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_operation_metrics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  LivestreamError sse_decode_livestream_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return LivestreamError_Busy();
+      case 1:
+        return LivestreamError_NotInitialized();
+      case 2:
+        var var_sessionId = sse_decode_u_64(deserializer);
+        return LivestreamError_NoSession(sessionId: var_sessionId);
+      case 3:
+        var var_sessionId = sse_decode_u_64(deserializer);
+        return LivestreamError_AlreadyOpen(sessionId: var_sessionId);
+      case 4:
+        var var_expected = sse_decode_u_64(deserializer);
+        var var_got = sse_decode_u_64(deserializer);
+        return LivestreamError_SessionMismatch(
+          expected: var_expected,
+          got: var_got,
+        );
+      case 5:
+        var var_sessionId = sse_decode_u_64(deserializer);
+        return LivestreamError_SessionClosed(sessionId: var_sessionId);
+      case 6:
+        return LivestreamError_AwaitingKeyUpdate();
+      case 7:
+        var var_expected = sse_decode_opt_box_autoadd_u_64(deserializer);
+        var var_found = sse_decode_opt_box_autoadd_u_64(deserializer);
+        return LivestreamError_EpochMismatch(
+          expected: var_expected,
+          found: var_found,
+        );
+      case 8:
+        var var_field0 = sse_decode_String(deserializer);
+        return LivestreamError_Failed(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  LivestreamSession sse_decode_livestream_session(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionId = sse_decode_u_64(deserializer);
+    var var_cameraName = sse_decode_String(deserializer);
+    var var_openedAtMillis = sse_decode_i_64(deserializer);
+    var var_keyUpdated = sse_decode_bool(deserializer);
+    var var_chunksDecrypted = sse_decode_u_64(deserializer);
+    return LivestreamSession(
+      sessionId: var_sessionId,
+      cameraName: var_cameraName,
+      openedAtMillis: var_openedAtMillis,
+      keyUpdated: var_keyUpdated,
+      chunksDecrypted: var_chunksDecrypted,
+    );
+  }
+
+  @protected
+  LivestreamStats sse_decode_livestream_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionStartedAtMillis = sse_decode_opt_box_autoadd_i_64(
+      deserializer,
+    );
+    var var_updateFailures = sse_decode_u_64(deserializer);
+    var var_chunksReceived = sse_decode_u_64(deserializer);
+    var var_chunksDecrypted = sse_decode_u_64(deserializer);
+    var var_chunksFailed = sse_decode_u_64(deserializer);
+    var var_chunksOutOfOrder = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_bytesDecrypted = sse_decode_u_64(deserializer);
+    var var_lastChunkNumber = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_lastChunkAtMillis = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_decryptP50Micros = sse_decode_u_64(deserializer);
+    var var_decryptP90Micros = sse_decode_u_64(deserializer);
+    var var_decryptP99Micros = sse_decode_u_64(deserializer);
+    var var_decryptMaxMicros = sse_decode_u_64(deserializer);
+    var var_lockWaitTotalMicros = sse_decode_u_64(deserializer);
+    var var_lockWaitMaxMicros = sse_decode_u_64(deserializer);
+    var var_lockTimeouts = sse_decode_u_64(deserializer);
+    return LivestreamStats(
+      sessionStartedAtMillis: var_sessionStartedAtMillis,
+      updateFailures: var_updateFailures,
+      chunksReceived: var_chunksReceived,
+      chunksDecrypted: var_chunksDecrypted,
+      chunksFailed: var_chunksFailed,
+      chunksOutOfOrder: var_chunksOutOfOrder,
+      bytesReceived: var_bytesReceived,
+      bytesDecrypted: var_bytesDecrypted,
+      lastChunkNumber: var_lastChunkNumber,
+      lastChunkAtMillis: var_lastChunkAtMillis,
+      decryptP50Micros: var_decryptP50Micros,
+      decryptP90Micros: var_decryptP90Micros,
+      decryptP99Micros: var_decryptP99Micros,
+      decryptMaxMicros: var_decryptMaxMicros,
+      lockWaitTotalMicros: var_lockWaitTotalMicros,
+      lockWaitMaxMicros: var_lockWaitMaxMicros,
+      lockTimeouts: var_lockTimeouts,
+    );
+  }
+
+  @protected
+  LockOwnerInfo sse_decode_lock_owner_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_owner = sse_decode_String(deserializer);
+    var var_shared = sse_decode_bool(deserializer);
+    var var_acquiredAtMillis = sse_decode_i_64(deserializer);
+    return LockOwnerInfo(
+      pid: var_pid,
+      owner: var_owner,
+      shared: var_shared,
+      acquiredAtMillis: var_acquiredAtMillis,
+    );
+  }
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeMillis = sse_decode_i_64(deserializer);
+    var var_level = sse_decode_i_32(deserializer);
+    var var_tag = sse_decode_String(deserializer);
+    var var_msg = sse_decode_String(deserializer);
+    var var_traceId = sse_decode_opt_String(deserializer);
+    var var_source = sse_decode_opt_String(deserializer);
+    var var_parentSpan = sse_decode_opt_String(deserializer);
+    var var_spanId = sse_decode_opt_String(deserializer);
+    var var_durationMicros = sse_decode_i_64(deserializer);
+    return LogEntry(
+      timeMillis: var_timeMillis,
+      level: var_level,
+      tag: var_tag,
+      msg: var_msg,
+      traceId: var_traceId,
+      source: var_source,
+      parentSpan: var_parentSpan,
+      spanId: var_spanId,
+      durationMicros: var_durationMicros,
+    );
+  }
+
+  @protected
+  MetricsSnapshot sse_decode_metrics_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sinceMillis = sse_decode_i_64(deserializer);
+    var var_takenAtMillis = sse_decode_i_64(deserializer);
+    var var_latencyBucketBoundsMillis = sse_decode_list_prim_u_64_strict(
+      deserializer,
+    );
+    var var_byFunction = sse_decode_list_operation_metrics(deserializer);
+    var var_byCamera = sse_decode_list_operation_metrics(deserializer);
+    return MetricsSnapshot(
+      sinceMillis: var_sinceMillis,
+      takenAtMillis: var_takenAtMillis,
+      latencyBucketBoundsMillis: var_latencyBucketBoundsMillis,
+      byFunction: var_byFunction,
+      byCamera: var_byCamera,
+    );
+  }
+
+  @protected
+  NotificationError sse_decode_notification_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return NotificationError_Busy();
+      case 1:
+        return NotificationError_NotInitialized();
+      case 2:
+        return NotificationError_DuplicateMessage();
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return NotificationError_Failed(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  NotificationPayload sse_decode_notification_payload(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_timestamp = sse_decode_String(deserializer);
+        return NotificationPayload_Motion(timestamp: var_timestamp);
+      case 1:
+        return NotificationPayload_VideoReady();
+      case 2:
+        return NotificationPayload_Empty();
+      case 3:
+        var var_kind = sse_decode_String(deserializer);
+        var var_json = sse_decode_String(deserializer);
+        return NotificationPayload_Typed(kind: var_kind, json: var_json);
+      case 4:
+        var var_raw = sse_decode_String(deserializer);
+        return NotificationPayload_Unknown(raw: var_raw);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  OperationMetrics sse_decode_operation_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_function = sse_decode_String(deserializer);
+    var var_camera = sse_decode_String(deserializer);
+    var var_calls = sse_decode_u_64(deserializer);
+    var var_errors = sse_decode_list_error_count(deserializer);
+    var var_latencyCounts = sse_decode_list_prim_u_64_strict(deserializer);
+    var var_totalMicros = sse_decode_u_64(deserializer);
+    var var_maxMicros = sse_decode_u_64(deserializer);
+    return OperationMetrics(
+      function: var_function,
+      camera: var_camera,
+      calls: var_calls,
+      errors: var_errors,
+      latencyCounts: var_latencyCounts,
+      totalMicros: var_totalMicros,
+      maxMicros: var_maxMicros,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LockHandle?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CallContext? sse_decode_opt_box_autoadd_call_context(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_call_context(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LivestreamSession? sse_decode_opt_box_autoadd_livestream_session(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_livestream_session(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LockOwnerInfo? sse_decode_opt_box_autoadd_lock_owner_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_lock_owner_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PairingRequest? sse_decode_opt_box_autoadd_pairing_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pairing_request(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PairingPhase sse_decode_pairing_phase(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PairingPhase.values[inner];
+  }
+
+  @protected
+  PairingRequest sse_decode_pairing_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cameraName = sse_decode_String(deserializer);
+    var var_ip = sse_decode_String(deserializer);
+    var var_secret = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_standalone = sse_decode_bool(deserializer);
+    var var_ssid = sse_decode_String(deserializer);
+    var var_wifiPassword = sse_decode_String(deserializer);
+    var var_pairingToken = sse_decode_String(deserializer);
+    var var_credentials = sse_decode_server_credentials(deserializer);
+    return PairingRequest(
+      cameraName: var_cameraName,
+      ip: var_ip,
+      secret: var_secret,
+      standalone: var_standalone,
+      ssid: var_ssid,
+      wifiPassword: var_wifiPassword,
+      pairingToken: var_pairingToken,
+      credentials: var_credentials,
+    );
+  }
+
+  @protected
+  PairingSession sse_decode_pairing_session(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cameraName = sse_decode_String(deserializer);
+    var var_phase = sse_decode_pairing_phase(deserializer);
+    var var_firmwareVersion = sse_decode_opt_String(deserializer);
+    var var_attempts = sse_decode_u_32(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    var var_updatedAtMillis = sse_decode_i_64(deserializer);
+    return PairingSession(
+      cameraName: var_cameraName,
+      phase: var_phase,
+      firmwareVersion: var_firmwareVersion,
+      attempts: var_attempts,
+      lastError: var_lastError,
+      updatedAtMillis: var_updatedAtMillis,
+    );
+  }
+
+  @protected
+  PushDrainReport sse_decode_push_drain_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_messages = sse_decode_list_drained_push_message(deserializer);
+    var var_duplicates = sse_decode_u_32(deserializer);
+    var var_dropped = sse_decode_u_32(deserializer);
+    var var_remaining = sse_decode_u_32(deserializer);
+    var var_busy = sse_decode_bool(deserializer);
+    return PushDrainReport(
+      messages: var_messages,
+      duplicates: var_duplicates,
+      dropped: var_dropped,
+      remaining: var_remaining,
+      busy: var_busy,
+    );
+  }
+
+  @protected
+  RecordingSummary sse_decode_recording_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_fragments = sse_decode_u_32(deserializer);
+    var var_frames = sse_decode_u_32(deserializer);
+    var var_durationMillis = sse_decode_u_64(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    return RecordingSummary(
+      path: var_path,
+      fragments: var_fragments,
+      frames: var_frames,
+      durationMillis: var_durationMillis,
+      bytes: var_bytes,
+    );
+  }
+
+  @protected
+  ServerCredentials sse_decode_server_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    return ServerCredentials(
+      address: var_address,
+      username: var_username,
+      password: var_password,
+    );
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WaitOutcome sse_decode_wait_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_wait_status(deserializer);
+    var var_attempts = sse_decode_u_32(deserializer);
+    var var_stablePolls = sse_decode_u_32(deserializer);
+    var var_probeLatenciesMicros = sse_decode_list_prim_u_64_strict(
+      deserializer,
+    );
+    var var_elapsedMillis = sse_decode_u_64(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    return WaitOutcome(
+      status: var_status,
+      attempts: var_attempts,
+      stablePolls: var_stablePolls,
+      probeLatenciesMicros: var_probeLatenciesMicros,
+      elapsedMillis: var_elapsedMillis,
+      lastError: var_lastError,
+    );
+  }
+
+  @protected
+  WaitStatus sse_decode_wait_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WaitStatus.values[inner];
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    LevelFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LevelFilterImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LockHandleImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    SendToDartLogger self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SendToDartLoggerImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LockHandleImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    LevelFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LevelFilterImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LockHandleImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    SendToDartLogger self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SendToDartLoggerImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void sse_encode_StreamSink_camera_event_Sse(
+    RustStreamSink<CameraEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_camera_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_StreamSink_log_entry_Sse(
+    RustStreamSink<LogEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_StreamSink_pairing_session_Sse(
+    RustStreamSink<PairingSession> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_pairing_session,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  LogEntry dco_decode_log_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return LogEntry(
-      timeMillis: dco_decode_i_64(arr[0]),
-      level: dco_decode_i_32(arr[1]),
-      tag: dco_decode_String(arr[2]),
-      msg: dco_decode_String(arr[3]),
-      traceId: dco_decode_opt_String(arr[4]),
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+      self,
+      serializer,
     );
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_box_autoadd_call_context(
+    CallContext self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_call_context(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_decrypt_error(
+    DecryptError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_decrypt_error(self, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_decrypted_file(
+    DecryptedFile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_decrypted_file(self, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_livestream_session(
+    LivestreamSession self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_livestream_session(self, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_lock_owner_info(
+    LockOwnerInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_lock_owner_info(self, serializer);
   }
 
   @protected
-  LevelFilter
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_pairing_request(
+    PairingRequest self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return LevelFilterImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_pairing_request(self, serializer);
   }
 
   @protected
-  SendToDartLogger
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_server_credentials(
+    ServerCredentials self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_server_credentials(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_call_context(CallContext self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.traceId, serializer);
+    sse_encode_opt_String(self.source, serializer);
+    sse_encode_opt_String(self.parentSpan, serializer);
+  }
+
+  @protected
+  void sse_encode_camera_event(CameraEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.timeMillis, serializer);
+    sse_encode_String(self.cameraName, serializer);
+    sse_encode_camera_event_kind(self.kind, serializer);
+    sse_encode_opt_String(self.detail, serializer);
+    sse_encode_opt_String(self.traceId, serializer);
+  }
+
+  @protected
+  void sse_encode_camera_event_kind(
+    CameraEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_client_reload_report(
+    ClientReloadReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.wasLoaded, serializer);
+    sse_encode_bool(self.consistent, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_decrypt_error(DecryptError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DecryptError_Busy():
+        sse_encode_i_32(0, serializer);
+      case DecryptError_NotInitialized():
+        sse_encode_i_32(1, serializer);
+      case DecryptError_EpochMismatch(expected: final expected, found: final found):
+        sse_encode_i_32(2, serializer);
+        sse_encode_opt_box_autoadd_u_64(expected, serializer);
+        sse_encode_opt_box_autoadd_u_64(found, serializer);
+      case DecryptError_Failed(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_decrypt_outcome(
+    DecryptOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DecryptOutcome_Decrypted(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_decrypted_file(field0, serializer);
+      case DecryptOutcome_Failed(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_decrypt_error(field0, serializer);
+      case DecryptOutcome_Skipped():
+        sse_encode_i_32(2, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_decrypted_file(DecryptedFile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.filename, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.epoch, serializer);
+    sse_encode_bool(self.caughtUp, serializer);
+  }
+
+  @protected
+  void sse_encode_drained_push_message(
+    DrainedPushMessage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.contentHash, serializer);
+    sse_encode_i_64(self.receivedAtMillis, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.sentAtMillis, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.generation, serializer);
+    sse_encode_notification_payload(self.payload, serializer);
+  }
+
+  @protected
+  void sse_encode_error_count(ErrorCount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_u_64(self.count, serializer);
+  }
+
+  @protected
+  void sse_encode_held_lock_info(HeldLockInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.handle, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.shared, serializer);
+    sse_encode_String(self.owner, serializer);
+    sse_encode_u_64(self.heldMillis, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_decrypt_outcome(
+    List<DecryptOutcome> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_decrypt_outcome(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_drained_push_message(
+    List<DrainedPushMessage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_drained_push_message(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_error_count(
+    List<ErrorCount> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_error_count(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_held_lock_info(
+    List<HeldLockInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_held_lock_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_log_entry(
+    List<LogEntry> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return SendToDartLoggerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_log_entry(item, serializer);
+    }
   }
 
   @protected
-  LevelFilter
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    SseDeserializer deserializer,
+  void sse_encode_list_operation_metrics(
+    List<OperationMetrics> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return LevelFilterImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_operation_metrics(item, serializer);
+    }
   }
 
   @protected
-  SendToDartLogger
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return SendToDartLoggerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint64List(self);
   }
 
   @protected
-  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_livestream_error(
+    LivestreamError self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    switch (self) {
+      case LivestreamError_Busy():
+        sse_encode_i_32(0, serializer);
+      case LivestreamError_NotInitialized():
+        sse_encode_i_32(1, serializer);
+      case LivestreamError_NoSession(sessionId: final sessionId):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_64(sessionId, serializer);
+      case LivestreamError_AlreadyOpen(sessionId: final sessionId):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_64(sessionId, serializer);
+      case LivestreamError_SessionMismatch(expected: final expected, got: final got):
+        sse_encode_i_32(4, serializer);
+        sse_encode_u_64(expected, serializer);
+        sse_encode_u_64(got, serializer);
+      case LivestreamError_SessionClosed(sessionId: final sessionId):
+        sse_encode_i_32(5, serializer);
+        sse_encode_u_64(sessionId, serializer);
+      case LivestreamError_AwaitingKeyUpdate():
+        sse_encode_i_32(6, serializer);
+      case LivestreamError_EpochMismatch(expected: final expected, found: final found):
+        sse_encode_i_32(7, serializer);
+        sse_encode_opt_box_autoadd_u_64(expected, serializer);
+        sse_encode_opt_box_autoadd_u_64(found, serializer);
+      case LivestreamError_Failed(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(field0, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_livestream_session(
+    LivestreamSession self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_u_64(self.sessionId, serializer);
+    sse_encode_String(self.cameraName, serializer);
+    sse_encode_i_64(self.openedAtMillis, serializer);
+    sse_encode_bool(self.keyUpdated, serializer);
+    sse_encode_u_64(self.chunksDecrypted, serializer);
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_livestream_stats(
+    LivestreamStats self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_opt_box_autoadd_i_64(self.sessionStartedAtMillis, serializer);
+    sse_encode_u_64(self.updateFailures, serializer);
+    sse_encode_u_64(self.chunksReceived, serializer);
+    sse_encode_u_64(self.chunksDecrypted, serializer);
+    sse_encode_u_64(self.chunksFailed, serializer);
+    sse_encode_u_64(self.chunksOutOfOrder, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_u_64(self.bytesDecrypted, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastChunkNumber, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastChunkAtMillis, serializer);
+    sse_encode_u_64(self.decryptP50Micros, serializer);
+    sse_encode_u_64(self.decryptP90Micros, serializer);
+    sse_encode_u_64(self.decryptP99Micros, serializer);
+    sse_encode_u_64(self.decryptMaxMicros, serializer);
+    sse_encode_u_64(self.lockWaitTotalMicros, serializer);
+    sse_encode_u_64(self.lockWaitMaxMicros, serializer);
+    sse_encode_u_64(self.lockTimeouts, serializer);
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_lock_owner_info(
+    LockOwnerInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_String(self.owner, serializer);
+    sse_encode_bool(self.shared, serializer);
+    sse_encode_i_64(self.acquiredAtMillis, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_64(self.timeMillis, serializer);
+    sse_encode_i_32(self.level, serializer);
+    sse_encode_String(self.tag, serializer);
+    sse_encode_String(self.msg, serializer);
+    sse_encode_opt_String(self.traceId, serializer);
+    sse_encode_opt_String(self.source, serializer);
+    sse_encode_opt_String(self.parentSpan, serializer);
+    sse_encode_opt_String(self.spanId, serializer);
+    sse_encode_i_64(self.durationMicros, serializer);
   }
 
   @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+  void sse_encode_metrics_snapshot(
+    MetricsSnapshot self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_timeMillis = sse_decode_i_64(deserializer);
-    var var_level = sse_decode_i_32(deserializer);
-    var var_tag = sse_decode_String(deserializer);
-    var var_msg = sse_decode_String(deserializer);
-    var var_traceId = sse_decode_opt_String(deserializer);
-    return LogEntry(
-      timeMillis: var_timeMillis,
-      level: var_level,
-      tag: var_tag,
-      msg: var_msg,
-      traceId: var_traceId,
+    sse_encode_i_64(self.sinceMillis, serializer);
+    sse_encode_i_64(self.takenAtMillis, serializer);
+    sse_encode_list_prim_u_64_strict(
+      self.latencyBucketBoundsMillis,
+      serializer,
     );
+    sse_encode_list_operation_metrics(self.byFunction, serializer);
+    sse_encode_list_operation_metrics(self.byCamera, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_notification_error(
+    NotificationError self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    switch (self) {
+      case NotificationError_Busy():
+        sse_encode_i_32(0, serializer);
+      case NotificationError_NotInitialized():
+        sse_encode_i_32(1, serializer);
+      case NotificationError_DuplicateMessage():
+        sse_encode_i_32(2, serializer);
+      case NotificationError_Failed(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
     }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_notification_payload(
+    NotificationPayload self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    switch (self) {
+      case NotificationPayload_Motion(timestamp: final timestamp):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(timestamp, serializer);
+      case NotificationPayload_VideoReady():
+        sse_encode_i_32(1, serializer);
+      case NotificationPayload_Empty():
+        sse_encode_i_32(2, serializer);
+      case NotificationPayload_Typed(kind: final kind, json: final json):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(kind, serializer);
+        sse_encode_String(json, serializer);
+      case NotificationPayload_Unknown(raw: final raw):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(raw, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_operation_metrics(
+    OperationMetrics self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_String(self.function, serializer);
+    sse_encode_String(self.camera, serializer);
+    sse_encode_u_64(self.calls, serializer);
+    sse_encode_list_error_count(self.errors, serializer);
+    sse_encode_list_prim_u_64_strict(self.latencyCounts, serializer);
+    sse_encode_u_64(self.totalMicros, serializer);
+    sse_encode_u_64(self.maxMicros, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+        self,
+        serializer,
+      );
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_opt_box_autoadd_call_context(
+    CallContext? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_call_context(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    LevelFilter self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as LevelFilterImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    SendToDartLogger self,
+  void sse_encode_opt_box_autoadd_livestream_session(
+    LivestreamSession? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SendToDartLoggerImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_livestream_session(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    LevelFilter self,
+  void sse_encode_opt_box_autoadd_lock_owner_info(
+    LockOwnerInfo? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as LevelFilterImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_lock_owner_info(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    SendToDartLogger self,
+  void sse_encode_opt_box_autoadd_pairing_request(
+    PairingRequest? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SendToDartLoggerImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pairing_request(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_log_entry_Sse(
-    RustStreamSink<LogEntry> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_log_entry,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_pairing_phase(PairingPhase self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_pairing_request(
+    PairingRequest self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_String(self.cameraName, serializer);
+    sse_encode_String(self.ip, serializer);
+    sse_encode_list_prim_u_8_strict(self.secret, serializer);
+    sse_encode_bool(self.standalone, serializer);
+    sse_encode_String(self.ssid, serializer);
+    sse_encode_String(self.wifiPassword, serializer);
+    sse_encode_String(self.pairingToken, serializer);
+    sse_encode_server_credentials(self.credentials, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_pairing_session(
+    PairingSession self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_String(self.cameraName, serializer);
+    sse_encode_pairing_phase(self.phase, serializer);
+    sse_encode_opt_String(self.firmwareVersion, serializer);
+    sse_encode_u_32(self.attempts, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+    sse_encode_i_64(self.updatedAtMillis, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_push_drain_report(
+    PushDrainReport self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+    sse_encode_list_drained_push_message(self.messages, serializer);
+    sse_encode_u_32(self.duplicates, serializer);
+    sse_encode_u_32(self.dropped, serializer);
+    sse_encode_u_32(self.remaining, serializer);
+    sse_encode_bool(self.busy, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_recording_summary(
+    RecordingSummary self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.fragments, serializer);
+    sse_encode_u_32(self.frames, serializer);
+    sse_encode_u_64(self.durationMillis, serializer);
+    sse_encode_u_64(self.bytes, serializer);
   }
 
   @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+  void sse_encode_server_credentials(
+    ServerCredentials self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.timeMillis, serializer);
-    sse_encode_i_32(self.level, serializer);
-    sse_encode_String(self.tag, serializer);
-    sse_encode_String(self.msg, serializer);
-    sse_encode_opt_String(self.traceId, serializer);
+    sse_encode_String(self.address, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.password, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
   }
 
   @protected
@@ -1566,6 +5771,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
+
+  @protected
+  void sse_encode_wait_outcome(WaitOutcome self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wait_status(self.status, serializer);
+    sse_encode_u_32(self.attempts, serializer);
+    sse_encode_u_32(self.stablePolls, serializer);
+    sse_encode_list_prim_u_64_strict(self.probeLatenciesMicros, serializer);
+    sse_encode_u_64(self.elapsedMillis, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+  }
+
+  @protected
+  void sse_encode_wait_status(WaitStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 }
 
 @sealed
@@ -1588,6 +5810,30 @@ class LevelFilterImpl extends RustOpaque implements LevelFilter {
   );
 }
 
+@sealed
+class LockHandleImpl extends RustOpaque implements LockHandle {
+  // Not to be used by end users
+  LockHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  LockHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_LockHandle,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_LockHandle,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_LockHandlePtr,
+  );
+
+  /// Identifies the handle in `list_held_locks`.
+  BigInt get id =>
+      RustLib.instance.api.crateApiLockManagerLockHandleId(that: this);
+}
+
 @sealed
 class SendToDartLoggerImpl extends RustOpaque implements SendToDartLogger {
   // Not to be used by end users
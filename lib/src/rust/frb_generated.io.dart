@@ -4,8 +4,19 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api.dart';
+import 'api/backup.dart';
+import 'api/camera_events.dart';
+import 'api/credentials.dart';
+import 'api/hotspot.dart';
+import 'api/livestream_recording.dart';
+import 'api/livestream_session.dart';
+import 'api/livestream_stats.dart';
 import 'api/lock_manager.dart';
 import 'api/logger.dart';
+import 'api/metrics.dart';
+import 'api/notifications.dart';
+import 'api/pairing.dart';
+import 'api/push_queue.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -26,211 +37,951 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_LevelFilterPtr =>
       wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilterPtr;
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LockHandlePtr =>
+      wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandlePtr;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_SendToDartLoggerPtr =>
       wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLoggerPtr;
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  LevelFilter
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    dynamic raw,
+  );
+
+  @protected
+  LockHandle
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  );
+
+  @protected
+  SendToDartLogger
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    dynamic raw,
+  );
+
+  @protected
+  LockHandle
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  );
+
+  @protected
+  LevelFilter
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    dynamic raw,
+  );
+
+  @protected
+  LockHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  );
+
+  @protected
+  SendToDartLogger
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<CameraEvent> dco_decode_StreamSink_camera_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<PairingSession> dco_decode_StreamSink_pairing_session_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  LockHandle
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  );
+
+  @protected
+  CallContext dco_decode_box_autoadd_call_context(dynamic raw);
+
+  @protected
+  DecryptError dco_decode_box_autoadd_decrypt_error(dynamic raw);
+
+  @protected
+  DecryptedFile dco_decode_box_autoadd_decrypted_file(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  LivestreamSession dco_decode_box_autoadd_livestream_session(dynamic raw);
+
+  @protected
+  LockOwnerInfo dco_decode_box_autoadd_lock_owner_info(dynamic raw);
+
+  @protected
+  PairingRequest dco_decode_box_autoadd_pairing_request(dynamic raw);
+
+  @protected
+  ServerCredentials dco_decode_box_autoadd_server_credentials(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  CallContext dco_decode_call_context(dynamic raw);
+
+  @protected
+  CameraEvent dco_decode_camera_event(dynamic raw);
+
+  @protected
+  CameraEventKind dco_decode_camera_event_kind(dynamic raw);
+
+  @protected
+  ClientReloadReport dco_decode_client_reload_report(dynamic raw);
+
+  @protected
+  DecryptError dco_decode_decrypt_error(dynamic raw);
+
+  @protected
+  DecryptOutcome dco_decode_decrypt_outcome(dynamic raw);
+
+  @protected
+  DecryptedFile dco_decode_decrypted_file(dynamic raw);
+
+  @protected
+  DrainedPushMessage dco_decode_drained_push_message(dynamic raw);
+
+  @protected
+  ErrorCount dco_decode_error_count(dynamic raw);
+
+  @protected
+  HeldLockInfo dco_decode_held_lock_info(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<DecryptOutcome> dco_decode_list_decrypt_outcome(dynamic raw);
+
+  @protected
+  List<DrainedPushMessage> dco_decode_list_drained_push_message(dynamic raw);
+
+  @protected
+  List<ErrorCount> dco_decode_list_error_count(dynamic raw);
+
+  @protected
+  List<HeldLockInfo> dco_decode_list_held_lock_info(dynamic raw);
+
+  @protected
+  List<LogEntry> dco_decode_list_log_entry(dynamic raw);
+
+  @protected
+  List<OperationMetrics> dco_decode_list_operation_metrics(dynamic raw);
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  LivestreamError dco_decode_livestream_error(dynamic raw);
+
+  @protected
+  LivestreamSession dco_decode_livestream_session(dynamic raw);
+
+  @protected
+  LivestreamStats dco_decode_livestream_stats(dynamic raw);
+
+  @protected
+  LockOwnerInfo dco_decode_lock_owner_info(dynamic raw);
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw);
+
+  @protected
+  MetricsSnapshot dco_decode_metrics_snapshot(dynamic raw);
+
+  @protected
+  NotificationError dco_decode_notification_error(dynamic raw);
+
+  @protected
+  NotificationPayload dco_decode_notification_payload(dynamic raw);
+
+  @protected
+  OperationMetrics dco_decode_operation_metrics(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  LockHandle?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    dynamic raw,
+  );
+
+  @protected
+  CallContext? dco_decode_opt_box_autoadd_call_context(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  LivestreamSession? dco_decode_opt_box_autoadd_livestream_session(dynamic raw);
+
+  @protected
+  LockOwnerInfo? dco_decode_opt_box_autoadd_lock_owner_info(dynamic raw);
+
+  @protected
+  PairingRequest? dco_decode_opt_box_autoadd_pairing_request(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  PairingPhase dco_decode_pairing_phase(dynamic raw);
+
+  @protected
+  PairingRequest dco_decode_pairing_request(dynamic raw);
+
+  @protected
+  PairingSession dco_decode_pairing_session(dynamic raw);
+
+  @protected
+  PushDrainReport dco_decode_push_drain_report(dynamic raw);
+
+  @protected
+  RecordingSummary dco_decode_recording_summary(dynamic raw);
+
+  @protected
+  ServerCredentials dco_decode_server_credentials(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WaitOutcome dco_decode_wait_outcome(dynamic raw);
+
+  @protected
+  WaitStatus dco_decode_wait_status(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  LevelFilter
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LockHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SendToDartLogger
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LockHandle
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LevelFilter
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LockHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SendToDartLogger
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<CameraEvent> sse_decode_StreamSink_camera_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<PairingSession> sse_decode_StreamSink_pairing_session_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  LockHandle
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CallContext sse_decode_box_autoadd_call_context(SseDeserializer deserializer);
+
+  @protected
+  DecryptError sse_decode_box_autoadd_decrypt_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DecryptedFile sse_decode_box_autoadd_decrypted_file(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  LivestreamSession sse_decode_box_autoadd_livestream_session(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LockOwnerInfo sse_decode_box_autoadd_lock_owner_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PairingRequest sse_decode_box_autoadd_pairing_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ServerCredentials sse_decode_box_autoadd_server_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  CallContext sse_decode_call_context(SseDeserializer deserializer);
+
+  @protected
+  CameraEvent sse_decode_camera_event(SseDeserializer deserializer);
+
+  @protected
+  CameraEventKind sse_decode_camera_event_kind(SseDeserializer deserializer);
+
+  @protected
+  ClientReloadReport sse_decode_client_reload_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DecryptError sse_decode_decrypt_error(SseDeserializer deserializer);
+
+  @protected
+  DecryptOutcome sse_decode_decrypt_outcome(SseDeserializer deserializer);
+
+  @protected
+  DecryptedFile sse_decode_decrypted_file(SseDeserializer deserializer);
+
+  @protected
+  DrainedPushMessage sse_decode_drained_push_message(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ErrorCount sse_decode_error_count(SseDeserializer deserializer);
+
+  @protected
+  HeldLockInfo sse_decode_held_lock_info(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<DecryptOutcome> sse_decode_list_decrypt_outcome(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DrainedPushMessage> sse_decode_list_drained_push_message(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ErrorCount> sse_decode_list_error_count(SseDeserializer deserializer);
+
+  @protected
+  List<HeldLockInfo> sse_decode_list_held_lock_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer);
+
+  @protected
+  List<OperationMetrics> sse_decode_list_operation_metrics(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  LivestreamError sse_decode_livestream_error(SseDeserializer deserializer);
+
+  @protected
+  LivestreamSession sse_decode_livestream_session(SseDeserializer deserializer);
+
+  @protected
+  LivestreamStats sse_decode_livestream_stats(SseDeserializer deserializer);
+
+  @protected
+  LockOwnerInfo sse_decode_lock_owner_info(SseDeserializer deserializer);
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+  @protected
+  MetricsSnapshot sse_decode_metrics_snapshot(SseDeserializer deserializer);
+
+  @protected
+  NotificationError sse_decode_notification_error(SseDeserializer deserializer);
+
+  @protected
+  NotificationPayload sse_decode_notification_payload(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OperationMetrics sse_decode_operation_metrics(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  LockHandle?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CallContext? sse_decode_opt_box_autoadd_call_context(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  LivestreamSession? sse_decode_opt_box_autoadd_livestream_session(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LockOwnerInfo? sse_decode_opt_box_autoadd_lock_owner_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PairingRequest? sse_decode_opt_box_autoadd_pairing_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  PairingPhase sse_decode_pairing_phase(SseDeserializer deserializer);
+
+  @protected
+  PairingRequest sse_decode_pairing_request(SseDeserializer deserializer);
+
+  @protected
+  PairingSession sse_decode_pairing_session(SseDeserializer deserializer);
+
+  @protected
+  PushDrainReport sse_decode_push_drain_report(SseDeserializer deserializer);
+
+  @protected
+  RecordingSummary sse_decode_recording_summary(SseDeserializer deserializer);
+
+  @protected
+  ServerCredentials sse_decode_server_credentials(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  WaitOutcome sse_decode_wait_outcome(SseDeserializer deserializer);
 
   @protected
-  LevelFilter
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    dynamic raw,
+  WaitStatus sse_decode_wait_status(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
   );
 
   @protected
-  SendToDartLogger
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    LevelFilter self,
+    SseSerializer serializer,
   );
 
   @protected
-  LevelFilter
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle self,
+    SseSerializer serializer,
   );
 
   @protected
-  SendToDartLogger
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    SendToDartLogger self,
+    SseSerializer serializer,
   );
 
   @protected
-  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw);
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String dco_decode_String(dynamic raw);
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
+    LevelFilter self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool dco_decode_bool(dynamic raw);
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
+    SendToDartLogger self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  void sse_encode_StreamSink_camera_event_Sse(
+    RustStreamSink<CameraEvent> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  void sse_encode_StreamSink_log_entry_Sse(
+    RustStreamSink<LogEntry> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_StreamSink_pairing_session_Sse(
+    RustStreamSink<PairingSession> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  LogEntry dco_decode_log_entry(dynamic raw);
+  void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_box_autoadd_call_context(
+    CallContext self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_box_autoadd_decrypt_error(
+    DecryptError self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_box_autoadd_decrypted_file(
+    DecryptedFile self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
 
   @protected
-  LevelFilter
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_livestream_session(
+    LivestreamSession self,
+    SseSerializer serializer,
   );
 
   @protected
-  SendToDartLogger
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_lock_owner_info(
+    LockOwnerInfo self,
+    SseSerializer serializer,
   );
 
   @protected
-  LevelFilter
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_pairing_request(
+    PairingRequest self,
+    SseSerializer serializer,
   );
 
   @protected
-  SendToDartLogger
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_server_credentials(
+    ServerCredentials self,
+    SseSerializer serializer,
   );
 
   @protected
-  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_call_context(CallContext self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_camera_event(CameraEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_camera_event_kind(
+    CameraEventKind self,
+    SseSerializer serializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_client_reload_report(
+    ClientReloadReport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_decrypt_error(DecryptError self, SseSerializer serializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_decrypt_outcome(
+    DecryptOutcome self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_decrypted_file(DecryptedFile self, SseSerializer serializer);
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  void sse_encode_drained_push_message(
+    DrainedPushMessage self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_error_count(ErrorCount self, SseSerializer serializer);
 
   @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+  void sse_encode_held_lock_info(HeldLockInfo self, SseSerializer serializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_list_decrypt_outcome(
+    List<DecryptOutcome> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_list_drained_push_message(
+    List<DrainedPushMessage> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_error_count(
+    List<ErrorCount> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    LevelFilter self,
+  void sse_encode_list_held_lock_info(
+    List<HeldLockInfo> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    SendToDartLogger self,
+  void sse_encode_list_log_entry(List<LogEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_operation_metrics(
+    List<OperationMetrics> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilter(
-    LevelFilter self,
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
-    SendToDartLogger self,
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_log_entry_Sse(
-    RustStreamSink<LogEntry> self,
+  void sse_encode_livestream_error(
+    LivestreamError self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_livestream_session(
+    LivestreamSession self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_livestream_stats(
+    LivestreamStats self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_lock_owner_info(LockOwnerInfo self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  void sse_encode_metrics_snapshot(
+    MetricsSnapshot self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_notification_error(
+    NotificationError self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+  void sse_encode_notification_payload(
+    NotificationPayload self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_operation_metrics(
+    OperationMetrics self,
+    SseSerializer serializer,
+  );
 
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    LockHandle? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_call_context(
+    CallContext? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_livestream_session(
+    LivestreamSession? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_lock_owner_info(
+    LockOwnerInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_pairing_request(
+    PairingRequest? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pairing_phase(PairingPhase self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pairing_request(
+    PairingRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_pairing_session(
+    PairingSession self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_push_drain_report(
+    PushDrainReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recording_summary(
+    RecordingSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_server_credentials(
+    ServerCredentials self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
@@ -242,6 +993,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wait_outcome(WaitOutcome self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wait_status(WaitStatus self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -292,6 +1049,40 @@ class RustLibWire implements BaseWire {
       _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLevelFilterPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandlePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_secluso_flutter_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle',
+      );
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandlePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandlePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_secluso_flutter_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle',
+      );
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandlePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
     ffi.Pointer<ffi.Void> ptr,
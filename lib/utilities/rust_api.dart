@@ -3,16 +3,16 @@
 import 'dart:typed_data';
 
 import 'package:secluso_flutter/src/rust/api.dart' as raw;
+import 'package:secluso_flutter/src/rust/api/credentials.dart';
+import 'package:secluso_flutter/src/rust/api/logger.dart';
 import 'package:secluso_flutter/utilities/logger.dart';
 
-const String _traceSep = '|trace=';
-
-String _cameraNameWithTrace(String cameraName) {
+CallContext? _callContext() {
   final traceId = Log.currentContextId();
   if (traceId.isEmpty) {
-    return cameraName;
+    return null;
   }
-  return '$cameraName$_traceSep$traceId';
+  return CallContext(traceId: traceId);
 }
 
 Future<bool> initializeCamera({
@@ -20,22 +20,24 @@ Future<bool> initializeCamera({
   required String fileDir,
   required bool firstTime,
 }) => raw.initializeCamera(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   fileDir: fileDir,
   firstTime: firstTime,
+  context: _callContext(),
 );
 
 Future<void> deregisterCamera({required String cameraName}) =>
-    raw.deregisterCamera(cameraName: _cameraNameWithTrace(cameraName));
+    raw.deregisterCamera(cameraName: cameraName, context: _callContext());
 
 Future<String> decryptVideo({
   required String cameraName,
   required String encFilename,
   required BigInt assumedEpoch,
 }) => raw.decryptVideo(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   encFilename: encFilename,
   assumedEpoch: assumedEpoch,
+  context: _callContext(),
 );
 
 Future<String> decryptThumbnail({
@@ -44,10 +46,11 @@ Future<String> decryptThumbnail({
   required String pendingMetaDirectory,
   required BigInt assumedEpoch,
 }) => raw.decryptThumbnail(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   encFilename: encFilename,
   pendingMetaDirectory: pendingMetaDirectory,
   assumedEpoch: assumedEpoch,
+  context: _callContext(),
 );
 
 Future<String> flutterAddCamera({
@@ -58,16 +61,17 @@ Future<String> flutterAddCamera({
   required String ssid,
   required String password,
   required String pairingToken,
-  required String credentialsFull,
+  required ServerCredentials credentials,
 }) => raw.flutterAddCamera(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   ip: ip,
   secret: secret,
   standalone: standalone,
   ssid: ssid,
   password: password,
   pairingToken: pairingToken,
-  credentialsFull: credentialsFull,
+  credentials: credentials,
+  context: _callContext(),
 );
 
 Future<void> shutdownApp() => raw.shutdownApp();
@@ -79,8 +83,9 @@ Future<Uint8List> encryptSettingsMessage({
   required String cameraName,
   required List<int> data,
 }) => raw.encryptSettingsMessage(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   data: data,
+  context: _callContext(),
 );
 
 Future<String> decryptMessage({
@@ -89,8 +94,9 @@ Future<String> decryptMessage({
   required List<int> data,
 }) => raw.decryptMessage(
   clientTag: clientTag,
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   data: data,
+  context: _callContext(),
 );
 
 Future<String> getGroupName({
@@ -98,15 +104,17 @@ Future<String> getGroupName({
   required String cameraName,
 }) => raw.getGroupName(
   clientTag: clientTag,
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
+  context: _callContext(),
 );
 
 Future<bool> livestreamUpdate({
   required String cameraName,
   required List<int> msg,
 }) => raw.livestreamUpdate(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   msg: msg,
+  context: _callContext(),
 );
 
 Future<Uint8List> livestreamDecrypt({
@@ -114,9 +122,10 @@ Future<Uint8List> livestreamDecrypt({
   required List<int> data,
   required BigInt expectedChunkNumber,
 }) => raw.livestreamDecrypt(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   data: data,
   expectedChunkNumber: expectedChunkNumber,
+  context: _callContext(),
 );
 
 Future<String> rustLibVersion() => raw.rustLibVersion();
@@ -125,8 +134,9 @@ Future<Uint8List> generateHeartbeatRequestConfigCommand({
   required String cameraName,
   required BigInt timestamp,
 }) => raw.generateHeartbeatRequestConfigCommand(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   timestamp: timestamp,
+  context: _callContext(),
 );
 
 Future<String> processHeartbeatConfigResponse({
@@ -134,7 +144,8 @@ Future<String> processHeartbeatConfigResponse({
   required List<int> configResponse,
   required BigInt expectedTimestamp,
 }) => raw.processHeartbeatConfigResponse(
-  cameraName: _cameraNameWithTrace(cameraName),
+  cameraName: cameraName,
   configResponse: configResponse,
   expectedTimestamp: expectedTimestamp,
+  context: _callContext(),
 );
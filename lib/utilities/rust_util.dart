@@ -5,6 +5,7 @@ import 'dart:io';
 import 'package:path/path.dart' as p;
 import 'package:shared_preferences/shared_preferences.dart';
 import 'package:secluso_flutter/keys.dart';
+import 'package:secluso_flutter/src/rust/api/credentials.dart';
 import 'package:secluso_flutter/utilities/app_paths.dart';
 import 'package:secluso_flutter/utilities/logger.dart';
 import 'package:secluso_flutter/utilities/rust_api.dart';
@@ -74,7 +75,11 @@ Future<String> addCamera(
     ssid: ssid,
     password: password,
     pairingToken: pairingToken,
-    credentialsFull: serverUsername + serverPassword + serverAddress,
+    credentials: ServerCredentials(
+      address: serverAddress,
+      username: serverUsername,
+      password: serverPassword,
+    ),
   );
 }
 
@@ -0,0 +1,25 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `decrypt_message` on untrusted push payloads. The first byte picks the
+//! client tag, the rest is the message.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_lib_secluso_flutter::api;
+
+const CLIENT_TAGS: [&str; 3] = ["fcm", "motion", "livestream"];
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&tag, message)) = data.split_first() else {
+        return;
+    };
+    let client_tag = CLIENT_TAGS[tag as usize % CLIENT_TAGS.len()];
+    let _ = api::decrypt_message(
+        client_tag.to_string(),
+        secluso_fuzz::camera_name(),
+        message.to_vec(),
+        None,
+    );
+    secluso_fuzz::check_invariants();
+});
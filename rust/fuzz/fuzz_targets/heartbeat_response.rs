@@ -0,0 +1,20 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `process_heartbeat_config_response` on untrusted responses. The first 8 bytes
+//! are the expected timestamp, the rest is the response.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_lib_secluso_flutter::api;
+
+fuzz_target!(|data: &[u8]| {
+    let (expected_timestamp, response) = secluso_fuzz::split_u64(data);
+    let _ = api::process_heartbeat_config_response(
+        secluso_fuzz::camera_name(),
+        response.to_vec(),
+        expected_timestamp,
+        None,
+    );
+    secluso_fuzz::check_invariants();
+});
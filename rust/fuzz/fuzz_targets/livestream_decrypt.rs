@@ -0,0 +1,20 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `livestream_decrypt` on untrusted chunks. The first 8 bytes are the expected
+//! chunk number, the rest is the chunk.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_lib_secluso_flutter::api;
+
+fuzz_target!(|data: &[u8]| {
+    let (expected_chunk_number, chunk) = secluso_fuzz::split_u64(data);
+    let _ = api::livestream_decrypt(
+        secluso_fuzz::camera_name(),
+        chunk.to_vec(),
+        expected_chunk_number,
+        None,
+    );
+    secluso_fuzz::check_invariants();
+});
@@ -0,0 +1,25 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! The `|trace=` suffix parser, which sees every camera name Dart passes in.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_lib_secluso_flutter::api;
+
+const TRACE_TAG: &str = "|trace=";
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+    let (base, trace) = api::split_trace_camera_for_tests(&input);
+
+    // The base name never carries the tag, and splitting loses nothing.
+    assert!(!base.contains(TRACE_TAG));
+    match trace {
+        Some(trace) => {
+            assert!(!trace.is_empty());
+            assert_eq!(format!("{}{}{}", base, TRACE_TAG, trace), input);
+        }
+        None => assert!(input == base || input == format!("{}{}", base, TRACE_TAG)),
+    }
+});
@@ -0,0 +1,111 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Write a starting corpus for each fuzz target:
+//!
+//!     SECLUSO_FUZZ_BACKEND=fake cargo run --bin seed_corpus [CORPUS_DIR]
+//!
+//! Inputs are laid out the way each target splits them. Messages come from the
+//! simulated camera, sealed with the harness session key, so they only decrypt
+//! in fake-backend runs; against the native client only the camera-name seeds
+//! are written.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use rust_lib_secluso_flutter::api;
+
+fn write(dir: &Path, target: &str, name: &str, data: &[u8]) -> io::Result<()> {
+    let dir = dir.join(target);
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(name), data)
+}
+
+fn with_u64(header: u64, data: &[u8]) -> Vec<u8> {
+    let mut input = header.to_le_bytes().to_vec();
+    input.extend_from_slice(data);
+    input
+}
+
+fn main() -> io::Result<()> {
+    let corpus = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "corpus".to_string());
+    let corpus = Path::new(&corpus);
+
+    for (i, name) in [
+        "Front Door",
+        "Front Door|trace=0b4f2c",
+        "Front Door|trace=",
+        "|trace=x|trace=y",
+    ]
+    .iter()
+    .enumerate()
+    {
+        write(
+            corpus,
+            "split_trace_camera",
+            &format!("name_{}", i),
+            name.as_bytes(),
+        )?;
+    }
+
+    let camera_name = secluso_fuzz::camera_name();
+    let Some(sim) = secluso_fuzz::simulator() else {
+        api::shutdown_app();
+        println!(
+            "wrote camera-name seeds to {}; set SECLUSO_FUZZ_BACKEND=fake for message seeds",
+            corpus.display()
+        );
+        return Ok(());
+    };
+
+    // First byte selects the client tag (0 = "fcm").
+    for (i, payload) in [&b"motion:1700000000"[..], b"{\"type\":\"status\"}", b""]
+        .iter()
+        .enumerate()
+    {
+        let mut input = vec![0];
+        input.extend(sim.notification(i as u64, payload)?);
+        write(
+            corpus,
+            "decrypt_message",
+            &format!("notification_{}", i),
+            &input,
+        )?;
+    }
+
+    for chunk_number in 0..4u64 {
+        let chunk = sim.livestream_chunk(chunk_number, &[chunk_number as u8; 188])?;
+        write(
+            corpus,
+            "livestream_decrypt",
+            &format!("chunk_{}", chunk_number),
+            &with_u64(chunk_number, &chunk),
+        )?;
+    }
+    // An out-of-order chunk, which must be rejected rather than accepted.
+    let chunk = sim.livestream_chunk(7, b"late")?;
+    write(
+        corpus,
+        "livestream_decrypt",
+        "chunk_out_of_order",
+        &with_u64(3, &chunk),
+    )?;
+
+    for timestamp in [1u64, 1_700_000_000] {
+        let request =
+            api::generate_heartbeat_request_config_command(camera_name.clone(), timestamp, None);
+        let response = sim.answer_heartbeat(&request)?;
+        write(
+            corpus,
+            "heartbeat_response",
+            &format!("response_{}", timestamp),
+            &with_u64(timestamp, &response),
+        )?;
+    }
+
+    api::shutdown_app();
+    println!("wrote corpus to {}", corpus.display());
+    Ok(())
+}
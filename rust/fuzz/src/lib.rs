@@ -0,0 +1,117 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Shared setup for the fuzz targets.
+//!
+//! By default the targets fuzz the native client, so inputs reach the real MLS
+//! parsing behind the bridge's locking, lazy init and error mapping. Point
+//! `SECLUSO_FUZZ_STATE_DIR` at a copy of a paired camera's state directory to
+//! get past the group lookup; without it the harness creates fresh, unpaired
+//! state.
+//!
+//! Set `SECLUSO_FUZZ_BACKEND=fake` to fuzz the bridge alone, against the fake
+//! backend paired with a simulated camera through `flutter_add_camera`. The
+//! simulator always hands out [`SESSION_KEY`], so the corpus written by
+//! `seed_corpus` opens in every fuzzing process.
+
+use std::fs;
+use std::sync::OnceLock;
+
+use rust_lib_secluso_flutter::api::credentials::ServerCredentials;
+use rust_lib_secluso_flutter::api::{self, lock_manager};
+use rust_lib_secluso_flutter::backend::fake::FakeCamera;
+use rust_lib_secluso_flutter::backend::simulator::SimulatedCamera;
+
+pub const CAMERA_NAME: &str = "fuzz-camera";
+pub const SESSION_KEY: [u8; 32] = [0x5e; 32];
+
+struct Harness {
+    fake: Option<FakeCamera>,
+    sim: Option<SimulatedCamera>,
+}
+
+static HARNESS: OnceLock<Harness> = OnceLock::new();
+
+fn fresh_state_dir() -> String {
+    let file_dir = std::env::temp_dir().join(format!("secluso-fuzz-{}", std::process::id()));
+    fs::create_dir_all(&file_dir).expect("create fuzz state dir");
+    file_dir.to_string_lossy().into_owned()
+}
+
+fn harness() -> &'static Harness {
+    HARNESS.get_or_init(|| {
+        if std::env::var("SECLUSO_FUZZ_BACKEND").as_deref() != Ok("fake") {
+            match std::env::var("SECLUSO_FUZZ_STATE_DIR") {
+                Ok(state_dir) => {
+                    api::initialize_camera(CAMERA_NAME.to_string(), state_dir, false, None)
+                }
+                Err(_) => {
+                    api::initialize_camera(CAMERA_NAME.to_string(), fresh_state_dir(), true, None)
+                }
+            };
+            return Harness {
+                fake: None,
+                sim: None,
+            };
+        }
+
+        let fake = FakeCamera::new();
+        fake.install();
+        let sim = SimulatedCamera::start_with_session_key(SESSION_KEY.to_vec())
+            .expect("start simulated camera");
+        api::initialize_camera(CAMERA_NAME.to_string(), fresh_state_dir(), true, None);
+        let firmware_version = api::flutter_add_camera(
+            CAMERA_NAME.to_string(),
+            sim.addr().to_string(),
+            sim.secret(),
+            false,
+            "ssid".to_string(),
+            "wifi-password".to_string(),
+            "pairing-token".to_string(),
+            ServerCredentials {
+                address: "https://relay.example.com".to_string(),
+                username: "fuzzusername01".to_string(),
+                password: "fuzzpassword01".to_string(),
+            },
+            None,
+        );
+        assert_eq!(firmware_version, sim.firmware_version(), "pairing failed");
+        Harness {
+            fake: Some(fake),
+            sim: Some(sim),
+        }
+    })
+}
+
+/// Camera name to pass to the bridge, set up on first use.
+pub fn camera_name() -> String {
+    harness();
+    CAMERA_NAME.to_string()
+}
+
+/// The paired simulated camera, or `None` when fuzzing the native client.
+pub fn simulator() -> Option<&'static SimulatedCamera> {
+    harness().sim.as_ref()
+}
+
+/// Check the invariants every bridge call must leave behind: no client lock or
+/// file lock still held once the call has returned.
+pub fn check_invariants() {
+    assert_eq!(api::held_client_lock_count(), 0, "client lock leaked");
+    assert!(
+        lock_manager::list_held_locks().is_empty(),
+        "file lock leaked"
+    );
+    // The fake logs every call; don't let it grow for the whole run.
+    if let Some(fake) = &harness().fake {
+        fake.state().calls.clear();
+    }
+}
+
+/// Split a fuzz input into a little-endian u64 header and the remaining bytes.
+pub fn split_u64(data: &[u8]) -> (u64, &[u8]) {
+    if data.len() < 8 {
+        return (0, data);
+    }
+    let (head, rest) = data.split_at(8);
+    (u64::from_le_bytes(head.try_into().unwrap()), rest)
+}
@@ -0,0 +1,401 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Passphrase-protected backups of a camera's MLS client state, so a new phone
+//! can take over the camera without pairing again.
+//!
+//! A backup is every file under the camera's `file_dir` except downloaded media,
+//! taken while holding the client lock, along with the MLS group epoch the
+//! client reports for that state. The format is
+//!
+//! ```text
+//! "SCLBKP" | version u8 | memory KiB u32 | passes u32 | salt [16] | nonce [12] | ciphertext | tag [16]
+//! ```
+//!
+//! Argon2id stretches the passphrase into a ChaCha20-Poly1305 key, which
+//! encrypts the payload with the header as associated data.
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload as Sealed};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use log::info;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use tracing::{field, info_span};
+
+use super::logger::CallContext;
+use super::{
+    ensure_client_initialized, get_or_create_channel_mutex, lock_client_with_owner, logger,
+    metrics, resolve_call, CHANNEL_FIXED, INIT_PARAMS,
+};
+use crate::backend::new_backend;
+
+const MAGIC: &[u8] = b"SCLBKP";
+const FORMAT_VERSION: u8 = 2;
+const KDF_MEMORY_KIB: u32 = 64 * 1024;
+const KDF_PASSES: u32 = 3;
+// Backups name their own cost; don't let one exhaust memory or stall the app.
+const MAX_KDF_MEMORY_KIB: u32 = 256 * 1024;
+const MAX_KDF_PASSES: u32 = 16;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const HEADER_LEN: usize = MAGIC.len() + 1 + 4 + 4 + SALT_LEN + NONCE_LEN;
+
+/// Downloaded media, which can be fetched again and is not needed to restore.
+const SKIPPED_DIRS: [&str; 2] = ["videos", "encrypted"];
+
+static KDF_MEMORY: AtomicU32 = AtomicU32::new(KDF_MEMORY_KIB);
+static KDF_PASSES_USED: AtomicU32 = AtomicU32::new(KDF_PASSES);
+
+/// Use a cheaper key derivation for new backups, so tests don't spend seconds
+/// in Argon2 under a debug build.
+#[cfg(feature = "test-support")]
+#[flutter_rust_bridge::frb(ignore)]
+pub fn set_kdf_cost_for_tests(memory_kib: u32, passes: u32) {
+    KDF_MEMORY.store(memory_kib, Ordering::SeqCst);
+    KDF_PASSES_USED.store(passes, Ordering::SeqCst);
+}
+
+fn cipher(
+    passphrase: &str,
+    salt: &[u8],
+    memory_kib: u32,
+    passes: u32,
+) -> Result<ChaCha20Poly1305, String> {
+    let params = Params::new(memory_kib, passes, 1, Some(32))
+        .map_err(|e| format!("bad key derivation cost: {}", e))?;
+    let mut key = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("key derivation failed: {}", e))?;
+    Ok(ChaCha20Poly1305::new(&key.into()))
+}
+
+// Plaintext: camera name, epoch (a presence byte, then the value), then
+// (relative path, contents) per file, all length-prefixed with big-endian
+// u32/u64.
+struct Payload {
+    camera_name: String,
+    epoch: Option<u64>,
+    files: Vec<(String, Vec<u8>)>,
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+    if input.len() < len {
+        return Err("backup payload is truncated".to_string());
+    }
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    Ok(head)
+}
+
+fn take_bytes<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], String> {
+    let len = u32::from_be_bytes(take(input, 4)?.try_into().unwrap());
+    take(input, len as usize)
+}
+
+impl Payload {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        put_bytes(&mut out, self.camera_name.as_bytes());
+        out.push(self.epoch.is_some() as u8);
+        out.extend_from_slice(&self.epoch.unwrap_or(0).to_be_bytes());
+        out.extend_from_slice(&(self.files.len() as u32).to_be_bytes());
+        for (path, contents) in &self.files {
+            put_bytes(&mut out, path.as_bytes());
+            put_bytes(&mut out, contents);
+        }
+        out
+    }
+
+    fn decode(mut input: &[u8]) -> Result<Self, String> {
+        let input = &mut input;
+        let camera_name = String::from_utf8(take_bytes(input)?.to_vec())
+            .map_err(|_| "camera name is not UTF-8".to_string())?;
+        let has_epoch = take(input, 1)?[0] != 0;
+        let epoch = u64::from_be_bytes(take(input, 8)?.try_into().unwrap());
+        let epoch = has_epoch.then_some(epoch);
+        let count = u32::from_be_bytes(take(input, 4)?.try_into().unwrap());
+        let mut files = Vec::new();
+        for _ in 0..count {
+            let path = String::from_utf8(take_bytes(input)?.to_vec())
+                .map_err(|_| "file name is not UTF-8".to_string())?;
+            files.push((path, take_bytes(input)?.to_vec()));
+        }
+        Ok(Payload {
+            camera_name,
+            epoch,
+            files,
+        })
+    }
+}
+
+// State files under `dir`, as (path relative to `root`, full path), leaving out
+// downloaded media. A missing directory has none.
+fn list_files(root: &Path, dir: &Path, files: &mut Vec<(String, PathBuf)>) -> io::Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound && dir == root => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        if path.is_dir() {
+            if dir == root && SKIPPED_DIRS.iter().any(|skip| relative == Path::new(skip)) {
+                continue;
+            }
+            list_files(root, &path, files)?;
+        } else {
+            let name = relative
+                .to_str()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "non UTF-8 file name"))?
+                .replace('\\', "/");
+            files.push((name, path));
+        }
+    }
+    Ok(())
+}
+
+fn state_files(file_dir: &Path) -> Result<Vec<(String, PathBuf)>, String> {
+    let mut files = Vec::new();
+    list_files(file_dir, file_dir, &mut files).map_err(|e| e.to_string())?;
+    Ok(files)
+}
+
+// Refuse to overwrite state in `file_dir` that is known to be newer than the
+// backup. The existing state is loaded the way the app would load it and asked
+// for its group epoch. The native client only knows the epoch once it has opened
+// a file, so state that can't be compared, or doesn't load at all, is replaced.
+fn refuse_newer_state(file_dir: &Path, payload: &Payload) -> Result<(), String> {
+    let Some(backup_epoch) = payload.epoch else {
+        return Ok(());
+    };
+    if state_files(file_dir)?.is_empty() {
+        return Ok(());
+    }
+    let mut existing = new_backend();
+    if existing
+        .initialize(file_dir.to_string_lossy().into_owned(), false)
+        .is_err()
+    {
+        return Ok(());
+    }
+    match existing.group_epoch() {
+        Some(existing_epoch) if existing_epoch > backup_epoch => Err(format!(
+            "existing state for camera {} is at epoch {}, newer than the backup (epoch {})",
+            payload.camera_name, existing_epoch, backup_epoch
+        )),
+        _ => Ok(()),
+    }
+}
+
+// Only plain relative paths may come out of a backup.
+fn safe_join(root: &Path, relative: &str) -> Result<PathBuf, String> {
+    let relative = Path::new(relative);
+    if relative
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return Err(format!("refusing unsafe path {:?} in backup", relative));
+    }
+    Ok(root.join(relative))
+}
+
+/// Back up the MLS client state of `camera_name`, encrypted under `passphrase`.
+#[flutter_rust_bridge::frb]
+pub fn export_camera_backup(
+    camera_name: String,
+    passphrase: String,
+    context: Option<CallContext>,
+) -> Result<Vec<u8>, String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let _span = info_span!("export_camera_backup", camera = %camera_name).entered();
+    let _metrics = metrics::track("export_camera_backup", &camera_name);
+    if passphrase.is_empty() {
+        return Err("passphrase must not be empty".to_string());
+    }
+    let file_dir = INIT_PARAMS
+        .lock()
+        .get(&camera_name)
+        .map(|params| PathBuf::from(&params.file_dir))
+        .ok_or_else(|| format!("camera {} is not initialized", camera_name))?;
+
+    // Hold the client lock so no operation writes state while we read it.
+    let client_mutex = get_or_create_channel_mutex(&camera_name, CHANNEL_FIXED);
+    let op = "export_camera_backup".to_string();
+    let mut client_guard = lock_client_with_owner(
+        &client_mutex,
+        &camera_name,
+        CHANNEL_FIXED,
+        &op,
+        context.trace_id.as_deref(),
+    )
+    .ok_or_else(|| "Error: Busy".to_string())?;
+    let epoch = if ensure_client_initialized(&mut **client_guard, &camera_name, CHANNEL_FIXED) {
+        client_guard.group_epoch()
+    } else {
+        None
+    };
+
+    let mut files = Vec::new();
+    for (name, path) in state_files(&file_dir)? {
+        files.push((name, fs::read(&path).map_err(|e| e.to_string())?));
+    }
+    let payload = Payload {
+        camera_name: camera_name.clone(),
+        epoch,
+        files,
+    };
+
+    let salt = rand::random::<[u8; SALT_LEN]>();
+    let nonce = rand::random::<[u8; NONCE_LEN]>();
+    let memory_kib = KDF_MEMORY.load(Ordering::SeqCst);
+    let passes = KDF_PASSES_USED.load(Ordering::SeqCst);
+    let cipher = cipher(&passphrase, &salt, memory_kib, passes)?;
+
+    let mut backup = Vec::with_capacity(HEADER_LEN);
+    backup.extend_from_slice(MAGIC);
+    backup.push(FORMAT_VERSION);
+    backup.extend_from_slice(&memory_kib.to_be_bytes());
+    backup.extend_from_slice(&passes.to_be_bytes());
+    backup.extend_from_slice(&salt);
+    backup.extend_from_slice(&nonce);
+    let body = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Sealed {
+                msg: &payload.encode(),
+                aad: &backup,
+            },
+        )
+        .map_err(|_| "backup encryption failed".to_string())?;
+    backup.extend_from_slice(&body);
+
+    info!(
+        "Exported backup of camera {} ({} files, epoch {:?})",
+        camera_name,
+        payload.files.len(),
+        payload.epoch
+    );
+    Ok(backup)
+}
+
+/// Restore a backup made by `export_camera_backup` into `file_dir` and return
+/// the camera name it belongs to, removing state files the backup doesn't have.
+/// Refuses to replace state known to be from a later epoch. Call
+/// `initialize_camera` with the returned name afterwards.
+#[flutter_rust_bridge::frb]
+pub fn import_camera_backup(
+    backup: Vec<u8>,
+    passphrase: String,
+    file_dir: String,
+    context: Option<CallContext>,
+) -> Result<String, String> {
+    let (_, context) = resolve_call("", context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    // The camera is only known once the backup is open.
+    let span = info_span!("import_camera_backup", camera = field::Empty).entered();
+    let mut metrics = metrics::track("import_camera_backup", "");
+    let result = open_backup(&backup, &passphrase).and_then(|payload| {
+        span.record("camera", payload.camera_name.as_str());
+        metrics.set_camera(&payload.camera_name);
+        restore(payload, Path::new(&file_dir), context.trace_id.as_deref())
+    });
+    if result.is_err() {
+        metrics::record_error(metrics::ERROR_FAILED);
+    }
+    result
+}
+
+fn open_backup(backup: &[u8], passphrase: &str) -> Result<Payload, String> {
+    if backup.len() < HEADER_LEN + TAG_LEN || !backup.starts_with(MAGIC) {
+        return Err("not a camera backup".to_string());
+    }
+    let version = backup[MAGIC.len()];
+    if version != FORMAT_VERSION {
+        return Err(format!("unsupported backup format version {}", version));
+    }
+    let (header, body) = backup.split_at(HEADER_LEN);
+    let mut fields = &header[MAGIC.len() + 1..];
+    let memory_kib = u32::from_be_bytes(take(&mut fields, 4)?.try_into().unwrap());
+    let passes = u32::from_be_bytes(take(&mut fields, 4)?.try_into().unwrap());
+    if memory_kib > MAX_KDF_MEMORY_KIB || passes > MAX_KDF_PASSES {
+        return Err(format!(
+            "implausible key derivation cost ({} KiB, {} passes)",
+            memory_kib, passes
+        ));
+    }
+    let salt = take(&mut fields, SALT_LEN)?;
+    let nonce = take(&mut fields, NONCE_LEN)?;
+    let plaintext = cipher(passphrase, salt, memory_kib, passes)?
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Sealed {
+                msg: body,
+                aad: header,
+            },
+        )
+        .map_err(|_| "wrong passphrase or corrupted backup".to_string())?;
+    Payload::decode(&plaintext)
+}
+
+fn restore(payload: Payload, file_dir: &Path, trace_id: Option<&str>) -> Result<String, String> {
+    // Keep a loaded client from writing over the restored files, and make it
+    // reload them on next use.
+    let client_mutex = get_or_create_channel_mutex(&payload.camera_name, CHANNEL_FIXED);
+    let op = "import_camera_backup".to_string();
+    let mut client_guard = lock_client_with_owner(
+        &client_mutex,
+        &payload.camera_name,
+        CHANNEL_FIXED,
+        &op,
+        trace_id,
+    )
+    .ok_or_else(|| {
+        metrics::record_error(metrics::ERROR_BUSY);
+        "Error: Busy".to_string()
+    })?;
+
+    refuse_newer_state(file_dir, &payload)?;
+    for (relative, contents) in &payload.files {
+        let path = safe_join(file_dir, relative)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        // Write then rename, so an interrupted restore leaves whole files.
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".restore-tmp");
+        fs::write(&tmp, contents).map_err(|e| e.to_string())?;
+        fs::rename(&tmp, &path).map_err(|e| e.to_string())?;
+    }
+    // Whatever the backup doesn't have would mix with the restored state.
+    let restored: HashSet<&str> = payload
+        .files
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    for (name, path) in state_files(file_dir)? {
+        if !restored.contains(name.as_str()) {
+            fs::remove_file(&path).map_err(|e| e.to_string())?;
+        }
+    }
+    client_guard.reset();
+
+    info!(
+        "Restored backup of camera {} ({} files, epoch {:?})",
+        payload.camera_name,
+        payload.files.len(),
+        payload.epoch
+    );
+    Ok(payload.camera_name)
+}
@@ -0,0 +1,82 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Camera state changes pushed to Dart as they happen, so the UI does not have
+//! to reconstruct them from the return strings of individual calls.
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::api::logger;
+use crate::frb_generated::StreamSink;
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraEventKind {
+    /// `initialize_camera` stored the state directory; the client loads lazily.
+    Registered,
+    /// The client was loaded from (or created in) the state directory.
+    ClientInitialized,
+    ClientInitFailed,
+    Paired,
+    PairingFailed,
+    Deregistered,
+    /// `detail` carries the firmware version.
+    HeartbeatHealthy,
+    /// `detail` carries the result Dart would have received.
+    HeartbeatFailed,
+    /// A call gave up waiting for the client lock; `detail` names the holder.
+    LockTimeout,
+}
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct CameraEvent {
+    pub time_millis: i64,
+    pub camera_name: String,
+    pub kind: CameraEventKind,
+    pub detail: Option<String>,
+    pub trace_id: Option<String>,
+}
+
+static SUBSCRIBERS: Lazy<Mutex<Vec<StreamSink<CameraEvent>>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Receive every camera event from now on. Each isolate that subscribes gets
+/// its own copy; closed streams are dropped on the next event.
+#[flutter_rust_bridge::frb]
+pub fn subscribe_camera_events(sink: StreamSink<CameraEvent>) {
+    SUBSCRIBERS.lock().push(sink);
+}
+
+// Events kept for `take_camera_events_for_tests`, once it has been called.
+#[cfg(feature = "test-support")]
+static RECORDED: Lazy<Mutex<Option<Vec<CameraEvent>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Events emitted since the previous call. Tests can't subscribe without a Dart
+/// stream, so the first call starts recording instead.
+#[cfg(feature = "test-support")]
+#[flutter_rust_bridge::frb(ignore)]
+pub fn take_camera_events_for_tests() -> Vec<CameraEvent> {
+    RECORDED.lock().replace(Vec::new()).unwrap_or_default()
+}
+
+pub(crate) fn emit(camera_name: &str, kind: CameraEventKind, detail: Option<String>) {
+    let event = CameraEvent {
+        time_millis: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_else(|_| Duration::from_secs(0))
+            .as_millis() as i64,
+        camera_name: camera_name.to_string(),
+        kind,
+        detail,
+        trace_id: logger::current_call_context().trace_id,
+    };
+    #[cfg(feature = "test-support")]
+    if let Some(recorded) = RECORDED.lock().as_mut() {
+        recorded.push(event.clone());
+    }
+    SUBSCRIBERS
+        .lock()
+        .retain(|sink| sink.add(event.clone()).is_ok());
+}
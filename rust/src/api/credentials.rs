@@ -0,0 +1,253 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Server credentials handed to the camera during pairing.
+//!
+//! They used to travel as `username + password + address` with no separator,
+//! which only works because the camera splits them at the fixed lengths the
+//! relay server issues usernames and passwords in. Version 1 of the encoding
+//! makes every field self-delimiting:
+//!
+//! ```text
+//! "SCRED1;" <len>:<username> <len>:<password> <len>:<address>
+//! ```
+//!
+//! where each `<len>` is the decimal byte length of the field that follows
+//! (written without the spaces shown above). A string without the `SCRED`
+//! prefix is read the legacy way. [`ServerCredentials::validate`] still holds
+//! the fields to the fixed lengths, so either reading gives the same result.
+
+/// Byte length of a relay server username (`Constants.usernameLength` in Dart).
+pub const USERNAME_LEN: usize = 14;
+/// Byte length of a relay server password (`Constants.passwordLength` in Dart).
+pub const PASSWORD_LEN: usize = 14;
+const MAX_ADDRESS_LEN: usize = 2048;
+const MAGIC: &str = "SCRED";
+const FORMAT_VERSION: u32 = 1;
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerCredentials {
+    /// Base URL of the relay server, e.g. `https://relay.example.com:8000`.
+    pub address: String,
+    pub username: String,
+    pub password: String,
+}
+
+// ASCII only, so the camera's byte split and Dart's length checks agree.
+fn check_field(name: &str, value: &str, len: Option<usize>) -> Result<(), String> {
+    if value.is_empty() {
+        return Err(format!("server {} must not be empty", name));
+    }
+    if !value.is_ascii() {
+        return Err(format!("server {} is not ASCII", name));
+    }
+    if value.chars().any(|c| c.is_ascii_control()) {
+        return Err(format!("server {} contains control characters", name));
+    }
+    match len {
+        Some(len) if value.len() != len => Err(format!(
+            "server {} must be {} bytes, not {}",
+            name,
+            len,
+            value.len()
+        )),
+        None if value.len() > MAX_ADDRESS_LEN => Err(format!(
+            "server {} is longer than {} bytes",
+            name, MAX_ADDRESS_LEN
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl ServerCredentials {
+    /// Check that every field is something the camera can use.
+    pub fn validate(&self) -> Result<(), String> {
+        check_field("username", &self.username, Some(USERNAME_LEN))?;
+        if self.username.contains(' ') {
+            return Err("server username contains whitespace".to_string());
+        }
+        check_field("password", &self.password, Some(PASSWORD_LEN))?;
+        check_field("address", &self.address, None)?;
+        if self.address.contains(' ') {
+            return Err("server address contains whitespace".to_string());
+        }
+        let Some(rest) = self
+            .address
+            .strip_prefix("https://")
+            .or_else(|| self.address.strip_prefix("http://"))
+        else {
+            return Err("server address must start with http:// or https://".to_string());
+        };
+        let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        if host.is_empty() || host.starts_with(':') {
+            return Err("server address has no host".to_string());
+        }
+        Ok(())
+    }
+
+    /// The version 1 encoding described at the top of this module.
+    pub(crate) fn encode(&self) -> String {
+        let mut out = format!("{}{};", MAGIC, FORMAT_VERSION);
+        for field in [&self.username, &self.password, &self.address] {
+            out.push_str(&format!("{}:{}", field.len(), field));
+        }
+        out
+    }
+
+    /// Parse an encoded string the way the camera does: the versioned encoding
+    /// if it has the prefix, the legacy concatenation otherwise.
+    #[cfg(any(test, feature = "test-support"))]
+    pub(crate) fn decode(encoded: &str) -> Result<Self, String> {
+        let Some(rest) = encoded.strip_prefix(MAGIC) else {
+            return Self::decode_legacy(encoded);
+        };
+        let (version, mut rest) = rest
+            .split_once(';')
+            .ok_or_else(|| "missing credentials format version".to_string())?;
+        if version != FORMAT_VERSION.to_string() {
+            return Err(format!(
+                "unsupported credentials format version {}",
+                version
+            ));
+        }
+
+        let mut fields = Vec::with_capacity(3);
+        for _ in 0..3 {
+            let (len, tail) = rest
+                .split_once(':')
+                .ok_or_else(|| "truncated credentials".to_string())?;
+            let len: usize = len
+                .parse()
+                .map_err(|_| format!("bad credentials field length {:?}", len))?;
+            let field = tail
+                .get(..len)
+                .ok_or_else(|| "truncated credentials".to_string())?;
+            fields.push(field.to_string());
+            rest = &tail[len..];
+        }
+        if !rest.is_empty() {
+            return Err("trailing data after credentials".to_string());
+        }
+
+        let address = fields.pop().unwrap_or_default();
+        let password = fields.pop().unwrap_or_default();
+        let username = fields.pop().unwrap_or_default();
+        Ok(ServerCredentials {
+            address,
+            username,
+            password,
+        })
+    }
+
+    // Split the legacy concatenation at the fixed field lengths.
+    #[cfg(any(test, feature = "test-support"))]
+    fn decode_legacy(encoded: &str) -> Result<Self, String> {
+        let split = |from: usize, to: usize| {
+            encoded
+                .get(from..to)
+                .ok_or_else(|| "truncated credentials".to_string())
+        };
+        let username = split(0, USERNAME_LEN)?;
+        let password = split(USERNAME_LEN, USERNAME_LEN + PASSWORD_LEN)?;
+        let address = split(USERNAME_LEN + PASSWORD_LEN, encoded.len())?;
+        Ok(ServerCredentials {
+            address: address.to_string(),
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn credentials() -> impl Strategy<Value = ServerCredentials> {
+        (
+            "[A-Za-z0-9]{14}",
+            "[!-~][ -~]{12}[!-~]",
+            "https?://[a-z0-9.-]{1,40}(:[0-9]{1,5})?(/[!-~]{0,40})?",
+        )
+            .prop_map(|(username, password, address)| ServerCredentials {
+                address,
+                username,
+                password,
+            })
+    }
+
+    fn legacy(credentials: &ServerCredentials) -> String {
+        format!(
+            "{}{}{}",
+            credentials.username, credentials.password, credentials.address
+        )
+    }
+
+    #[test]
+    fn encodes_with_a_version_and_field_lengths() {
+        let credentials = ServerCredentials {
+            address: "https://relay.example.com:8000".to_string(),
+            username: "u".repeat(USERNAME_LEN),
+            password: "p".repeat(PASSWORD_LEN),
+        };
+        assert_eq!(credentials.validate(), Ok(()));
+        assert_eq!(
+            credentials.encode(),
+            format!(
+                "SCRED1;14:{}14:{}30:https://relay.example.com:8000",
+                "u".repeat(USERNAME_LEN),
+                "p".repeat(PASSWORD_LEN)
+            )
+        );
+        assert_eq!(
+            ServerCredentials::decode(&credentials.encode()),
+            Ok(credentials)
+        );
+    }
+
+    #[test]
+    fn decode_rejects_malformed_strings() {
+        assert!(ServerCredentials::decode("").is_err());
+        assert!(ServerCredentials::decode("SCRED2;1:u1:p1:a").is_err());
+        assert!(ServerCredentials::decode("SCRED1;1:u1:p").is_err());
+        assert!(ServerCredentials::decode("SCRED1;1:u1:p9:a").is_err());
+        assert!(ServerCredentials::decode("SCRED1;1:u1:p1:aextra").is_err());
+        assert!(ServerCredentials::decode("SCRED1;x:u1:p1:a").is_err());
+        // Legacy strings the camera cannot split.
+        assert!(ServerCredentials::decode(&"x".repeat(USERNAME_LEN + 3)).is_err());
+        let encoded = format!("{}é{}", "u".repeat(USERNAME_LEN - 1), "p".repeat(20));
+        assert!(ServerCredentials::decode(&encoded).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn valid_credentials_round_trip(credentials in credentials()) {
+            prop_assert_eq!(credentials.validate(), Ok(()));
+            prop_assert_eq!(ServerCredentials::decode(&credentials.encode()), Ok(credentials.clone()));
+            prop_assert_eq!(ServerCredentials::decode(&legacy(&credentials)), Ok(credentials));
+        }
+
+        #[test]
+        fn fields_of_the_wrong_length_are_refused(
+            credentials in credentials(),
+            username in "[A-Za-z0-9]{0,30}",
+            password in "[A-Za-z0-9]{0,30}",
+        ) {
+            let username_ok = username.len() == USERNAME_LEN;
+            let password_ok = password.len() == PASSWORD_LEN;
+            let credentials = ServerCredentials { username, password, ..credentials };
+            prop_assert_eq!(credentials.validate().is_ok(), username_ok && password_ok);
+        }
+
+        #[test]
+        fn decode_never_panics(encoded in ".{0,80}") {
+            if let Ok(credentials) = ServerCredentials::decode(&encoded) {
+                if encoded.starts_with(MAGIC) {
+                    prop_assert_eq!(credentials.encode(), encoded);
+                } else {
+                    prop_assert_eq!(legacy(&credentials), encoded);
+                }
+            }
+        }
+    }
+}
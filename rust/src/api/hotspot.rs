@@ -0,0 +1,306 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Waiting for a proprietary camera to come up on its hotspot.
+//!
+//! Dart used to poll `ping_proprietary_device` once a second, paying an FFI
+//! round trip per probe. `wait_for_proprietary_device` runs the whole loop here
+//! and reports what it saw.
+
+use log::debug;
+use once_cell::sync::Lazy;
+use std::net::{IpAddr, Ipv6Addr, Shutdown, SocketAddr, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::info_span;
+
+use super::waiters::Waiters;
+
+/// Port the camera listens on for pairing while it runs its hotspot.
+pub(crate) const PAIRING_PORT: u16 = 12348;
+const PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+// Sleeps are cut into slices this long so cancellation is noticed quickly.
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+static DEVICE_WAITERS: Lazy<Waiters> = Lazy::new(|| Waiters::new("device"));
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitStatus {
+    Ready,
+    TimedOut,
+    Cancelled,
+}
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct WaitOutcome {
+    pub status: WaitStatus,
+    /// Probes made, successful or not.
+    pub attempts: u32,
+    /// Successful probes in a row when the wait ended.
+    pub stable_polls: u32,
+    /// How long each probe took to connect or fail, in order.
+    pub probe_latencies_micros: Vec<u64>,
+    pub elapsed_millis: u64,
+    /// Why the most recent failed probe failed.
+    pub last_error: Option<String>,
+}
+
+// Split "host", "host:port", "[v6]", "[v6]:port" or a bare IPv6 literal.
+fn split_host_port(address: &str) -> Result<(&str, Option<u16>), String> {
+    let parse_port = |text: &str| {
+        text.parse::<u16>()
+            .ok()
+            .filter(|port| *port != 0)
+            .ok_or_else(|| format!("invalid port {:?} in camera address {:?}", text, address))
+    };
+
+    if let Some(rest) = address.strip_prefix('[') {
+        let (host, after) = rest
+            .split_once(']')
+            .ok_or_else(|| format!("unclosed '[' in camera address {:?}", address))?;
+        if host.parse::<Ipv6Addr>().is_err() {
+            return Err(format!("{:?} in brackets is not an IPv6 address", host));
+        }
+        return match after {
+            "" => Ok((host, None)),
+            after => match after.strip_prefix(':') {
+                Some(port) => Ok((host, Some(parse_port(port)?))),
+                None => Err(format!(
+                    "unexpected {:?} after ']' in camera address {:?}",
+                    after, address
+                )),
+            },
+        };
+    }
+    if address.matches(':').count() > 1 {
+        return match address.parse::<Ipv6Addr>() {
+            Ok(_) => Ok((address, None)),
+            Err(_) => Err(format!(
+                "{:?} is not an IPv6 address (put IPv6 addresses with a port in brackets, e.g. [fe80::1]:{})",
+                address, PAIRING_PORT
+            )),
+        };
+    }
+    match address.rsplit_once(':') {
+        Some((host, port)) => Ok((host, Some(parse_port(port)?))),
+        None => Ok((address, None)),
+    }
+}
+
+/// Resolve a camera address given as an IPv4 or IPv6 address or a hostname,
+/// optionally with a port (IPv6 in brackets then). `port` is used when the
+/// address names none, and the pairing port when neither does.
+pub(super) fn resolve_device_addr(address: &str, port: Option<u16>) -> Result<SocketAddr, String> {
+    let address = address.trim();
+    if address.is_empty() {
+        return Err("camera address is empty".to_string());
+    }
+    let (host, address_port) = split_host_port(address)?;
+    let port = match (address_port, port) {
+        (Some(named), Some(given)) if named != given => {
+            return Err(format!(
+                "camera address {:?} names port {} but port {} was given",
+                address, named, given
+            ));
+        }
+        (Some(port), _) | (None, Some(port)) => port,
+        (None, None) => PAIRING_PORT,
+    };
+    if port == 0 {
+        return Err("port 0 is not a valid camera port".to_string());
+    }
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(ip, port));
+    }
+
+    let is_hostname = host.split('.').all(|label| {
+        !label.is_empty()
+            && label.len() <= 63
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+    });
+    if !is_hostname {
+        return Err(format!(
+            "{:?} is neither an IP address nor a hostname",
+            host
+        ));
+    }
+    (host, port)
+        .to_socket_addrs()
+        .map_err(|e| format!("could not resolve camera host {:?}: {}", host, e))?
+        .next()
+        .ok_or_else(|| format!("camera host {:?} resolved to no addresses", host))
+}
+
+/// Resolve the address of a camera to pair with, once the client backend has
+/// agreed it can pair with such an address at all.
+pub(super) fn device_addr(ip: &str) -> Result<SocketAddr, String> {
+    crate::backend::new_backend().check_pairing_address(ip)?;
+    resolve_device_addr(ip, None)
+}
+
+/// Try to open a connection to `addr`, returning how long it took.
+pub(super) fn probe(addr: &SocketAddr, timeout: Duration) -> Result<Duration, (Duration, String)> {
+    let started = Instant::now();
+    match TcpStream::connect_timeout(addr, timeout) {
+        Ok(stream) => {
+            let _ = stream.shutdown(Shutdown::Both);
+            Ok(started.elapsed())
+        }
+        Err(e) => Err((started.elapsed(), e.to_string())),
+    }
+}
+
+// Sleep for `duration` unless cancelled first. Returns false if cancelled.
+fn sleep_unless_cancelled(duration: Duration, cancel: &AtomicBool) -> bool {
+    let until = Instant::now() + duration;
+    loop {
+        if cancel.load(Ordering::SeqCst) {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= until {
+            return true;
+        }
+        thread::sleep(CANCEL_CHECK_INTERVAL.min(until - now));
+    }
+}
+
+/// Check once whether the camera at `address` (see `resolve_device_addr` for
+/// the accepted forms) accepts connections on `port`, or the pairing port.
+/// Unlike `ping_proprietary_device`, bad input is an error rather than false.
+#[flutter_rust_bridge::frb]
+pub fn probe_proprietary_device(address: String, port: Option<u16>) -> Result<bool, String> {
+    let addr = resolve_device_addr(&address, port)?;
+    let _span = info_span!("probe_proprietary_device", %addr).entered();
+    match probe(&addr, PROBE_TIMEOUT) {
+        Ok(_) => Ok(true),
+        Err((_, e)) => {
+            debug!("Proprietary device probe of {} failed: {}", addr, e);
+            Ok(false)
+        }
+    }
+}
+
+/// Reserve an id for a `wait_for_proprietary_device` call, so the wait can be
+/// cancelled with `cancel_device_wait` while it runs.
+#[flutter_rust_bridge::frb]
+pub fn new_device_waiter() -> String {
+    DEVICE_WAITERS.reserve()
+}
+
+/// Probe the camera at `camera_ip` (and `port`, as for
+/// `probe_proprietary_device`) every `poll_interval_millis` until it has
+/// answered `required_stable_polls` times in a row, then wait
+/// `settle_delay_millis` more and report it ready. Gives up after
+/// `timeout_millis`, or when `cancel_device_wait(waiter_id)` is called.
+/// `waiter_id` comes from `new_device_waiter` and is used up by the wait.
+#[flutter_rust_bridge::frb]
+pub fn wait_for_proprietary_device(
+    camera_ip: String,
+    port: Option<u16>,
+    timeout_millis: u64,
+    poll_interval_millis: u64,
+    required_stable_polls: u32,
+    settle_delay_millis: u64,
+    waiter_id: String,
+) -> Result<WaitOutcome, String> {
+    let waiting = DEVICE_WAITERS.begin(&waiter_id)?;
+    resolve_device_addr(&camera_ip, port).map(|addr| {
+        wait_for_device(
+            addr,
+            timeout_millis,
+            poll_interval_millis,
+            required_stable_polls,
+            settle_delay_millis,
+            waiting.cancel_flag(),
+        )
+    })
+}
+
+fn wait_for_device(
+    addr: SocketAddr,
+    timeout_millis: u64,
+    poll_interval_millis: u64,
+    required_stable_polls: u32,
+    settle_delay_millis: u64,
+    cancel: &AtomicBool,
+) -> WaitOutcome {
+    let _span = info_span!("wait_for_proprietary_device", %addr).entered();
+
+    let started = Instant::now();
+    let deadline = started + Duration::from_millis(timeout_millis);
+    let poll_interval = Duration::from_millis(poll_interval_millis);
+    let required_stable_polls = required_stable_polls.max(1);
+    let mut outcome = WaitOutcome {
+        status: WaitStatus::TimedOut,
+        attempts: 0,
+        stable_polls: 0,
+        probe_latencies_micros: Vec::new(),
+        elapsed_millis: 0,
+        last_error: None,
+    };
+
+    loop {
+        if cancel.load(Ordering::SeqCst) {
+            outcome.status = WaitStatus::Cancelled;
+            break;
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        outcome.attempts += 1;
+        let latency = match probe(&addr, PROBE_TIMEOUT.min(remaining)) {
+            Ok(latency) => {
+                outcome.stable_polls += 1;
+                latency
+            }
+            Err((latency, e)) => {
+                outcome.stable_polls = 0;
+                outcome.last_error = Some(e);
+                latency
+            }
+        };
+        outcome
+            .probe_latencies_micros
+            .push(latency.as_micros() as u64);
+        debug!(
+            "Probe {} of {}: stable_polls={} latency={:?}",
+            outcome.attempts, addr, outcome.stable_polls, latency
+        );
+
+        if outcome.stable_polls >= required_stable_polls {
+            outcome.status =
+                if sleep_unless_cancelled(Duration::from_millis(settle_delay_millis), cancel) {
+                    WaitStatus::Ready
+                } else {
+                    WaitStatus::Cancelled
+                };
+            break;
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if !sleep_unless_cancelled(poll_interval.min(remaining), cancel) {
+            outcome.status = WaitStatus::Cancelled;
+            break;
+        }
+    }
+
+    outcome.elapsed_millis = started.elapsed().as_millis() as u64;
+    debug!(
+        "Waiting for {} ended {:?} after {} attempts",
+        addr, outcome.status, outcome.attempts
+    );
+    outcome
+}
+
+/// Cancel a pending `wait_for_proprietary_device`. Returns false if the id is
+/// unknown or its wait has already ended. Ids no wait takes expire after a
+/// minute.
+#[flutter_rust_bridge::frb]
+pub fn cancel_device_wait(waiter_id: String) -> bool {
+    DEVICE_WAITERS.cancel(&waiter_id)
+}
@@ -0,0 +1,746 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Saving part of a livestream to a local MP4 file.
+//!
+//! The camera streams fragmented MP4: an init segment (`ftyp` + `moov`) at the
+//! start of the stream, then `moof` + `mdat` fragments. Every chunk that
+//! `livestream_decrypt` opens is fed through `observe`, which splits the bytes
+//! back into top-level boxes and remembers the init segment, so a recording
+//! started mid-stream still gets one. A recording writes the init segment and
+//! then every fragment from the next `moof` on, until it is stopped or the
+//! stream restarts with a new init segment. On stop, the durations in the
+//! init segment (which the camera leaves at zero) are filled in from the
+//! fragments, as `Mp4DurationFixer` in `lib/utilities/mp4_fix.dart` does for
+//! livestream clips.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use tracing::info_span;
+
+use super::logger::{self, CallContext};
+use super::{metrics, resolve_call};
+
+/// Per-camera stream state, keyed by camera name. The map is only locked to
+/// look a tap up; each tap has its own lock.
+static STREAMS: Lazy<Mutex<HashMap<String, Arc<Mutex<StreamTap>>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Largest `ftyp` or `moov` kept for the init segment. The camera's are a few
+/// hundred bytes; anything this big means the stream was misread.
+const MAX_INIT_BOX_LEN: u64 = 1 << 20;
+/// Chunks queued for a recording's writer thread before the recording fails.
+/// Each is the recorded part of one decrypted livestream chunk.
+const WRITE_QUEUE_CHUNKS: usize = 256;
+
+/// Splits a stream into top-level boxes as it arrives. Only the header of
+/// each box is buffered; its payload is copied only if it belongs to the init
+/// segment or to a running recording.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct StreamTap {
+    /// Header bytes of the next box, while they are still arriving.
+    header: Vec<u8>,
+    /// The box whose payload is arriving.
+    current: Option<CurrentBox>,
+    /// A box header made no sense. Nothing is parsed until a chunk starts a
+    /// new stream (`ftyp`) or a new fragment (`moof`).
+    lost: bool,
+    /// `ftyp` + `moov` of the current stream.
+    init: Vec<u8>,
+    ftyp_len: usize,
+    init_complete: bool,
+    recording: Option<Recording>,
+}
+
+struct CurrentBox {
+    kind: [u8; 4],
+    remaining: u64,
+    sink: Sink,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Sink {
+    Skip,
+    Init,
+    Recording,
+}
+
+struct Recording {
+    path: PathBuf,
+    /// Bytes for the writer thread, which owns the file.
+    queue: SyncSender<Vec<u8>>,
+    writer: Writer,
+    /// What the current livestream chunk adds to the recording, queued as one
+    /// chunk once the livestream chunk has been parsed.
+    pending: Vec<u8>,
+    /// Chunks not written because the writer fell behind or stopped.
+    dropped_chunks: u64,
+    /// Whether the init segment has been queued, i.e. a `moof` has been seen.
+    started: bool,
+    fragments: u32,
+    /// Why the recording stopped taking video before it was stopped.
+    ended: Option<String>,
+    error: Option<String>,
+}
+
+/// A recording's writer thread, returning the bytes it wrote.
+type Writer = JoinHandle<Result<u64, String>>;
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct RecordingSummary {
+    pub path: String,
+    /// `moof` + `mdat` fragments written.
+    pub fragments: u32,
+    pub frames: u32,
+    pub duration_millis: u64,
+    pub bytes: u64,
+}
+
+struct BoxHeader {
+    /// Declared size including the header; 0 means "to the end of the file".
+    size: u64,
+    kind: [u8; 4],
+    header_len: usize,
+}
+
+// None if `data` is too short to hold the header.
+fn box_header(data: &[u8]) -> Option<BoxHeader> {
+    let size = u32::from_be_bytes(data.get(0..4)?.try_into().ok()?) as u64;
+    let kind: [u8; 4] = data.get(4..8)?.try_into().ok()?;
+    if size == 1 {
+        let size = u64::from_be_bytes(data.get(8..16)?.try_into().ok()?);
+        return Some(BoxHeader {
+            size,
+            kind,
+            header_len: 16,
+        });
+    }
+    Some(BoxHeader {
+        size,
+        kind,
+        header_len: 8,
+    })
+}
+
+impl StreamTap {
+    fn push(&mut self, camera_name: &str, mut data: &[u8]) {
+        // A new stream starts with a new init segment, and a lost stream can
+        // pick up again at a fragment; either way, the box that was arriving
+        // will not be completed.
+        let kind = data.get(4..8);
+        if kind == Some(b"ftyp".as_slice()) || (self.lost && kind == Some(b"moof".as_slice())) {
+            if self.current.take().is_some() || !self.header.is_empty() {
+                warn!(
+                    "Livestream for {} restarted mid-box; dropping the partial box",
+                    camera_name
+                );
+            }
+            self.header.clear();
+            self.lost = false;
+        }
+
+        while !data.is_empty() && !self.lost {
+            let Some(current) = self.current.as_mut() else {
+                // Up to the largesize field; `box_header` says when it is enough.
+                let wanted = if self.header.get(0..4) == Some([0, 0, 0, 1].as_slice()) {
+                    16
+                } else {
+                    8
+                };
+                let take = (wanted - self.header.len()).min(data.len());
+                self.header.extend_from_slice(&data[..take]);
+                data = &data[take..];
+                if let Some(header) = box_header(&self.header) {
+                    self.begin_box(camera_name, header);
+                }
+                continue;
+            };
+            let take = current.remaining.min(data.len() as u64) as usize;
+            match current.sink {
+                Sink::Skip => {}
+                Sink::Init => self.init.extend_from_slice(&data[..take]),
+                Sink::Recording => {
+                    if let Some(recording) = self.recording.as_mut() {
+                        recording.send(&data[..take]);
+                    }
+                }
+            }
+            current.remaining -= take as u64;
+            data = &data[take..];
+            if current.remaining == 0 {
+                self.end_box();
+            }
+        }
+        if let Some(recording) = self.recording.as_mut() {
+            recording.flush();
+        }
+    }
+
+    fn begin_box(&mut self, camera_name: &str, header: BoxHeader) {
+        // A size of 0 ("to the end of the file") has no end in a stream.
+        if header.size < header.header_len as u64 {
+            warn!(
+                "Livestream for {} has a malformed {:?} box; skipping to the next fragment",
+                camera_name,
+                String::from_utf8_lossy(&header.kind)
+            );
+            self.header.clear();
+            self.lost = true;
+            return;
+        }
+        let sink = match &header.kind {
+            b"ftyp" | b"moov" => {
+                if header.size > MAX_INIT_BOX_LEN {
+                    warn!(
+                        "Livestream for {} has a {} byte init box; skipping to the next fragment",
+                        camera_name, header.size
+                    );
+                    self.header.clear();
+                    self.lost = true;
+                    return;
+                }
+                if &header.kind == b"ftyp" {
+                    self.init.clear();
+                } else {
+                    self.init.truncate(self.ftyp_len);
+                }
+                self.init_complete = false;
+                // Fragments after a new init segment may not decode with the
+                // one already written, so the recording ends here.
+                if let Some(recording) = self.recording.as_mut() {
+                    if recording.started && recording.ended.is_none() {
+                        info!(
+                            "Livestream for {} restarted; its recording ends at the restart",
+                            camera_name
+                        );
+                        recording.ended = Some("the livestream restarted".to_string());
+                    }
+                }
+                Sink::Init
+            }
+            _ => match self.recording.as_mut() {
+                Some(recording)
+                    if self.init_complete
+                        && recording.ended.is_none()
+                        && (recording.started || &header.kind == b"moof") =>
+                {
+                    // Fragments are only playable from a moof on.
+                    if !recording.started {
+                        recording.send(&self.init);
+                        recording.started = true;
+                    }
+                    if &header.kind == b"moof" {
+                        recording.fragments += 1;
+                    }
+                    Sink::Recording
+                }
+                _ => Sink::Skip,
+            },
+        };
+        match sink {
+            Sink::Skip => {}
+            Sink::Init => self.init.extend_from_slice(&self.header),
+            Sink::Recording => {
+                if let Some(recording) = self.recording.as_mut() {
+                    recording.send(&self.header);
+                }
+            }
+        }
+        self.header.clear();
+        self.current = Some(CurrentBox {
+            kind: header.kind,
+            remaining: header.size - header.header_len as u64,
+            sink,
+        });
+        if header.size == header.header_len as u64 {
+            self.end_box();
+        }
+    }
+
+    fn end_box(&mut self) {
+        let Some(current) = self.current.take() else {
+            return;
+        };
+        if current.sink != Sink::Init {
+            return;
+        }
+        match &current.kind {
+            b"ftyp" => self.ftyp_len = self.init.len(),
+            _ => self.init_complete = true,
+        }
+    }
+}
+
+impl Recording {
+    fn send(&mut self, bytes: &[u8]) {
+        self.pending.extend_from_slice(bytes);
+    }
+
+    // Queues what `send` collected. The bytes are copied once, as the
+    // decrypted chunk also goes back to Dart.
+    fn flush(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        // The file already has a gap, so nothing more is written.
+        if self.error.is_some() {
+            self.pending.clear();
+            self.dropped_chunks += 1;
+            return;
+        }
+        let chunk = std::mem::take(&mut self.pending);
+        let error = match self.queue.try_send(chunk) {
+            Ok(()) => return,
+            Err(TrySendError::Full(_)) => {
+                format!("writing {:?} fell behind the livestream", self.path)
+            }
+            // The writer stopped on an error, which `finish` reports.
+            Err(TrySendError::Disconnected(_)) => format!("writing {:?} stopped", self.path),
+        };
+        warn!("Recording stopped taking video: {}", error);
+        self.dropped_chunks += 1;
+        self.error = Some(error);
+    }
+}
+
+// Writes queued bytes to `file` until the queue is dropped; returns the
+// number of bytes written.
+fn spawn_writer(path: &Path, file: File) -> std::io::Result<(SyncSender<Vec<u8>>, Writer)> {
+    let (queue, chunks) = mpsc::sync_channel::<Vec<u8>>(WRITE_QUEUE_CHUNKS);
+    let path = path.to_path_buf();
+    let writer = thread::Builder::new()
+        .name("livestream-recording".to_string())
+        .spawn(move || {
+            let failed = |e: std::io::Error| format!("write to {:?} failed: {}", path, e);
+            let mut out = BufWriter::new(file);
+            let mut bytes = 0u64;
+            for chunk in chunks {
+                out.write_all(&chunk).map_err(failed)?;
+                bytes += chunk.len() as u64;
+            }
+            out.into_inner()
+                .map_err(|e| e.into_error())
+                .and_then(|file| file.sync_all())
+                .map_err(failed)?;
+            Ok(bytes)
+        })?;
+    Ok((queue, writer))
+}
+
+fn tap(camera_name: &str) -> Arc<Mutex<StreamTap>> {
+    let mut streams = STREAMS.lock();
+    match streams.get(camera_name) {
+        Some(tap) => tap.clone(),
+        None => streams.entry(camera_name.to_string()).or_default().clone(),
+    }
+}
+
+/// Feed one decrypted livestream chunk of `camera_name` to its recording, if
+/// any. Called with the client lock held, so chunks arrive in decrypt order;
+/// file writes happen on the recording's own thread.
+pub(super) fn observe(camera_name: &str, data: &[u8]) {
+    tap(camera_name).lock().push(camera_name, data);
+}
+
+/// Stop any recording of `camera_name` (finalizing what was written) and
+/// forget its stream state.
+pub(super) fn forget(camera_name: &str) {
+    let Some(tap) = STREAMS.lock().remove(camera_name) else {
+        return;
+    };
+    let recording = tap.lock().recording.take();
+    if let Some(recording) = recording {
+        if let Err(e) = finish(recording) {
+            warn!(
+                "Recording of {} abandoned on deregister: {}",
+                camera_name, e
+            );
+        }
+    }
+}
+
+/// Start saving the livestream of `camera_name` to `out_path`. The file is
+/// created (or truncated) now and holds video from the next fragment on.
+#[flutter_rust_bridge::frb]
+pub fn start_livestream_recording(
+    camera_name: String,
+    out_path: String,
+    context: Option<CallContext>,
+) -> Result<(), String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("start_livestream_recording", camera = %camera_name).entered();
+    let _metrics = metrics::track("start_livestream_recording", &camera_name);
+
+    let tap = tap(&camera_name);
+    let busy = |recording: &Recording| {
+        metrics::record_error(metrics::ERROR_BUSY);
+        format!(
+            "{} is already being recorded to {:?}",
+            camera_name, recording.path
+        )
+    };
+    if let Some(recording) = &tap.lock().recording {
+        return Err(busy(recording));
+    }
+    // Created outside the tap lock, which decrypting chunks wait on.
+    let path = PathBuf::from(out_path);
+    let (queue, writer) = File::create(&path)
+        .and_then(|file| spawn_writer(&path, file))
+        .map_err(|e| {
+            metrics::record_error(metrics::ERROR_FAILED);
+            format!("could not create {:?}: {}", path, e)
+        })?;
+    let mut tap = tap.lock();
+    if let Some(recording) = &tap.recording {
+        return Err(busy(recording));
+    }
+    info!("Recording livestream of {} to {:?}", camera_name, path);
+    tap.recording = Some(Recording {
+        path,
+        queue,
+        writer,
+        pending: Vec::new(),
+        dropped_chunks: 0,
+        started: false,
+        fragments: 0,
+        ended: None,
+        error: None,
+    });
+    Ok(())
+}
+
+/// Stop recording the livestream of `camera_name` and finalize the file.
+/// If no complete fragment arrived, the file is deleted and an error returned.
+#[flutter_rust_bridge::frb]
+pub fn stop_livestream_recording(
+    camera_name: String,
+    context: Option<CallContext>,
+) -> Result<RecordingSummary, String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("stop_livestream_recording", camera = %camera_name).entered();
+    let _metrics = metrics::track("stop_livestream_recording", &camera_name);
+
+    let tap = STREAMS.lock().get(&camera_name).cloned();
+    let recording = tap
+        .and_then(|tap| tap.lock().recording.take())
+        .ok_or_else(|| format!("{} is not being recorded", camera_name))?;
+    let result = finish(recording);
+    match &result {
+        Ok(summary) => info!(
+            "Recorded {} ms ({} frames) of {} to {}",
+            summary.duration_millis, summary.frames, camera_name, summary.path
+        ),
+        Err(_) => metrics::record_error(metrics::ERROR_FAILED),
+    }
+    result
+}
+
+fn finish(recording: Recording) -> Result<RecordingSummary, String> {
+    let Recording {
+        path,
+        queue,
+        writer,
+        pending: _,
+        dropped_chunks,
+        started,
+        fragments,
+        ended,
+        error,
+    } = recording;
+    // Dropping the queue lets the writer flush and exit.
+    drop(queue);
+    let with_dropped = |e: String| match dropped_chunks {
+        0 => e,
+        n => format!("{} ({} livestream chunks were not written)", e, n),
+    };
+    let bytes = writer
+        .join()
+        .unwrap_or_else(|_| Err(format!("writing {:?} panicked", path)))
+        .map_err(with_dropped)?;
+    if let Some(e) = error {
+        return Err(with_dropped(e));
+    }
+    if !started {
+        let _ = fs::remove_file(&path);
+        return Err("no video arrived while recording".to_string());
+    }
+    if let Some(reason) = ended {
+        info!("Recording to {:?} ended early: {}", path, reason);
+    }
+    let fixed = fix_duration(&path)?;
+    Ok(RecordingSummary {
+        path: path.to_string_lossy().into_owned(),
+        fragments,
+        frames: fixed.frames,
+        duration_millis: fixed.duration_millis,
+        bytes,
+    })
+}
+
+// MP4 duration fixing: a port of `Mp4DurationFixer.fix` in
+// lib/utilities/mp4_fix.dart (with `forceTrackIdFromTfhd` off), so recordings
+// come out as the Dart fixer would leave them. Keep the two in step.
+
+/// The Dart fixer's fragment timescale.
+const FRAGMENT_TIMESCALE: u64 = 90_000;
+
+struct Mp4Box {
+    kind: [u8; 4],
+    start: usize,
+    end: usize,
+}
+
+impl Mp4Box {
+    fn payload(&self) -> usize {
+        self.start + 8
+    }
+}
+
+// Walks 32-bit-sized boxes in `start..end`, stopping at the first that does
+// not fit, as the Dart loops do.
+fn children(f: &[u8], start: usize, end: usize) -> Vec<Mp4Box> {
+    let mut out = Vec::new();
+    let mut at = start;
+    while at + 8 <= end {
+        let size = u32::from_be_bytes(f[at..at + 4].try_into().unwrap()) as usize;
+        if size < 8 || size > end - at {
+            break;
+        }
+        out.push(Mp4Box {
+            kind: f[at + 4..at + 8].try_into().unwrap(),
+            start: at,
+            end: at + size,
+        });
+        at += size;
+    }
+    out
+}
+
+fn find<'a>(boxes: &'a [Mp4Box], kind: &[u8; 4]) -> Option<&'a Mp4Box> {
+    boxes.iter().find(|b| &b.kind == kind)
+}
+
+fn require<'a>(boxes: &'a [Mp4Box], kind: &[u8; 4]) -> Result<&'a Mp4Box, String> {
+    find(boxes, kind).ok_or_else(|| format!("no {} box", String::from_utf8_lossy(kind)))
+}
+
+fn read_u32(f: &[u8], at: usize) -> Result<u32, String> {
+    f.get(at..at + 4)
+        .map(|b| u32::from_be_bytes(b.try_into().unwrap()))
+        .ok_or_else(|| format!("truncated box at byte {}", at))
+}
+
+fn write_bytes(f: &mut [u8], at: usize, bytes: &[u8]) -> Result<(), String> {
+    f.get_mut(at..at + bytes.len())
+        .map(|dst| dst.copy_from_slice(bytes))
+        .ok_or_else(|| format!("truncated box at byte {}", at))
+}
+
+// Version 1 boxes have 64-bit times and durations; version 0 ones 32-bit,
+// where a longer duration is capped rather than wrapped.
+fn write_duration(f: &mut [u8], at: usize, wide: bool, value: u64) -> Result<(), String> {
+    if wide {
+        write_bytes(f, at, &value.to_be_bytes())
+    } else {
+        let value = u32::try_from(value).unwrap_or(u32::MAX);
+        write_bytes(f, at, &value.to_be_bytes())
+    }
+}
+
+// Offset of the field after creation/modification time in mvhd, tkhd and mdhd
+// (the timescale, or tkhd's track_ID), and whether the box is version 1.
+fn after_times(f: &[u8], full_box: &Mp4Box) -> Result<(usize, bool), String> {
+    let at = full_box.payload();
+    let wide = *f
+        .get(at)
+        .ok_or_else(|| format!("truncated box at byte {}", at))?
+        == 1;
+    Ok((at + 4 + if wide { 16 } else { 8 }, wide))
+}
+
+// `ticks` at 90 kHz in `timescale`, rounded to nearest.
+fn rescale(ticks: u64, timescale: u32) -> u64 {
+    ((ticks as u128 * timescale as u128 + FRAGMENT_TIMESCALE as u128 / 2)
+        / FRAGMENT_TIMESCALE as u128) as u64
+}
+
+struct FixedDuration {
+    frames: u32,
+    duration_millis: u64,
+}
+
+/// Sum the sample durations of every `trun` (taken to be 90 kHz ticks) and
+/// write the total into mvhd, tkhd, mdhd and mehd. A file with no sample
+/// durations is left as it is.
+fn fix_duration(path: &Path) -> Result<FixedDuration, String> {
+    let mut f = fs::read(path).map_err(|e| format!("could not read {:?}: {}", path, e))?;
+    let top = children(&f, 0, f.len());
+    let moov = require(&top, b"moov")?;
+    let moov_children = children(&f, moov.payload(), moov.end);
+    let mvhd = require(&moov_children, b"mvhd")?;
+    let trak = require(&moov_children, b"trak")?;
+    let trak_children = children(&f, trak.payload(), trak.end);
+    let tkhd = require(&trak_children, b"tkhd")?;
+    let mdia = require(&trak_children, b"mdia")?;
+    let mdia_children = children(&f, mdia.payload(), mdia.end);
+    let mdhd = require(&mdia_children, b"mdhd")?;
+
+    let (mvhd_timescale_at, mvhd_wide) = after_times(&f, mvhd)?;
+    let movie_timescale = read_u32(&f, mvhd_timescale_at)?;
+    let (tkhd_id_at, tkhd_wide) = after_times(&f, tkhd)?;
+    let tkhd_track_id = read_u32(&f, tkhd_id_at)?;
+    let (mdhd_timescale_at, mdhd_wide) = after_times(&f, mdhd)?;
+    let media_timescale = read_u32(&f, mdhd_timescale_at)?;
+
+    // The first tfhd's track; every traf's samples count, as in the Dart scan.
+    let mut tfhd_track_id = None;
+    let mut frames: u64 = 0;
+    let mut ticks: u64 = 0;
+    for moof in top.iter().filter(|b| &b.kind == b"moof") {
+        for traf in children(&f, moof.payload(), moof.end)
+            .iter()
+            .filter(|b| &b.kind == b"traf")
+        {
+            for child in children(&f, traf.payload(), traf.end) {
+                match &child.kind {
+                    b"tfhd" => {
+                        let track_id = read_u32(&f, child.payload() + 4)?;
+                        tfhd_track_id.get_or_insert(track_id);
+                    }
+                    b"trun" => {
+                        let flags = read_u32(&f, child.payload())? & 0x00ff_ffff;
+                        let sample_count = read_u32(&f, child.payload() + 4)? as u64;
+                        frames += sample_count;
+                        // Samples without a duration add nothing.
+                        if flags & 0x100 == 0 {
+                            continue;
+                        }
+                        let mut at = child.payload() + 8;
+                        if flags & 0x001 != 0 {
+                            at += 4; // data_offset
+                        }
+                        if flags & 0x004 != 0 {
+                            at += 4; // first_sample_flags
+                        }
+                        let stride = [0x100, 0x200, 0x400, 0x800]
+                            .iter()
+                            .filter(|bit| flags & **bit != 0)
+                            .count();
+                        if sample_count * stride as u64 * 4 > child.end.saturating_sub(at) as u64 {
+                            return Err(format!(
+                                "trun at byte {} is shorter than its {} samples",
+                                child.start, sample_count
+                            ));
+                        }
+                        for sample in
+                            f[at..at + sample_count as usize * stride * 4].chunks_exact(stride * 4)
+                        {
+                            let duration = u32::from_be_bytes(sample[..4].try_into().unwrap());
+                            ticks = ticks
+                                .checked_add(duration as u64)
+                                .ok_or("the recorded sample durations overflow")?;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    if frames == 0 || ticks == 0 {
+        return Ok(FixedDuration {
+            frames: 0,
+            duration_millis: 0,
+        });
+    }
+
+    write_duration(&mut f, mvhd_timescale_at + 4, mvhd_wide, ticks)?;
+    if tkhd_track_id == 0 {
+        if let Some(track_id) = tfhd_track_id {
+            write_bytes(&mut f, tkhd_id_at, &track_id.to_be_bytes())?;
+        }
+    }
+    write_duration(
+        &mut f,
+        tkhd_id_at + 8,
+        tkhd_wide,
+        rescale(ticks, movie_timescale),
+    )?;
+    write_duration(
+        &mut f,
+        mdhd_timescale_at + 4,
+        mdhd_wide,
+        rescale(ticks, media_timescale),
+    )?;
+    if let Some(mvex) = find(&moov_children, b"mvex") {
+        if let Some(mehd) = children(&f, mvex.payload(), mvex.end)
+            .iter()
+            .rfind(|b| &b.kind == b"mehd")
+        {
+            let (at, wide) = (mehd.payload(), f.get(mehd.payload()) == Some(&1));
+            write_duration(&mut f, at + 4, wide, ticks)?;
+        }
+    }
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp_fix");
+    let tmp = PathBuf::from(tmp);
+    let write = || -> std::io::Result<()> {
+        let mut file = File::create(&tmp)?;
+        file.write_all(&f)?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    };
+    write().map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        format!("could not rewrite {:?}: {}", path, e)
+    })?;
+
+    Ok(FixedDuration {
+        frames: u32::try_from(frames).unwrap_or(u32::MAX),
+        duration_millis: rescale(ticks, 1000),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_dropped_behind_a_full_queue_are_reported() {
+        let (queue, _chunks) = mpsc::sync_channel(1);
+        let mut recording = Recording {
+            path: PathBuf::from("behind.mp4"),
+            queue,
+            writer: thread::spawn(|| Ok(0)),
+            pending: Vec::new(),
+            dropped_chunks: 0,
+            started: true,
+            fragments: 1,
+            ended: None,
+            error: None,
+        };
+        for chunk in [b"one", b"two", b"six"] {
+            recording.send(chunk);
+            recording.flush();
+        }
+        assert_eq!(recording.dropped_chunks, 2);
+        let error = finish(recording).unwrap_err();
+        assert!(error.contains("fell behind"), "{}", error);
+        assert!(
+            error.contains("2 livestream chunks were not written"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,488 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Livestream sessions with an explicit lifecycle.
+//!
+//! `livestream_update` and `livestream_decrypt` act on whatever livestream
+//! state the client has, so a viewer that never cleaned up, or a second
+//! viewer of the same camera, can apply a key update under the first one. A
+//! session is opened by one viewer, must receive its key update before
+//! chunks decrypt, and once closed (explicitly or by `deregister_camera`)
+//! refuses anything sent with its id. At most one session per camera is open;
+//! one left idle for a minute expires, and a new viewer may take over an open
+//! one. Closing a session drops the client's livestream state.
+//!
+//! The legacy functions share the update and decrypt paths here, and are
+//! refused while a session is open.
+
+use log::info;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::info_span;
+
+use crate::backend::{CameraClientBackend, ClientError};
+
+use super::logger::CallContext;
+use super::{
+    ensure_client_initialized, get_or_create_channel_mutex, livestream_recording, livestream_stats,
+    lock_client_with_owner, logger, metrics, resolve_call, CHANNEL_FIXED,
+};
+
+static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// How long an open session may go without a call before it expires.
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+static SESSION_IDLE_TIMEOUT_MILLIS: AtomicU64 =
+    AtomicU64::new(SESSION_IDLE_TIMEOUT.as_millis() as u64);
+/// Closed session ids remembered per camera; older ones report `NoSession`.
+const CLOSED_REMEMBERED: usize = 32;
+
+/// Open and closed sessions, keyed by camera name.
+static SESSIONS: Lazy<Mutex<HashMap<String, CameraSessions>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct CameraSessions {
+    open: Option<OpenSession>,
+    /// Most recently closed session ids, oldest first.
+    closed: VecDeque<u64>,
+    /// A session closed since the client's livestream state was last
+    /// dropped. Whoever next holds the client lock drops it.
+    reset_pending: bool,
+}
+
+struct OpenSession {
+    session: LivestreamSession,
+    last_active: Instant,
+}
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LivestreamSession {
+    pub session_id: u64,
+    pub camera_name: String,
+    pub opened_at_millis: i64,
+    /// Whether the key update for this session has been applied.
+    pub key_updated: bool,
+    pub chunks_decrypted: u64,
+}
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LivestreamError {
+    /// The client lock was held by someone else past the timeout.
+    Busy,
+    /// The client could not be created (missing init params or bad state on disk).
+    NotInitialized,
+    /// The camera has no open session, and `session_id` was never one of its.
+    NoSession { session_id: u64 },
+    /// Another viewer already has session `session_id` open on this camera.
+    AlreadyOpen { session_id: u64 },
+    /// The call named session `got`, but `expected` is the one open.
+    SessionMismatch { expected: u64, got: u64 },
+    /// Session `session_id` has been closed.
+    SessionClosed { session_id: u64 },
+    /// A chunk arrived before the session's key update was applied.
+    AwaitingKeyUpdate,
+    /// The key update is for a different MLS epoch than the group is at. The
+    /// epochs are given when the client reports them.
+    EpochMismatch {
+        expected: Option<u64>,
+        found: Option<u64>,
+    },
+    /// Any other error reported by the native layer.
+    Failed(String),
+}
+
+impl LivestreamError {
+    fn metric_kind(&self) -> &'static str {
+        match self {
+            LivestreamError::Busy => metrics::ERROR_BUSY,
+            LivestreamError::NotInitialized => metrics::ERROR_NOT_INITIALIZED,
+            LivestreamError::NoSession { .. }
+            | LivestreamError::AlreadyOpen { .. }
+            | LivestreamError::SessionMismatch { .. }
+            | LivestreamError::SessionClosed { .. }
+            | LivestreamError::AwaitingKeyUpdate => metrics::ERROR_SESSION,
+            LivestreamError::EpochMismatch { .. } => metrics::ERROR_EPOCH_MISMATCH,
+            LivestreamError::Failed(_) => metrics::ERROR_FAILED,
+        }
+    }
+
+    fn is_session_error(&self) -> bool {
+        self.metric_kind() == metrics::ERROR_SESSION
+    }
+}
+
+fn session_idle_timeout() -> Duration {
+    Duration::from_millis(SESSION_IDLE_TIMEOUT_MILLIS.load(Ordering::Relaxed))
+}
+
+/// Override how long an open session may sit idle before it expires; `None`
+/// restores the default.
+#[cfg(feature = "test-support")]
+#[flutter_rust_bridge::frb(ignore)]
+pub fn set_session_idle_timeout_for_tests(timeout: Option<Duration>) {
+    let timeout = timeout.unwrap_or(SESSION_IDLE_TIMEOUT);
+    SESSION_IDLE_TIMEOUT_MILLIS.store(timeout.as_millis() as u64, Ordering::Relaxed);
+}
+
+impl CameraSessions {
+    fn close(&mut self) -> Option<LivestreamSession> {
+        let open = self.open.take()?;
+        if self.closed.len() == CLOSED_REMEMBERED {
+            self.closed.pop_front();
+        }
+        self.closed.push_back(open.session.session_id);
+        self.reset_pending = true;
+        Some(open.session)
+    }
+
+    fn expire_idle(&mut self, camera_name: &str) {
+        let idle = self
+            .open
+            .as_ref()
+            .is_some_and(|open| open.last_active.elapsed() >= session_idle_timeout());
+        if !idle {
+            return;
+        }
+        if let Some(session) = self.close() {
+            info!(
+                "Livestream session {} for {} expired after {} chunks",
+                session.session_id, camera_name, session.chunks_decrypted
+            );
+        }
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| Duration::from_secs(0))
+        .as_millis() as i64
+}
+
+// The open session `session_id` of `camera_name`, or why there is none.
+fn lookup<'a>(
+    sessions: &'a mut HashMap<String, CameraSessions>,
+    camera_name: &str,
+    session_id: u64,
+) -> Result<&'a mut LivestreamSession, LivestreamError> {
+    let Some(camera) = sessions.get_mut(camera_name) else {
+        return Err(LivestreamError::NoSession { session_id });
+    };
+    camera.expire_idle(camera_name);
+    if camera.closed.contains(&session_id) {
+        return Err(LivestreamError::SessionClosed { session_id });
+    }
+    match camera.open.as_mut() {
+        Some(open) if open.session.session_id == session_id => {
+            open.last_active = Instant::now();
+            Ok(&mut open.session)
+        }
+        Some(open) => Err(LivestreamError::SessionMismatch {
+            expected: open.session.session_id,
+            got: session_id,
+        }),
+        None => Err(LivestreamError::NoSession { session_id }),
+    }
+}
+
+fn with_session<T>(
+    camera_name: &str,
+    session_id: u64,
+    f: impl FnOnce(&mut LivestreamSession) -> T,
+) -> Result<T, LivestreamError> {
+    lookup(&mut SESSIONS.lock(), camera_name, session_id).map(f)
+}
+
+// Whether a call for `session` may go ahead, and if so whether its key update
+// has been applied. A call without a session (the legacy functions) may not
+// run alongside an open one, and has no key update to wait for.
+fn check_session(camera_name: &str, session: Option<u64>) -> Result<bool, LivestreamError> {
+    if let Some(session_id) = session {
+        return with_session(camera_name, session_id, |open| open.key_updated);
+    }
+    let mut sessions = SESSIONS.lock();
+    let Some(camera) = sessions.get_mut(camera_name) else {
+        return Ok(true);
+    };
+    camera.expire_idle(camera_name);
+    match &camera.open {
+        Some(open) => Err(LivestreamError::AlreadyOpen {
+            session_id: open.session.session_id,
+        }),
+        None => Ok(true),
+    }
+}
+
+// Drop the client's livestream state if a session closed since it was set
+// up. The native layer has no call for just that, so the whole in-memory
+// client goes and is reloaded from disk on next use. Needs the client lock.
+fn reset_if_closed(client: &mut dyn CameraClientBackend, camera_name: &str) {
+    let pending = SESSIONS
+        .lock()
+        .get_mut(camera_name)
+        .is_some_and(|camera| std::mem::take(&mut camera.reset_pending));
+    if pending {
+        info!("Dropping livestream state of {}", camera_name);
+        client.reset();
+    }
+}
+
+/// Apply a livestream key update, checked against `session` if given.
+pub(super) fn update(
+    camera_name: &str,
+    trace_id: Option<&str>,
+    session: Option<u64>,
+    msg: Vec<u8>,
+) -> Result<(), LivestreamError> {
+    let channel = CHANNEL_FIXED;
+    let client_mutex = get_or_create_channel_mutex(camera_name, channel);
+    let op = "livestream_update(livestream)";
+    let result = check_session(camera_name, session).and_then(|_| {
+        let mut client_guard =
+            lock_client_with_owner(&client_mutex, camera_name, channel, op, trace_id)
+                .ok_or(LivestreamError::Busy)?;
+        // Again under the client lock, in case the session closed meanwhile.
+        check_session(camera_name, session)?;
+        reset_if_closed(&mut **client_guard, camera_name);
+        if !ensure_client_initialized(&mut **client_guard, camera_name, channel) {
+            return Err(LivestreamError::NotInitialized);
+        }
+        client_guard.livestream_update(msg).map_err(|e| match e {
+            ClientError::WrongEpoch {
+                group_epoch,
+                message_epoch,
+            } => LivestreamError::EpochMismatch {
+                expected: group_epoch,
+                found: message_epoch,
+            },
+            e => LivestreamError::Failed(e.to_string()),
+        })?;
+        if let Some(session_id) = session {
+            let _ = with_session(camera_name, session_id, |open| open.key_updated = true);
+        }
+        livestream_stats::begin_session(camera_name);
+        Ok(())
+    });
+
+    if let Err(e) = &result {
+        metrics::record_error(e.metric_kind());
+        if !e.is_session_error() {
+            livestream_stats::record_update_failure(camera_name);
+        }
+        info!("Error: {:?}", e);
+    }
+    result
+}
+
+/// Decrypt one livestream chunk, checked against `session` if given.
+pub(super) fn decrypt(
+    camera_name: &str,
+    trace_id: Option<&str>,
+    session: Option<u64>,
+    data: Vec<u8>,
+    expected_chunk_number: u64,
+) -> Result<Vec<u8>, LivestreamError> {
+    let check = |session: Option<u64>| match check_session(camera_name, session)? {
+        true => Ok(()),
+        false => Err(LivestreamError::AwaitingKeyUpdate),
+    };
+    let result = check(session).and_then(|()| {
+        let channel = CHANNEL_FIXED;
+        let client_mutex = get_or_create_channel_mutex(camera_name, channel);
+        let op = "livestream_decrypt(livestream)";
+        let mut chunk_stats =
+            livestream_stats::chunk(camera_name, expected_chunk_number, data.len());
+        let lock_started = Instant::now();
+        let mut client_guard =
+            lock_client_with_owner(&client_mutex, camera_name, channel, op, trace_id)
+                .ok_or(LivestreamError::Busy)?;
+        chunk_stats.lock_acquired(lock_started.elapsed());
+        // Again under the client lock, so no chunk decrypts after a close.
+        check(session)?;
+        reset_if_closed(&mut **client_guard, camera_name);
+        if !ensure_client_initialized(&mut **client_guard, camera_name, channel) {
+            return Err(LivestreamError::NotInitialized);
+        }
+
+        let decrypt_started = Instant::now();
+        let dec_data = client_guard
+            .livestream_decrypt(data, expected_chunk_number)
+            .map_err(LivestreamError::Failed)?;
+        chunk_stats.decrypted(decrypt_started.elapsed(), dec_data.len());
+        // Still under the client lock, so chunks are recorded in decrypt order.
+        livestream_recording::observe(camera_name, &dec_data);
+        if let Some(session_id) = session {
+            let _ = with_session(camera_name, session_id, |open| open.chunks_decrypted += 1);
+        }
+        Ok(dec_data)
+    });
+
+    if let Err(e) = &result {
+        metrics::record_error(e.metric_kind());
+        info!("Error: {:?}", e);
+    }
+    result
+}
+
+/// Close any open session of `camera_name` and forget its sessions.
+pub(super) fn forget(camera_name: &str) {
+    SESSIONS.lock().remove(camera_name);
+}
+
+/// Open a livestream session on `camera_name`. Fails with `AlreadyOpen` if
+/// another viewer has one open, unless `take_over` is set, which closes it.
+#[flutter_rust_bridge::frb]
+pub fn open_livestream_session(
+    camera_name: String,
+    take_over: bool,
+    context: Option<CallContext>,
+) -> Result<LivestreamSession, LivestreamError> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("open_livestream_session", camera = %camera_name).entered();
+    let _metrics = metrics::track("open_livestream_session", &camera_name);
+
+    let mut sessions = SESSIONS.lock();
+    let camera = sessions.entry(camera_name.clone()).or_default();
+    camera.expire_idle(&camera_name);
+    if let Some(open) = &camera.open {
+        let session_id = open.session.session_id;
+        if !take_over {
+            let e = LivestreamError::AlreadyOpen { session_id };
+            metrics::record_error(e.metric_kind());
+            return Err(e);
+        }
+        camera.close();
+        info!(
+            "Livestream session {} for {} taken over",
+            session_id, camera_name
+        );
+    }
+    let session = LivestreamSession {
+        session_id: NEXT_SESSION_ID.fetch_add(1, Ordering::SeqCst),
+        camera_name: camera_name.clone(),
+        opened_at_millis: now_millis(),
+        key_updated: false,
+        chunks_decrypted: 0,
+    };
+    info!(
+        "Opened livestream session {} for {}",
+        session.session_id, camera_name
+    );
+    camera.open = Some(OpenSession {
+        session: session.clone(),
+        last_active: Instant::now(),
+    });
+    Ok(session)
+}
+
+/// Apply the key update (the commit message fetched as chunk 0) for session
+/// `session_id` of `camera_name`.
+#[flutter_rust_bridge::frb]
+pub fn livestream_session_update(
+    camera_name: String,
+    session_id: u64,
+    msg: Vec<u8>,
+    context: Option<CallContext>,
+) -> Result<(), LivestreamError> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let _span =
+        info_span!("livestream_session_update", camera = %camera_name, session_id).entered();
+    let _metrics = metrics::track("livestream_session_update", &camera_name);
+    update(
+        &camera_name,
+        context.trace_id.as_deref(),
+        Some(session_id),
+        msg,
+    )
+}
+
+/// Decrypt chunk `expected_chunk_number` of session `session_id`.
+#[flutter_rust_bridge::frb]
+pub fn livestream_session_decrypt(
+    camera_name: String,
+    session_id: u64,
+    data: Vec<u8>,
+    expected_chunk_number: u64,
+    context: Option<CallContext>,
+) -> Result<Vec<u8>, LivestreamError> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let _span =
+        info_span!("livestream_session_decrypt", camera = %camera_name, session_id).entered();
+    let _metrics = metrics::track("livestream_session_decrypt", &camera_name);
+    decrypt(
+        &camera_name,
+        context.trace_id.as_deref(),
+        Some(session_id),
+        data,
+        expected_chunk_number,
+    )
+}
+
+/// Close session `session_id` of `camera_name` and drop the client's
+/// livestream state. Later calls with its id fail with `SessionClosed`.
+#[flutter_rust_bridge::frb]
+pub fn close_livestream_session(
+    camera_name: String,
+    session_id: u64,
+    context: Option<CallContext>,
+) -> Result<LivestreamSession, LivestreamError> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let _span = info_span!("close_livestream_session", camera = %camera_name, session_id).entered();
+    let _metrics = metrics::track("close_livestream_session", &camera_name);
+
+    let result = {
+        let mut sessions = SESSIONS.lock();
+        lookup(&mut sessions, &camera_name, session_id)
+            .map(|_| ())
+            .and_then(|()| {
+                let camera = sessions.get_mut(&camera_name);
+                camera
+                    .and_then(CameraSessions::close)
+                    .ok_or(LivestreamError::NoSession { session_id })
+            })
+    };
+    let session = match result {
+        Ok(session) => session,
+        Err(e) => {
+            metrics::record_error(e.metric_kind());
+            return Err(e);
+        }
+    };
+    info!(
+        "Closed livestream session {} for {} after {} chunks",
+        session_id, camera_name, session.chunks_decrypted
+    );
+    // If the client is busy, its next livestream call drops the state instead.
+    let client_mutex = get_or_create_channel_mutex(&camera_name, CHANNEL_FIXED);
+    let op = "close_livestream_session(livestream)";
+    let trace_id = context.trace_id.as_deref();
+    if let Some(mut client_guard) =
+        lock_client_with_owner(&client_mutex, &camera_name, CHANNEL_FIXED, op, trace_id)
+    {
+        reset_if_closed(&mut **client_guard, &camera_name);
+    }
+    Ok(session)
+}
+
+/// The open livestream session of `camera_name`, if any.
+#[flutter_rust_bridge::frb]
+pub fn current_livestream_session(
+    camera_name: String,
+    context: Option<CallContext>,
+) -> Option<LivestreamSession> {
+    let (camera_name, _) = resolve_call(&camera_name, context);
+    let mut sessions = SESSIONS.lock();
+    let camera = sessions.get_mut(&camera_name)?;
+    camera.expire_idle(&camera_name);
+    camera.open.as_ref().map(|open| open.session.clone())
+}
@@ -0,0 +1,219 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Per-session livestream statistics, for telling a slow network from slow
+//! decryption or a contended client lock when live view stutters.
+//!
+//! A session starts with each successful `livestream_update` and collects
+//! what `livestream_decrypt` sees until the next one.
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::logger::{self, CallContext};
+use super::{metrics, resolve_call};
+
+/// Decrypt latencies kept for the percentiles.
+const LATENCY_WINDOW: usize = 512;
+
+static SESSIONS: Lazy<Mutex<HashMap<String, SessionStats>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct SessionStats {
+    started_at_millis: Option<i64>,
+    update_failures: u64,
+    chunks_received: u64,
+    chunks_decrypted: u64,
+    chunks_failed: u64,
+    chunks_out_of_order: u64,
+    bytes_received: u64,
+    bytes_decrypted: u64,
+    last_chunk_number: Option<u64>,
+    last_chunk_at_millis: Option<i64>,
+    decrypt_micros: VecDeque<u64>,
+    decrypt_max_micros: u64,
+    lock_wait_total_micros: u64,
+    lock_wait_max_micros: u64,
+    lock_timeouts: u64,
+}
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Default)]
+pub struct LivestreamStats {
+    /// When the last successful `livestream_update` started this session.
+    /// None if there has been none.
+    pub session_started_at_millis: Option<i64>,
+    pub update_failures: u64,
+    pub chunks_received: u64,
+    pub chunks_decrypted: u64,
+    /// Chunks that did not decrypt, including those given up on because the
+    /// client lock was busy.
+    pub chunks_failed: u64,
+    /// Chunks whose number was not one past the last decrypted chunk.
+    pub chunks_out_of_order: u64,
+    /// Encrypted bytes handed to `livestream_decrypt`.
+    pub bytes_received: u64,
+    pub bytes_decrypted: u64,
+    pub last_chunk_number: Option<u64>,
+    pub last_chunk_at_millis: Option<i64>,
+    /// Decrypt latency percentiles over the most recent chunks.
+    pub decrypt_p50_micros: u64,
+    pub decrypt_p90_micros: u64,
+    pub decrypt_p99_micros: u64,
+    pub decrypt_max_micros: u64,
+    pub lock_wait_total_micros: u64,
+    pub lock_wait_max_micros: u64,
+    pub lock_timeouts: u64,
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| Duration::from_secs(0))
+        .as_millis() as i64
+}
+
+fn percentile(sorted: &[u64], percent: usize) -> u64 {
+    match sorted.len() {
+        0 => 0,
+        len => sorted[((len * percent).div_ceil(100)).clamp(1, len) - 1],
+    }
+}
+
+impl SessionStats {
+    fn snapshot(&self) -> LivestreamStats {
+        let mut latencies: Vec<u64> = self.decrypt_micros.iter().copied().collect();
+        latencies.sort_unstable();
+        LivestreamStats {
+            session_started_at_millis: self.started_at_millis,
+            update_failures: self.update_failures,
+            chunks_received: self.chunks_received,
+            chunks_decrypted: self.chunks_decrypted,
+            chunks_failed: self.chunks_failed,
+            chunks_out_of_order: self.chunks_out_of_order,
+            bytes_received: self.bytes_received,
+            bytes_decrypted: self.bytes_decrypted,
+            last_chunk_number: self.last_chunk_number,
+            last_chunk_at_millis: self.last_chunk_at_millis,
+            decrypt_p50_micros: percentile(&latencies, 50),
+            decrypt_p90_micros: percentile(&latencies, 90),
+            decrypt_p99_micros: percentile(&latencies, 99),
+            decrypt_max_micros: self.decrypt_max_micros,
+            lock_wait_total_micros: self.lock_wait_total_micros,
+            lock_wait_max_micros: self.lock_wait_max_micros,
+            lock_timeouts: self.lock_timeouts,
+        }
+    }
+}
+
+/// Start a new session for `camera_name`, dropping the previous one's stats.
+pub(super) fn begin_session(camera_name: &str) {
+    SESSIONS.lock().insert(
+        camera_name.to_string(),
+        SessionStats {
+            started_at_millis: Some(now_millis()),
+            ..Default::default()
+        },
+    );
+}
+
+pub(super) fn record_update_failure(camera_name: &str) {
+    SESSIONS
+        .lock()
+        .entry(camera_name.to_string())
+        .or_default()
+        .update_failures += 1;
+}
+
+pub(super) fn forget(camera_name: &str) {
+    SESSIONS.lock().remove(camera_name);
+}
+
+/// What happened to one chunk; recorded when dropped. A chunk that is never
+/// marked decrypted counts as failed.
+pub(super) struct ChunkStats {
+    camera_name: String,
+    chunk_number: u64,
+    encrypted_bytes: usize,
+    lock_wait: Option<Duration>,
+    decrypted: Option<(Duration, usize)>,
+}
+
+/// Start accounting for chunk `chunk_number` of `camera_name`.
+pub(super) fn chunk(camera_name: &str, chunk_number: u64, encrypted_bytes: usize) -> ChunkStats {
+    ChunkStats {
+        camera_name: camera_name.to_string(),
+        chunk_number,
+        encrypted_bytes,
+        lock_wait: None,
+        decrypted: None,
+    }
+}
+
+impl ChunkStats {
+    /// The client lock was acquired after `wait`. Not calling this means the
+    /// lock timed out.
+    pub(super) fn lock_acquired(&mut self, wait: Duration) {
+        self.lock_wait = Some(wait);
+    }
+
+    pub(super) fn decrypted(&mut self, took: Duration, decrypted_bytes: usize) {
+        self.decrypted = Some((took, decrypted_bytes));
+    }
+}
+
+impl Drop for ChunkStats {
+    fn drop(&mut self) {
+        let mut sessions = SESSIONS.lock();
+        let stats = sessions
+            .entry(std::mem::take(&mut self.camera_name))
+            .or_default();
+        stats.chunks_received += 1;
+        stats.bytes_received += self.encrypted_bytes as u64;
+        stats.last_chunk_at_millis = Some(now_millis());
+        match self.lock_wait {
+            Some(wait) => {
+                let micros = wait.as_micros() as u64;
+                stats.lock_wait_total_micros += micros;
+                stats.lock_wait_max_micros = stats.lock_wait_max_micros.max(micros);
+            }
+            None => stats.lock_timeouts += 1,
+        }
+        let Some((took, decrypted_bytes)) = self.decrypted else {
+            stats.chunks_failed += 1;
+            return;
+        };
+        if stats
+            .last_chunk_number
+            .is_some_and(|last| self.chunk_number != last + 1)
+        {
+            stats.chunks_out_of_order += 1;
+        }
+        stats.last_chunk_number = Some(self.chunk_number);
+        stats.chunks_decrypted += 1;
+        stats.bytes_decrypted += decrypted_bytes as u64;
+        let micros = took.as_micros() as u64;
+        if stats.decrypt_micros.len() == LATENCY_WINDOW {
+            stats.decrypt_micros.pop_front();
+        }
+        stats.decrypt_micros.push_back(micros);
+        stats.decrypt_max_micros = stats.decrypt_max_micros.max(micros);
+    }
+}
+
+/// Statistics for the current livestream session of `camera_name`. All zero
+/// if it has never streamed.
+#[flutter_rust_bridge::frb]
+pub fn livestream_stats(camera_name: String, context: Option<CallContext>) -> LivestreamStats {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _metrics = metrics::track("livestream_stats", &camera_name);
+    SESSIONS
+        .lock()
+        .get(&camera_name)
+        .map(SessionStats::snapshot)
+        .unwrap_or_default()
+}
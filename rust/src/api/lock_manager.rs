@@ -1,50 +1,640 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 
-use std::collections::HashMap;
-use std::fs::File;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use fs2::FileExt;
+use log::{debug, warn};
 use once_cell::sync::Lazy;
+use tracing::debug_span;
 
-/// Global map of locked files (by path) to hold file handles across FFI calls.
-static LOCK_MAP: Lazy<Mutex<HashMap<String, File>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+use super::waiters::Waiters;
 
-/// Blocking lock
+// One flock on one open file. Handles taken by the same named owner join an
+// existing hold on the path instead of opening the file again, so an owner can
+// lock a path it already holds. Everyone else, including callers that give no
+// owner, gets their own file, and flock semantics apply between them exactly
+// as between processes: exclusive holds exclude each other and shared holds
+// coexist.
+struct Hold {
+    path: String,
+    owner: Option<String>,
+    /// Mode of the flock. An exclusive hold stays exclusive until its last
+    /// handle is released, even if only shared handles remain.
+    shared: bool,
+    file: File,
+    handles: BTreeMap<u64, HandleInfo>,
+}
+
+struct HandleInfo {
+    shared: bool,
+    acquired_at: Instant,
+    owner_entry: Option<PathBuf>,
+}
+
+impl Hold {
+    fn joinable(&self, path: &str, owner: Option<&str>, shared: bool) -> bool {
+        owner.is_some()
+            && self.owner.as_deref() == owner
+            && self.path == path
+            && (shared || !self.shared)
+    }
+}
+
+impl Drop for Hold {
+    fn drop(&mut self) {
+        // Remove our metadata while we still hold the lock, so a new holder
+        // never sees it.
+        for info in self.handles.values() {
+            if let Some(entry) = &info.owner_entry {
+                let _ = fs::remove_file(entry);
+            }
+        }
+        if let Err(e) = fs2::FileExt::unlock(&self.file) {
+            warn!("Failed to unlock {}: {}", self.path, e);
+        }
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Registry {
+    holds: HashMap<u64, Hold>,
+    /// Hold of every live handle.
+    handles: HashMap<u64, u64>,
+}
+
+/// Locks held by this process across FFI calls.
+static REGISTRY: Lazy<Mutex<Registry>> = Lazy::new(|| Mutex::new(Registry::default()));
+static NEXT_LOCK_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Holds taken through the path-based `acquire_lock`/`try_acquire_lock`, released
+/// by `release_lock(path)`. They give no owner, so there is at most one per path.
+static PATH_LOCKS: Lazy<Mutex<HashMap<String, LockHandle>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static LOCK_WAITERS: Lazy<Waiters> = Lazy::new(|| Waiters::new("lock"));
+
+const LOCK_POLL_MIN: Duration = Duration::from_millis(5);
+const LOCK_POLL_MAX: Duration = Duration::from_millis(100);
+
+/// A held lock. Released by `release_lock_handle`, or when dropped.
+#[flutter_rust_bridge::frb(opaque)]
+#[derive(Debug)]
+pub struct LockHandle {
+    id: u64,
+}
+
+impl LockHandle {
+    /// Identifies the handle in `list_held_locks`.
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl Drop for LockHandle {
+    fn drop(&mut self) {
+        release(self.id);
+    }
+}
+
+/// A lock held by this process, as reported by `list_held_locks`.
 #[flutter_rust_bridge::frb]
-pub fn acquire_lock(path: String) -> Result<bool, String> {
-    let file = File::create(&path).map_err(|e| e.to_string())?;
-    file.lock_exclusive().map_err(|e| e.to_string())?;
+#[derive(Debug, Clone)]
+pub struct HeldLockInfo {
+    pub handle: u64,
+    pub path: String,
+    pub shared: bool,
+    pub owner: String,
+    pub held_millis: u64,
+}
+
+/// Who is holding a lock file, as written by the holder when it acquired it.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct LockOwnerInfo {
+    pub pid: u32,
+    pub owner: String,
+    pub shared: bool,
+    pub acquired_at_millis: i64,
+}
+
+fn open_lock_file(path: &str) -> Result<File, String> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .map_err(|e| e.to_string())
+}
 
-    LOCK_MAP.lock().unwrap().insert(path, file);
+// Holder metadata lives next to the lock file, one file per holder, so shared
+// holders never write over each other and nobody has to lock to read it.
+fn owners_dir(path: &str) -> PathBuf {
+    PathBuf::from(format!("{}.holders", path))
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| Duration::from_secs(0))
+        .as_millis() as i64
+}
+
+fn write_owner_info(
+    path: &str,
+    handle: u64,
+    owner: Option<&str>,
+    shared: bool,
+) -> io::Result<PathBuf> {
+    let owner = owner
+        .filter(|o| !o.is_empty())
+        .unwrap_or("unknown")
+        .replace('\n', " ");
+    let text = format!(
+        "pid={}\nowner={}\nmode={}\nacquired_at={}\n",
+        std::process::id(),
+        owner,
+        if shared { "shared" } else { "exclusive" },
+        now_millis()
+    );
+    let dir = owners_dir(path);
+    fs::create_dir_all(&dir)?;
+    let entry = dir.join(format!("{}-{}", std::process::id(), handle));
+    let tmp = entry.with_extension("tmp");
+    let mut file = File::create(&tmp)?;
+    file.write_all(text.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&tmp, &entry)?;
+    Ok(entry)
+}
+
+// Entries left behind by a holder that died without releasing. Only an
+// exclusive holder can tell, since nobody else holds the lock then.
+fn clear_owner_info(path: &str) {
+    if let Ok(entries) = fs::read_dir(owners_dir(path)) {
+        for entry in entries.flatten() {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+// Whether the process that wrote an entry is gone. Only answerable where
+// /proc exists; elsewhere entries are trusted until an exclusive holder clears them.
+fn is_dead_process(pid: u32) -> bool {
+    let proc_dir = Path::new("/proc");
+    proc_dir.is_dir() && !proc_dir.join(pid.to_string()).exists()
+}
+
+fn parse_owner_info(text: &str) -> Option<LockOwnerInfo> {
+    let mut pid = None;
+    let mut owner = None;
+    let mut shared = false;
+    let mut acquired_at_millis = 0;
+    for line in text.lines() {
+        match line.split_once('=') {
+            Some(("pid", v)) => pid = v.trim().parse().ok(),
+            Some(("owner", v)) => owner = Some(v.to_string()),
+            Some(("mode", v)) => shared = v.trim() == "shared",
+            Some(("acquired_at", v)) => acquired_at_millis = v.trim().parse().unwrap_or(0),
+            _ => {}
+        }
+    }
+
+    Some(LockOwnerInfo {
+        pid: pid?,
+        owner: owner.unwrap_or_else(|| "unknown".to_string()),
+        shared,
+        acquired_at_millis,
+    })
+}
+
+// Add a handle to `hold_id`. The caller holds the registry lock.
+fn add_handle(
+    registry: &mut Registry,
+    hold_id: u64,
+    owner: Option<&str>,
+    shared: bool,
+) -> LockHandle {
+    let id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
+    let hold = registry.holds.get_mut(&hold_id).unwrap();
+    // Metadata is informational only; never fail an acquired lock because of it.
+    let owner_entry = write_owner_info(&hold.path, id, owner, shared)
+        .inspect_err(|e| debug!("Failed to write lock owner info for {}: {}", hold.path, e))
+        .ok();
+    hold.handles.insert(
+        id,
+        HandleInfo {
+            shared,
+            acquired_at: Instant::now(),
+            owner_entry,
+        },
+    );
+    registry.handles.insert(id, hold_id);
+    LockHandle { id }
+}
+
+// Join a hold this owner already has on `path`, if it covers `shared`.
+fn join_hold(path: &str, owner: Option<&str>, shared: bool) -> Option<LockHandle> {
+    let mut registry = REGISTRY.lock().unwrap();
+    let hold_id = registry
+        .holds
+        .iter()
+        .find(|(_, hold)| hold.joinable(path, owner, shared))
+        .map(|(id, _)| *id)?;
+    Some(add_handle(&mut registry, hold_id, owner, shared))
+}
+
+// Register a flock just taken on `file`.
+fn hold(path: &str, file: File, owner: Option<&str>, shared: bool) -> LockHandle {
+    let mut registry = REGISTRY.lock().unwrap();
+    // Another call by the same owner may have taken a shared flock of its own
+    // meanwhile. Join it; dropping `file` gives up our duplicate.
+    if let Some(hold_id) = registry
+        .holds
+        .iter()
+        .find(|(_, hold)| hold.joinable(path, owner, shared))
+        .map(|(id, _)| *id)
+    {
+        return add_handle(&mut registry, hold_id, owner, shared);
+    }
+    if !shared {
+        clear_owner_info(path);
+    }
+    let hold_id = NEXT_LOCK_ID.fetch_add(1, Ordering::SeqCst);
+    registry.holds.insert(
+        hold_id,
+        Hold {
+            path: path.to_string(),
+            owner: owner.map(str::to_string),
+            shared,
+            file,
+            handles: BTreeMap::new(),
+        },
+    );
+    add_handle(&mut registry, hold_id, owner, shared)
+}
+
+// Release handle `id`, and its hold with it if it was the last handle.
+// Returns false if the handle was already released.
+fn release(id: u64) -> bool {
+    let hold = {
+        let mut registry = REGISTRY.lock().unwrap();
+        let Some(hold_id) = registry.handles.remove(&id) else {
+            return false;
+        };
+        let hold = registry.holds.get_mut(&hold_id).unwrap();
+        if let Some(entry) = hold.handles.remove(&id).and_then(|info| info.owner_entry) {
+            let _ = fs::remove_file(entry);
+        }
+        if !hold.handles.is_empty() {
+            return true;
+        }
+        registry.holds.remove(&hold_id)
+    };
+    drop(hold);
+    true
+}
+
+enum Wait<'a> {
+    /// Give up at once if the lock is held elsewhere.
+    Try,
+    /// Poll until `deadline` (forever if `None`) or until `cancel` is set.
+    Poll {
+        deadline: Option<Instant>,
+        cancel: Option<&'a AtomicBool>,
+    },
+}
+
+enum Acquired {
+    Held(LockHandle),
+    Busy,
+    Cancelled,
+}
+
+// Poll with exponential backoff rather than block in flock, so a lock abandoned
+// by a dead isolate can be given up on and an owner can join a hold it takes
+// meanwhile on another thread.
+fn acquire(path: &str, owner: Option<&str>, shared: bool, wait: Wait) -> Result<Acquired, String> {
+    if let Some(handle) = join_hold(path, owner, shared) {
+        return Ok(Acquired::Held(handle));
+    }
+    let file = open_lock_file(path)?;
+    let try_lock = |file: &File| {
+        if shared {
+            FileExt::try_lock_shared(file)
+        } else {
+            FileExt::try_lock_exclusive(file)
+        }
+    };
+    if try_lock(&file).is_ok() {
+        return Ok(Acquired::Held(hold(path, file, owner, shared)));
+    }
+    let Wait::Poll { deadline, cancel } = wait else {
+        return Ok(Acquired::Busy);
+    };
+
+    let _span = debug_span!("file_lock_wait", path, shared).entered();
+    let mut delay = LOCK_POLL_MIN;
+    loop {
+        if cancel.is_some_and(|c| c.load(Ordering::SeqCst)) {
+            return Ok(Acquired::Cancelled);
+        }
+        let now = Instant::now();
+        if deadline.is_some_and(|deadline| now >= deadline) {
+            return Ok(Acquired::Busy);
+        }
+        let sleep = match deadline {
+            Some(deadline) => delay.min(deadline - now),
+            None => delay,
+        };
+        thread::sleep(sleep);
+        delay = (delay * 2).min(LOCK_POLL_MAX);
+
+        if try_lock(&file).is_ok() {
+            return Ok(Acquired::Held(hold(path, file, owner, shared)));
+        }
+        if let Some(handle) = join_hold(path, owner, shared) {
+            return Ok(Acquired::Held(handle));
+        }
+    }
+}
+
+fn acquire_blocking(path: &str, owner: Option<&str>, shared: bool) -> Result<LockHandle, String> {
+    let wait = Wait::Poll {
+        deadline: None,
+        cancel: None,
+    };
+    match acquire(path, owner, shared, wait)? {
+        Acquired::Held(handle) => Ok(handle),
+        Acquired::Busy | Acquired::Cancelled => Err(format!("Lock {} not acquired", path)),
+    }
+}
+
+fn try_acquire(
+    path: &str,
+    owner: Option<&str>,
+    shared: bool,
+) -> Result<Option<LockHandle>, String> {
+    match acquire(path, owner, shared, Wait::Try)? {
+        Acquired::Held(handle) => Ok(Some(handle)),
+        Acquired::Busy | Acquired::Cancelled => Ok(None),
+    }
+}
+
+// The path API can't tell its holds from anyone else's, so it refuses a path
+// this process holds through handles rather than risk mixing the two.
+fn refuse_handle_holds(path: &str) -> Result<(), String> {
+    let path_lock = PATH_LOCKS.lock().unwrap().get(path).map(LockHandle::id);
+    let registry = REGISTRY.lock().unwrap();
+    let by_handle = registry
+        .holds
+        .values()
+        .any(|hold| hold.path == path && hold.handles.keys().any(|id| Some(*id) != path_lock));
+    if by_handle {
+        return Err(format!(
+            "{} is held through lock handles; use acquire_lock_as",
+            path
+        ));
+    }
+    Ok(())
+}
+
+/// Deprecated: use `acquire_lock_as`, which returns a handle.
+///
+/// Blocking exclusive lock, released with `release_lock(path)`. Fails if this
+/// process holds `path` through handles.
+#[flutter_rust_bridge::frb]
+pub fn acquire_lock(path: String) -> Result<bool, String> {
+    refuse_handle_holds(&path)?;
+    let handle = acquire_blocking(&path, None, false)?;
+    PATH_LOCKS.lock().unwrap().insert(path, handle);
     Ok(true)
 }
 
-/// Non-blocking lock
+/// Deprecated: use `try_acquire_lock_as`, which returns a handle.
+///
+/// Non-blocking exclusive lock, released with `release_lock(path)`. Fails if
+/// this process holds `path` through handles.
 #[flutter_rust_bridge::frb]
 pub fn try_acquire_lock(path: String) -> Result<bool, String> {
-    let file = File::create(&path).map_err(|e| e.to_string())?;
-    match file.try_lock_exclusive() {
-        Ok(_) => {
-            LOCK_MAP.lock().unwrap().insert(path, file);
+    refuse_handle_holds(&path)?;
+    match try_acquire(&path, None, false)? {
+        Some(handle) => {
+            PATH_LOCKS.lock().unwrap().insert(path, handle);
             Ok(true)
         }
-        Err(_) => Ok(false),
+        None => Ok(false),
     }
 }
 
-/// Release a previously acquired lock
+/// Deprecated: use `release_lock_handle`.
+///
+/// Release the lock taken on `path` by `acquire_lock` or `try_acquire_lock`.
+/// Locks held through handles are left alone.
 #[flutter_rust_bridge::frb]
 pub fn release_lock(path: String) -> Result<(), String> {
-    let mut map = LOCK_MAP.lock().unwrap();
-    if let Some(file) = map.remove(&path) {
-        fs2::FileExt::unlock(&file).map_err(|e| e.to_string())?;
-    }
+    let handle = PATH_LOCKS.lock().unwrap().remove(&path);
+    drop(handle);
     Ok(())
 }
 
+/// Blocking exclusive lock, recording `owner` (e.g. the isolate label) next to
+/// the lock file. An owner may lock a path it already holds; the lock is
+/// released once every handle it took is.
+#[flutter_rust_bridge::frb]
+pub fn acquire_lock_as(path: String, owner: Option<String>) -> Result<LockHandle, String> {
+    acquire_blocking(&path, owner.as_deref(), false)
+}
+
+/// Blocking shared lock. Any number of shared holders may coexist, but they
+/// exclude exclusive holders. Asking for an exclusive lock on a path held
+/// shared here waits until every shared handle is released.
+#[flutter_rust_bridge::frb]
+pub fn acquire_shared_lock(path: String, owner: Option<String>) -> Result<LockHandle, String> {
+    acquire_blocking(&path, owner.as_deref(), true)
+}
+
+/// Non-blocking exclusive lock, recording `owner` next to the lock file.
+/// Returns `None` if the lock is held elsewhere.
+#[flutter_rust_bridge::frb]
+pub fn try_acquire_lock_as(
+    path: String,
+    owner: Option<String>,
+) -> Result<Option<LockHandle>, String> {
+    try_acquire(&path, owner.as_deref(), false)
+}
+
+/// Non-blocking shared lock
+#[flutter_rust_bridge::frb]
+pub fn try_acquire_shared_lock(
+    path: String,
+    owner: Option<String>,
+) -> Result<Option<LockHandle>, String> {
+    try_acquire(&path, owner.as_deref(), true)
+}
+
+fn log_lock_timeout(path: &str, timeout: Duration) {
+    let owner = lock_owner_info(path.to_string()).ok().flatten();
+    warn!(
+        "Lock {} not acquired within {:?} (holder={:?})",
+        path, timeout, owner
+    );
+}
+
+/// Exclusive lock that gives up after `timeout_millis`. Returns `None` on timeout.
+#[flutter_rust_bridge::frb]
+pub fn acquire_lock_with_timeout(
+    path: String,
+    timeout_millis: u64,
+    owner: Option<String>,
+) -> Result<Option<LockHandle>, String> {
+    let timeout = Duration::from_millis(timeout_millis);
+    let wait = Wait::Poll {
+        deadline: Some(Instant::now() + timeout),
+        cancel: None,
+    };
+    match acquire(&path, owner.as_deref(), false, wait)? {
+        Acquired::Held(handle) => Ok(Some(handle)),
+        Acquired::Busy | Acquired::Cancelled => {
+            log_lock_timeout(&path, timeout);
+            Ok(None)
+        }
+    }
+}
+
+/// Reserve an id for a `wait_for_lock` call, so the wait can be cancelled
+/// with `cancel_lock_wait` while it runs.
+#[flutter_rust_bridge::frb]
+pub fn new_lock_waiter() -> String {
+    LOCK_WAITERS.reserve()
+}
+
+/// Wait up to `timeout_millis` for an exclusive lock, giving up early if
+/// `cancel_lock_wait(waiter_id)` is called. `waiter_id` comes from
+/// `new_lock_waiter` and is used up by the wait. Returns the lock handle, or
+/// fails with "Timeout ..." or "Cancelled".
+#[flutter_rust_bridge::frb]
+pub fn wait_for_lock(
+    path: String,
+    timeout_millis: u64,
+    owner: Option<String>,
+    waiter_id: String,
+) -> Result<LockHandle, String> {
+    let waiting = LOCK_WAITERS.begin(&waiter_id)?;
+    let timeout = Duration::from_millis(timeout_millis);
+    let wait = Wait::Poll {
+        deadline: Some(Instant::now() + timeout),
+        cancel: Some(waiting.cancel_flag()),
+    };
+    match acquire(&path, owner.as_deref(), false, wait)? {
+        // Cancelled while we were taking it; nobody is waiting for it anymore,
+        // and dropping the handle releases it.
+        Acquired::Held(_) if waiting.is_cancelled() => Err("Cancelled".to_string()),
+        Acquired::Held(handle) => Ok(handle),
+        Acquired::Busy => {
+            log_lock_timeout(&path, timeout);
+            Err(format!("Timeout after {:?}", timeout))
+        }
+        Acquired::Cancelled => Err("Cancelled".to_string()),
+    }
+}
+
+/// Cancel a pending `wait_for_lock`. Returns false if the id is unknown or its
+/// wait has already ended. Ids no wait takes expire after a minute.
+#[flutter_rust_bridge::frb]
+pub fn cancel_lock_wait(waiter_id: String) -> bool {
+    LOCK_WAITERS.cancel(&waiter_id)
+}
+
+/// Release the lock behind `handle`. Returns false if it was already released
+/// by `shutdown_app`.
+#[flutter_rust_bridge::frb]
+pub fn release_lock_handle(handle: LockHandle) -> bool {
+    release(handle.id)
+}
+
 /// Check if the current process holds the lock
 #[flutter_rust_bridge::frb]
 pub fn is_lock_held(path: String) -> Result<bool, String> {
-    Ok(LOCK_MAP.lock().unwrap().contains_key(&path))
+    Ok(REGISTRY
+        .lock()
+        .unwrap()
+        .holds
+        .values()
+        .any(|hold| hold.path == path))
+}
+
+/// Every lock handle this process holds, oldest first.
+#[flutter_rust_bridge::frb]
+pub fn list_held_locks() -> Vec<HeldLockInfo> {
+    let registry = REGISTRY.lock().unwrap();
+    let mut locks: Vec<HeldLockInfo> = registry
+        .holds
+        .values()
+        .flat_map(|hold| {
+            hold.handles.iter().map(|(id, info)| HeldLockInfo {
+                handle: *id,
+                path: hold.path.clone(),
+                shared: info.shared,
+                owner: hold.owner.clone().unwrap_or_else(|| "unknown".to_string()),
+                held_millis: info.acquired_at.elapsed().as_millis() as u64,
+            })
+        })
+        .collect();
+    locks.sort_by_key(|info| info.handle);
+    locks
+}
+
+/// Release every lock this process holds. Returns how many handles were released.
+pub(crate) fn release_all_locks() -> usize {
+    let holds: Vec<Hold> = {
+        let mut registry = REGISTRY.lock().unwrap();
+        registry.handles.clear();
+        registry.holds.drain().map(|(_, hold)| hold).collect()
+    };
+    // Their handles are dead now; dropping them is a no-op.
+    PATH_LOCKS.lock().unwrap().clear();
+    holds.iter().map(|hold| hold.handles.len()).sum()
+}
+
+/// Report who holds the lock at `path`, in this or any other process, from
+/// the metadata holders leave next to it. An exclusive holder is reported
+/// first, otherwise the longest-standing shared one. Returns `None` if nobody
+/// holds it or the holder left no metadata. Does not touch the lock itself.
+#[flutter_rust_bridge::frb]
+pub fn lock_owner_info(path: String) -> Result<Option<LockOwnerInfo>, String> {
+    let entries = match fs::read_dir(owners_dir(&path)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let pid = std::process::id();
+    let held_here: Vec<u64> = REGISTRY.lock().unwrap().handles.keys().copied().collect();
+    let mut owners: Vec<LockOwnerInfo> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_none())
+        .filter_map(|entry| {
+            let info = parse_owner_info(&fs::read_to_string(entry.path()).ok()?)?;
+            let stale = if info.pid == pid {
+                let name = entry.file_name();
+                let handle = name.to_str()?.rsplit_once('-')?.1.parse::<u64>().ok()?;
+                !held_here.contains(&handle)
+            } else {
+                is_dead_process(info.pid)
+            };
+            (!stale).then_some(info)
+        })
+        .collect();
+    owners.sort_by_key(|info| (info.shared, info.acquired_at_millis));
+    Ok(owners.into_iter().next())
 }
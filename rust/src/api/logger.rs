@@ -2,15 +2,18 @@
 
 use once_cell::sync::Lazy;
 use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Once;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use crate::frb_generated::StreamSink;
 use lazy_static::lazy_static;
 use log::LevelFilter;
 use log::{error, info, warn, Level, Log, Metadata, Record};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use simplelog::format_description;
 use simplelog::ColorChoice;
 use simplelog::CombinedLogger;
@@ -19,14 +22,36 @@ use simplelog::ConfigBuilder;
 use simplelog::SharedLogger;
 use simplelog::TermLogger;
 use simplelog::TerminalMode;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record as SpanRecord};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::{LookupSpan, Registry};
 
 #[flutter_rust_bridge::frb]
+#[derive(Clone)]
 pub struct LogEntry {
     pub time_millis: i64,
     pub level: i32,
     pub tag: String,
     pub msg: String,
     pub trace_id: Option<String>,
+    pub source: Option<String>,
+    pub parent_span: Option<String>,
+    /// Set on span close entries, which report the span's start time in
+    /// `time_millis` and how long it was open in `duration_micros`.
+    pub span_id: Option<String>,
+    pub duration_micros: i64,
+}
+
+/// Where a bridge call comes from, attached to every log entry it produces.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallContext {
+    pub trace_id: Option<String>,
+    /// What started the call, e.g. "fcm", "ui" or "background_task".
+    pub source: Option<String>,
+    pub parent_span: Option<String>,
 }
 
 #[flutter_rust_bridge::frb]
@@ -34,6 +59,22 @@ pub fn create_log_stream(s: StreamSink<LogEntry>) {
     SendToDartLogger::set_stream_sink(s);
 }
 
+/// Entries still in the recent-log buffer, oldest first, that are at least
+/// `min_level`, not older than `since_millis` and, if given, from `trace_id`.
+/// Kept whether or not a log stream is attached.
+#[flutter_rust_bridge::frb]
+pub fn recent_logs(since_millis: i64, min_level: i32, trace_id: Option<String>) -> Vec<LogEntry> {
+    RECENT_LOGS
+        .lock()
+        .entries
+        .iter()
+        .map(|(_, entry)| entry)
+        .filter(|entry| entry.time_millis >= since_millis && entry.level >= min_level)
+        .filter(|entry| trace_id.is_none() || entry.trace_id == trace_id)
+        .cloned()
+        .collect()
+}
+
 #[flutter_rust_bridge::frb]
 pub fn rust_set_up() {
     LOGGING_ACTIVE.store(true, Ordering::SeqCst);
@@ -54,14 +95,42 @@ pub fn rust_shutdown() -> Result<(), String> {
     Ok(())
 }
 
+const RECENT_LOGS_CAPACITY: usize = 2000;
+
+// Recent entries with a sequence number, so a new sink can be sent exactly the
+// ones no sink received.
+struct RecentLogs {
+    entries: VecDeque<(u64, LogEntry)>,
+    next_seq: u64,
+    last_delivered: u64,
+}
+
+impl RecentLogs {
+    fn push(&mut self, entry: LogEntry) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        if self.entries.len() == RECENT_LOGS_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((seq, entry));
+        seq
+    }
+}
+
+static RECENT_LOGS: Lazy<Mutex<RecentLogs>> = Lazy::new(|| {
+    Mutex::new(RecentLogs {
+        entries: VecDeque::with_capacity(RECENT_LOGS_CAPACITY),
+        next_seq: 1,
+        last_delivered: 0,
+    })
+});
+
 static INIT_LOGGER_ONCE: Once = Once::new();
 static LOGGING_ACTIVE: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(true));
 
 pub fn init_logger() {
     // https://stackoverflow.com/questions/30177845/how-to-initialize-the-logger-for-integration-tests
     INIT_LOGGER_ONCE.call_once(|| {
-        //tracing_log::LogTracer::init().expect("Failed to set tracing -> log bridge");
-
         let level = LevelFilter::Trace;
         assert!(
             level <= log::STATIC_MAX_LEVEL,
@@ -86,6 +155,14 @@ pub fn init_logger() {
         .unwrap_or_else(|e| {
             error!("init_logger (inside 'once') has error: {:?}", e);
         });
+
+        // `log` stays the logging facade; tracing only carries spans, whose close
+        // events DartSpanLayer turns into log entries with durations.
+        if let Err(e) = tracing::subscriber::set_global_default(
+            Registry::default().with(DartSpanLayer { level }),
+        ) {
+            warn!("init_logger: tracing subscriber already set: {}", e);
+        }
         info!("init_logger (inside 'once') finished");
 
         warn!(
@@ -95,6 +172,25 @@ pub fn init_logger() {
     });
 }
 
+/// Keep log entries for `recent_logs` only, without writing them to the
+/// terminal. For headless tools that print entries themselves.
+#[flutter_rust_bridge::frb(ignore)]
+pub fn init_buffered_logger(level: LevelFilter) {
+    INIT_LOGGER_ONCE.call_once(|| {
+        if log::set_boxed_logger(Box::new(SendToDartLogger::new(level))).is_ok() {
+            log::set_max_level(level);
+        }
+        if let Err(e) = tracing::subscriber::set_global_default(
+            Registry::default().with(DartSpanLayer { level }),
+        ) {
+            warn!(
+                "init_buffered_logger: tracing subscriber already set: {}",
+                e
+            );
+        }
+    });
+}
+
 lazy_static! {
     static ref SEND_TO_DART_LOGGER_STREAM_SINK: RwLock<Option<StreamSink<LogEntry>>> =
         RwLock::new(None);
@@ -105,37 +201,50 @@ pub struct SendToDartLogger {
 }
 
 thread_local! {
-    static LOG_TRACE: RefCell<Option<String>> = RefCell::new(None);
+    static CALL_CONTEXT: RefCell<CallContext> = RefCell::new(CallContext::default());
 }
 
-pub(crate) struct LogTraceGuard {
-    prev: Option<String>,
+pub(crate) struct CallContextGuard {
+    prev: CallContext,
 }
 
-impl Drop for LogTraceGuard {
+impl Drop for CallContextGuard {
     fn drop(&mut self) {
-        LOG_TRACE.with(|slot| {
-            *slot.borrow_mut() = self.prev.clone();
-        });
+        let prev = std::mem::take(&mut self.prev);
+        CALL_CONTEXT.with(|slot| *slot.borrow_mut() = prev);
     }
 }
 
-pub(crate) fn set_log_trace(trace_id: Option<&str>) -> LogTraceGuard {
-    let prev = LOG_TRACE.with(|slot| slot.borrow().clone());
-    LOG_TRACE.with(|slot| {
-        *slot.borrow_mut() = trace_id.map(|id| id.to_string());
-    });
-    LogTraceGuard { prev }
+/// Attach `context` to everything logged on this thread until the guard drops.
+pub(crate) fn enter_call_context(context: CallContext) -> CallContextGuard {
+    let prev = CALL_CONTEXT.with(|slot| std::mem::replace(&mut *slot.borrow_mut(), context));
+    CallContextGuard { prev }
 }
 
-fn current_log_trace() -> Option<String> {
-    LOG_TRACE.with(|slot| slot.borrow().clone())
+pub(crate) fn current_call_context() -> CallContext {
+    CALL_CONTEXT.with(|slot| slot.borrow().clone())
 }
 
 impl SendToDartLogger {
     pub fn set_stream_sink(stream_sink: StreamSink<LogEntry>) {
+        // Sends deliver under the read lock, so once we hold the write lock every
+        // entry not marked delivered is one no sink received.
         let mut guard = SEND_TO_DART_LOGGER_STREAM_SINK.write();
         let overriding = guard.is_some();
+        let missed: Vec<LogEntry> = {
+            let mut recent = RECENT_LOGS.lock();
+            let last_delivered = recent.last_delivered;
+            recent.last_delivered = recent.next_seq - 1;
+            recent
+                .entries
+                .iter()
+                .filter(|(seq, _)| *seq > last_delivered)
+                .map(|(_, entry)| entry.clone())
+                .collect()
+        };
+        for entry in missed {
+            let _ = stream_sink.add(entry);
+        }
         *guard = Some(stream_sink);
         drop(guard);
 
@@ -151,31 +260,55 @@ impl SendToDartLogger {
         SendToDartLogger { level }
     }
 
-    fn record_to_entry(record: &Record) -> LogEntry {
-        let time_millis = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_else(|_| Duration::from_secs(0))
-            .as_millis() as i64;
+    fn send(entry: LogEntry) {
+        // The buffer is locked only to record the entry, never while the sink
+        // runs, so sends don't queue behind each other and a sink that logs
+        // doesn't deadlock. The sink lock is taken recursively for the latter.
+        let sink = SEND_TO_DART_LOGGER_STREAM_SINK.read_recursive();
+        let sink = sink
+            .as_ref()
+            .filter(|_| LOGGING_ACTIVE.load(Ordering::SeqCst));
+        {
+            let mut recent = RECENT_LOGS.lock();
+            let seq = recent.push(entry.clone());
+            if sink.is_some() {
+                recent.last_delivered = recent.last_delivered.max(seq);
+            }
+        }
+        if let Some(sink) = sink {
+            let _ = sink.add(entry);
+        }
+    }
 
-        let level = match record.level() {
+    fn level_to_i32(level: Level) -> i32 {
+        match level {
             Level::Trace => Self::LEVEL_TRACE,
             Level::Debug => Self::LEVEL_DEBUG,
             Level::Info => Self::LEVEL_INFO,
             Level::Warn => Self::LEVEL_WARN,
             Level::Error => Self::LEVEL_ERROR,
-        };
+        }
+    }
+
+    fn record_to_entry(record: &Record) -> LogEntry {
+        let time_millis = now_millis();
+        let level = Self::level_to_i32(record.level());
 
         let tag = record.file().unwrap_or_else(|| record.target()).to_owned();
 
         let msg = format!("{}", record.args());
-        let trace_id = current_log_trace();
+        let context = current_call_context();
 
         LogEntry {
             time_millis,
             level,
             tag,
             msg,
-            trace_id,
+            trace_id: context.trace_id,
+            source: context.source,
+            parent_span: context.parent_span,
+            span_id: None,
+            duration_micros: 0,
         }
     }
 
@@ -195,15 +328,7 @@ impl Log for SendToDartLogger {
     }
 
     fn log(&self, record: &Record) {
-        if !LOGGING_ACTIVE.load(Ordering::SeqCst) {
-            return;
-        }
-
-        let entry = Self::record_to_entry(record);
-
-        if let Some(sink) = &*SEND_TO_DART_LOGGER_STREAM_SINK.read() {
-            let _ = sink.add(entry);
-        }
+        Self::send(Self::record_to_entry(record));
     }
 
     fn flush(&self) {}
@@ -222,3 +347,123 @@ impl SharedLogger for SendToDartLogger {
         Box::new(*self)
     }
 }
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| Duration::from_secs(0))
+        .as_millis() as i64
+}
+
+// Span ids handed to Dart. tracing reuses its own ids once a span closes, which
+// would tangle a waterfall, so every span gets a fresh one.
+static NEXT_SPAN_ID: AtomicU64 = AtomicU64::new(1);
+
+struct SpanTiming {
+    id: u64,
+    started: Instant,
+    started_millis: i64,
+    context: CallContext,
+    fields: String,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct FieldFormatter(String);
+
+impl Visit for FieldFormatter {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        let _ = write!(self.0, " {}={:?}", field.name(), value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        let _ = write!(self.0, " {}={}", field.name(), value);
+    }
+}
+
+/// Sends a debug log entry to Dart for every closed span, carrying the call
+/// context that was current when the span opened and the time it was open.
+/// Spans are timed only when `level` lets debug entries through.
+pub(crate) struct DartSpanLayer {
+    level: LevelFilter,
+}
+
+impl DartSpanLayer {
+    fn enabled(&self) -> bool {
+        Level::Debug <= self.level
+    }
+}
+
+impl<S> Layer<S> for DartSpanLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if !self.enabled() {
+            return;
+        }
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = FieldFormatter::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(SpanTiming {
+            id: NEXT_SPAN_ID.fetch_add(1, Ordering::Relaxed),
+            started: Instant::now(),
+            started_millis: now_millis(),
+            context: current_call_context(),
+            fields: fields.0,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &SpanRecord<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+            let mut fields = FieldFormatter(std::mem::take(&mut timing.fields));
+            values.record(&mut fields);
+            timing.fields = fields.0;
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(timing) = extensions.get::<SpanTiming>() else {
+            return;
+        };
+        // Nest under the enclosing span if there is one, otherwise under the
+        // span Dart passed in with the call context.
+        let parent_span = span
+            .parent()
+            .and_then(|parent| parent.extensions().get::<SpanTiming>().map(|t| t.id))
+            .map(|id| id.to_string())
+            .or_else(|| timing.context.parent_span.clone());
+        let duration = timing.started.elapsed();
+        let metadata = span.metadata();
+
+        SendToDartLogger::send(LogEntry {
+            time_millis: timing.started_millis,
+            level: SendToDartLogger::LEVEL_DEBUG,
+            tag: metadata
+                .file()
+                .unwrap_or_else(|| metadata.target())
+                .to_owned(),
+            msg: format!(
+                "{}{} took {:.3}ms",
+                metadata.name(),
+                timing.fields,
+                duration.as_secs_f64() * 1000.0
+            ),
+            trace_id: timing.context.trace_id.clone(),
+            source: timing.context.source.clone(),
+            parent_span,
+            span_id: Some(timing.id.to_string()),
+            duration_micros: duration.as_micros() as i64,
+        });
+    }
+}
@@ -0,0 +1,221 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Call counts, errors and latencies for bridge functions, kept per function
+//! and per camera for the debug page and diagnostics export.
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub(crate) const ERROR_BUSY: &str = "busy";
+pub(crate) const ERROR_NOT_INITIALIZED: &str = "not_initialized";
+pub(crate) const ERROR_EPOCH_MISMATCH: &str = "epoch_mismatch";
+pub(crate) const ERROR_DUPLICATE: &str = "duplicate";
+pub(crate) const ERROR_SESSION: &str = "session";
+pub(crate) const ERROR_FAILED: &str = "failed";
+pub(crate) const ERROR_PANIC: &str = "panic";
+
+/// Upper bounds of the latency histogram buckets. Anything slower lands in a
+/// final overflow bucket.
+const LATENCY_BUCKETS_MILLIS: [u64; 12] =
+    [1, 5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+const _: () = assert!(LATENCY_BUCKETS_MILLIS.len() + 1 == 13);
+
+#[flutter_rust_bridge::frb]
+pub struct ErrorCount {
+    pub kind: String,
+    pub count: u64,
+}
+
+#[flutter_rust_bridge::frb]
+pub struct OperationMetrics {
+    pub function: String,
+    /// Empty in the per-function totals.
+    pub camera: String,
+    pub calls: u64,
+    pub errors: Vec<ErrorCount>,
+    /// One count per bound in `MetricsSnapshot::latency_bucket_bounds_millis`,
+    /// plus a last one for slower calls.
+    pub latency_counts: Vec<u64>,
+    pub total_micros: u64,
+    pub max_micros: u64,
+}
+
+#[flutter_rust_bridge::frb]
+pub struct MetricsSnapshot {
+    pub since_millis: i64,
+    pub taken_at_millis: i64,
+    pub latency_bucket_bounds_millis: Vec<u64>,
+    pub by_function: Vec<OperationMetrics>,
+    pub by_camera: Vec<OperationMetrics>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default, Clone)]
+struct Counters {
+    calls: u64,
+    errors: HashMap<&'static str, u64>,
+    // One per bucket plus the overflow one. A literal length, as the bindings
+    // generator can't evaluate `LATENCY_BUCKETS_MILLIS.len() + 1`.
+    latency_counts: [u64; 13],
+    total_micros: u64,
+    max_micros: u64,
+}
+
+impl Counters {
+    fn record(&mut self, elapsed: Duration, error: Option<&'static str>) {
+        let micros = elapsed.as_micros() as u64;
+        let bucket = LATENCY_BUCKETS_MILLIS
+            .iter()
+            .position(|bound| micros <= bound * 1000)
+            .unwrap_or(LATENCY_BUCKETS_MILLIS.len());
+        self.calls += 1;
+        self.latency_counts[bucket] += 1;
+        self.total_micros += micros;
+        self.max_micros = self.max_micros.max(micros);
+        if let Some(kind) = error {
+            *self.errors.entry(kind).or_default() += 1;
+        }
+    }
+
+    fn merge(&mut self, other: &Counters) {
+        self.calls += other.calls;
+        for (kind, count) in &other.errors {
+            *self.errors.entry(kind).or_default() += count;
+        }
+        for (total, count) in self.latency_counts.iter_mut().zip(other.latency_counts) {
+            *total += count;
+        }
+        self.total_micros += other.total_micros;
+        self.max_micros = self.max_micros.max(other.max_micros);
+    }
+
+    fn to_metrics(&self, function: &str, camera: &str) -> OperationMetrics {
+        let mut errors: Vec<ErrorCount> = self
+            .errors
+            .iter()
+            .map(|(kind, count)| ErrorCount {
+                kind: kind.to_string(),
+                count: *count,
+            })
+            .collect();
+        errors.sort_by(|a, b| a.kind.cmp(&b.kind));
+        OperationMetrics {
+            function: function.to_string(),
+            camera: camera.to_string(),
+            calls: self.calls,
+            errors,
+            latency_counts: self.latency_counts.to_vec(),
+            total_micros: self.total_micros,
+            max_micros: self.max_micros,
+        }
+    }
+}
+
+struct Registry {
+    since_millis: i64,
+    counters: HashMap<(&'static str, String), Counters>,
+}
+
+static REGISTRY: Lazy<Mutex<Registry>> = Lazy::new(|| {
+    Mutex::new(Registry {
+        since_millis: now_millis(),
+        counters: HashMap::new(),
+    })
+});
+
+thread_local! {
+    // Error noted for each tracked call in progress on this thread, innermost last.
+    static CALL_ERRORS: RefCell<Vec<Option<&'static str>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| Duration::from_secs(0))
+        .as_millis() as i64
+}
+
+/// Records one call when dropped.
+pub(crate) struct CallMetricsGuard {
+    function: &'static str,
+    camera: String,
+    started: Instant,
+}
+
+impl Drop for CallMetricsGuard {
+    fn drop(&mut self) {
+        let error = CALL_ERRORS.with(|errors| errors.borrow_mut().pop().flatten());
+        let elapsed = self.started.elapsed();
+        REGISTRY
+            .lock()
+            .counters
+            .entry((self.function, std::mem::take(&mut self.camera)))
+            .or_default()
+            .record(elapsed, error);
+    }
+}
+
+impl CallMetricsGuard {
+    /// Count the call for `camera`, for calls that only learn it part way.
+    pub(crate) fn set_camera(&mut self, camera: &str) {
+        self.camera = camera.to_string();
+    }
+}
+
+/// Start timing a call to `function` for `camera`.
+pub(crate) fn track(function: &'static str, camera: &str) -> CallMetricsGuard {
+    CALL_ERRORS.with(|errors| errors.borrow_mut().push(None));
+    CallMetricsGuard {
+        function,
+        camera: camera.to_string(),
+        started: Instant::now(),
+    }
+}
+
+/// Count the innermost call in progress on this thread as failed with `kind`.
+/// The first error noted wins, since later ones are usually its consequences.
+pub(crate) fn record_error(kind: &'static str) {
+    CALL_ERRORS.with(|errors| {
+        if let Some(slot) = errors.borrow_mut().last_mut() {
+            slot.get_or_insert(kind);
+        }
+    });
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_metrics_snapshot() -> MetricsSnapshot {
+    let registry = REGISTRY.lock();
+    let mut totals: HashMap<&'static str, Counters> = HashMap::new();
+    let mut by_camera: Vec<OperationMetrics> = registry
+        .counters
+        .iter()
+        .map(|((function, camera), counters)| {
+            totals.entry(function).or_default().merge(counters);
+            counters.to_metrics(function, camera)
+        })
+        .collect();
+    by_camera.sort_by(|a, b| (&a.function, &a.camera).cmp(&(&b.function, &b.camera)));
+    let mut by_function: Vec<OperationMetrics> = totals
+        .iter()
+        .map(|(function, counters)| counters.to_metrics(function, ""))
+        .collect();
+    by_function.sort_by(|a, b| a.function.cmp(&b.function));
+
+    MetricsSnapshot {
+        since_millis: registry.since_millis,
+        taken_at_millis: now_millis(),
+        latency_bucket_bounds_millis: LATENCY_BUCKETS_MILLIS.to_vec(),
+        by_function,
+        by_camera,
+    }
+}
+
+#[flutter_rust_bridge::frb]
+pub fn reset_metrics() {
+    let mut registry = REGISTRY.lock();
+    registry.counters.clear();
+    registry.since_millis = now_millis();
+}
@@ -1,21 +1,36 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 
+pub mod backup;
+pub mod camera_events;
+pub mod credentials;
+pub mod hotspot;
+pub mod livestream_recording;
+pub mod livestream_session;
+pub mod livestream_stats;
 pub mod lock_manager;
 pub mod logger;
+pub mod metrics;
+pub mod notifications;
+pub mod pairing;
+pub mod push_queue;
 pub mod simple;
+mod waiters;
 
-use secluso_app_native::{self, Clients};
+use crate::api::camera_events::CameraEventKind;
+use crate::api::credentials::ServerCredentials;
+use crate::api::logger::CallContext;
+use crate::backend::{self, CameraClientBackend, ClientError};
 
 use log::{debug, error, info, warn};
 use once_cell::sync::Lazy;
 use parking_lot::{Mutex, MutexGuard};
 use std::collections::HashMap;
+use std::net::SocketAddr;
+use tracing::{debug_span, info_span};
 
-use std::net::{Shutdown, SocketAddr, TcpStream};
 use std::ops::{Deref, DerefMut};
 use std::panic;
-use std::str::FromStr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -31,7 +46,9 @@ struct InitParams {
     first_time: bool,
 }
 
-static CLIENTS: Lazy<Mutex<HashMap<ClientKey, Arc<Mutex<Option<Box<Clients>>>>>>> =
+type ClientSlot = Arc<Mutex<Box<dyn CameraClientBackend>>>;
+
+static CLIENTS: Lazy<Mutex<HashMap<ClientKey, ClientSlot>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 static CLIENT_LOCK_OWNERS: Lazy<Mutex<HashMap<ClientKey, String>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
@@ -40,6 +57,8 @@ static INIT_PARAMS: Lazy<Mutex<HashMap<String, InitParams>>> =
 static IS_SHUTTING_DOWN: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(false));
 
 const CLIENT_LOCK_TIMEOUT: Duration = Duration::from_secs(8);
+static CLIENT_LOCK_TIMEOUT_MILLIS: AtomicU64 =
+    AtomicU64::new(CLIENT_LOCK_TIMEOUT.as_millis() as u64);
 const CLIENT_LOCK_WARN: Duration = Duration::from_millis(250);
 // TODO: using different channels for "Clients" break the heartbeat since the heartbeat needs to see the
 // latest state of different MLS channels. To solve that, we'll need to use one Clients objects.
@@ -68,6 +87,51 @@ fn split_trace_camera(camera_name: &str) -> (String, Option<&str>) {
     }
 }
 
+// Resolve the camera and call context for an incoming call. With an explicit
+// context the name is taken literally. Otherwise the call inherits the context
+// of the call it runs inside, if any, and a `|trace=` suffix is split off and
+// overrides its trace id. The suffix is deprecated: it only remains for callers
+// that don't pass a context yet.
+fn resolve_call(camera_name: &str, context: Option<CallContext>) -> (String, CallContext) {
+    if let Some(context) = context {
+        return (camera_name.to_string(), context);
+    }
+    let (base, trace_id) = split_trace_camera(camera_name);
+    let mut context = logger::current_call_context();
+    if let Some(trace_id) = trace_id {
+        context.trace_id = Some(trace_id.to_string());
+    }
+    (base, context)
+}
+
+/// `split_trace_camera` for fuzzing, with an owned trace id.
+#[cfg(feature = "test-support")]
+#[flutter_rust_bridge::frb(ignore)]
+pub fn split_trace_camera_for_tests(camera_name: &str) -> (String, Option<String>) {
+    let (base, trace) = split_trace_camera(camera_name);
+    (base, trace.map(str::to_string))
+}
+
+/// Number of camera client locks currently held. Tests and fuzz targets use this
+/// to catch guards that outlive the call that took them.
+#[cfg(feature = "test-support")]
+#[flutter_rust_bridge::frb(ignore)]
+pub fn held_client_lock_count() -> usize {
+    CLIENT_LOCK_OWNERS.lock().len()
+}
+
+fn client_lock_timeout() -> Duration {
+    Duration::from_millis(CLIENT_LOCK_TIMEOUT_MILLIS.load(Ordering::Relaxed))
+}
+
+/// Override how long calls wait for a busy client lock; `None` restores the default.
+#[cfg(feature = "test-support")]
+#[flutter_rust_bridge::frb(ignore)]
+pub fn set_client_lock_timeout_for_tests(timeout: Option<Duration>) {
+    let timeout = timeout.unwrap_or(CLIENT_LOCK_TIMEOUT);
+    CLIENT_LOCK_TIMEOUT_MILLIS.store(timeout.as_millis() as u64, Ordering::Relaxed);
+}
+
 macro_rules! lock_client_or_return {
     ($client_mutex:expr, $camera_name:expr, $channel:expr, $op:expr, $owner:expr, $ret:expr) => {{
         match lock_client_with_owner(&$client_mutex, $camera_name, $channel, $op, $owner) {
@@ -77,10 +141,7 @@ macro_rules! lock_client_or_return {
     }};
 }
 
-fn get_or_create_channel_mutex(
-    camera_name: &str,
-    channel: &str,
-) -> Arc<Mutex<Option<Box<Clients>>>> {
+fn get_or_create_channel_mutex(camera_name: &str, channel: &str) -> ClientSlot {
     let mut guard = CLIENTS.lock();
     let key = ClientKey {
         camera: camera_name.to_owned(),
@@ -88,14 +149,14 @@ fn get_or_create_channel_mutex(
     };
     guard
         .entry(key)
-        .or_insert_with(|| Arc::new(Mutex::new(None)))
+        .or_insert_with(|| Arc::new(Mutex::new(backend::new_backend())))
         .clone()
 }
 
 // Wrap the MLS client lock so we can log who holds it and for how long.
 // This keeps lock tracking out of the call sites while making contention visible in logs.
 struct TracedClientGuard<'a> {
-    guard: MutexGuard<'a, Option<Box<Clients>>>,
+    guard: MutexGuard<'a, Box<dyn CameraClientBackend>>,
     key: ClientKey,
     owner: String,
     op: String,
@@ -103,7 +164,7 @@ struct TracedClientGuard<'a> {
 }
 
 impl<'a> Deref for TracedClientGuard<'a> {
-    type Target = Option<Box<Clients>>;
+    type Target = Box<dyn CameraClientBackend>;
     fn deref(&self) -> &Self::Target {
         &self.guard
     }
@@ -131,7 +192,7 @@ impl<'a> Drop for TracedClientGuard<'a> {
 }
 
 fn lock_client_with_owner<'a>(
-    client_mutex: &'a Arc<Mutex<Option<Box<Clients>>>>,
+    client_mutex: &'a ClientSlot,
     camera_name: &str,
     channel: &str,
     op: &str,
@@ -147,7 +208,10 @@ fn lock_client_with_owner<'a>(
         "MLS lock attempt for {} on camera {} channel {} (owner={})",
         op, camera_name, channel, owner_label
     );
-    match client_mutex.try_lock_for(CLIENT_LOCK_TIMEOUT) {
+    let lock_wait = debug_span!("lock_wait", channel).entered();
+    let guard = client_mutex.try_lock_for(client_lock_timeout());
+    drop(lock_wait);
+    match guard {
         Some(guard) => {
             {
                 let mut owners = CLIENT_LOCK_OWNERS.lock();
@@ -182,7 +246,17 @@ fn lock_client_with_owner<'a>(
             };
             warn!(
                 "MLS lock busy after {:?} for {} on camera {} channel {} (owner={})",
-                CLIENT_LOCK_TIMEOUT, op, camera_name, channel, owner_label
+                client_lock_timeout(),
+                op,
+                camera_name,
+                channel,
+                owner_label
+            );
+            metrics::record_error(metrics::ERROR_BUSY);
+            camera_events::emit(
+                camera_name,
+                CameraEventKind::LockTimeout,
+                Some(format!("{} waited for {}", op, owner_label)),
             );
             None
         }
@@ -190,14 +264,19 @@ fn lock_client_with_owner<'a>(
 }
 
 fn ensure_client_initialized(
-    client_guard: &mut Option<Box<Clients>>,
+    client: &mut dyn CameraClientBackend,
     camera_name: &str,
     channel: &str,
 ) -> bool {
-    if client_guard.is_some() {
-        return true;
-    }
+    client.is_initialized() || initialize_from_params(client, camera_name, channel).is_ok()
+}
 
+// Load (or create) the client state with the params `initialize_camera` stored.
+fn initialize_from_params(
+    client: &mut dyn CameraClientBackend,
+    camera_name: &str,
+    channel: &str,
+) -> Result<(), String> {
     let params = {
         let guard = INIT_PARAMS.lock();
         guard.get(camera_name).cloned()
@@ -208,25 +287,47 @@ fn ensure_client_initialized(
             "No init params for camera {} (channel {})",
             camera_name, channel
         );
-        return false;
+        metrics::record_error(metrics::ERROR_NOT_INITIALIZED);
+        camera_events::emit(
+            camera_name,
+            CameraEventKind::ClientInitFailed,
+            Some("no init params".to_string()),
+        );
+        return Err("no init params".to_string());
     };
 
-    match secluso_app_native::initialize(client_guard, params.file_dir, params.first_time) {
-        Ok(_) => true,
+    match client.initialize(params.file_dir, params.first_time) {
+        Ok(_) => {
+            camera_events::emit(camera_name, CameraEventKind::ClientInitialized, None);
+            Ok(())
+        }
         Err(e) => {
             info!(
                 "initialize error for camera {} channel {}: {}",
                 camera_name, channel, e
             );
-            false
+            metrics::record_error(metrics::ERROR_NOT_INITIALIZED);
+            camera_events::emit(
+                camera_name,
+                CameraEventKind::ClientInitFailed,
+                Some(e.clone()),
+            );
+            Err(e)
         }
     }
 }
 
 #[flutter_rust_bridge::frb]
-pub fn initialize_camera(camera_name: String, file_dir: String, first_time: bool) -> bool {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
+pub fn initialize_camera(
+    camera_name: String,
+    file_dir: String,
+    first_time: bool,
+    context: Option<CallContext>,
+) -> bool {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("initialize_camera", camera = %camera_name).entered();
+    let _metrics = metrics::track("initialize_camera", &camera_name);
     {
         let mut guard = INIT_PARAMS.lock();
         guard.insert(
@@ -240,14 +341,22 @@ pub fn initialize_camera(camera_name: String, file_dir: String, first_time: bool
 
     // Lazy per-channel init: only set init params here.
     // Clients get created on first use inside ensure_client_initialized.
+    camera_events::emit(&camera_name, CameraEventKind::Registered, None);
     true
 }
 
 #[flutter_rust_bridge::frb]
-pub fn deregister_camera(camera_name: String) {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
-    let entries: Vec<(ClientKey, Arc<Mutex<Option<Box<Clients>>>>)> = {
+pub fn deregister_camera(camera_name: String, context: Option<CallContext>) {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("deregister_camera", camera = %camera_name).entered();
+    let _metrics = metrics::track("deregister_camera", &camera_name);
+    pairing::forget(&camera_name);
+    livestream_recording::forget(&camera_name);
+    livestream_stats::forget(&camera_name);
+    livestream_session::forget(&camera_name);
+    let entries: Vec<(ClientKey, ClientSlot)> = {
         let guard = CLIENTS.lock();
         guard
             .iter()
@@ -277,8 +386,8 @@ pub fn deregister_camera(camera_name: String) {
             };
 
         let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
-            secluso_app_native::deregister(&mut *client_guard);
-            *client_guard = None;
+            client_guard.deregister();
+            client_guard.reset();
         }));
 
         if res.is_err() {
@@ -286,6 +395,7 @@ pub fn deregister_camera(camera_name: String) {
                 "Panic while deregistering camera {} channel {}",
                 camera_name, key.channel
             );
+            metrics::record_error(metrics::ERROR_PANIC);
         } else {
             did_deregister = true;
         }
@@ -303,75 +413,463 @@ pub fn deregister_camera(camera_name: String) {
         let mut guard = INIT_PARAMS.lock();
         guard.remove(&camera_name);
     }
+    camera_events::emit(&camera_name, CameraEventKind::Deregistered, None);
+}
+
+/// What `reload_camera_client` found on disk.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientReloadReport {
+    /// A client was loaded in memory before the reload.
+    pub was_loaded: bool,
+    /// The state on disk loaded again. If not, the camera has no client until
+    /// its state is restored or it is paired again.
+    pub consistent: bool,
+    /// Why the state did not load, when `consistent` is false.
+    pub error: Option<String>,
 }
 
+/// Throw away the in-memory client for `camera_name` and load it again from
+/// disk, without touching the state there. Use this instead of deregistering
+/// when the client may be behind what another process wrote.
+///
+/// The reload waits for the call holding the client, as any call does, and
+/// fails with "Error: Busy" past the lock timeout. Calls that already failed
+/// against the old client are not retried; repeat them after the reload.
 #[flutter_rust_bridge::frb]
-pub fn decrypt_video(camera_name: String, enc_filename: String, _assumed_epoch: u64) -> String {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
+pub fn reload_camera_client(
+    camera_name: String,
+    context: Option<CallContext>,
+) -> Result<ClientReloadReport, String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("reload_camera_client", camera = %camera_name).entered();
+    let _metrics = metrics::track("reload_camera_client", &camera_name);
     let channel = CHANNEL_FIXED;
+    if !INIT_PARAMS.lock().contains_key(&camera_name) {
+        metrics::record_error(metrics::ERROR_NOT_INITIALIZED);
+        return Err(format!("camera {} is not initialized", camera_name));
+    }
+
+    let op = format!("reload_camera_client({})", channel);
     let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
-    let op = "decrypt_video(motion)".to_string();
     let mut client_guard = lock_client_or_return!(
         client_mutex,
         &camera_name,
         channel,
         &op,
         trace_id,
-        "Error: Busy".to_string()
+        Err("Error: Busy".to_string())
     );
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
-        return "Error".to_string();
+    let was_loaded = client_guard.is_initialized();
+    client_guard.reset();
+    let result = initialize_from_params(&mut **client_guard, &camera_name, channel);
+    info!(
+        "Reloaded client for camera {} (was loaded: {}, consistent: {})",
+        camera_name,
+        was_loaded,
+        result.is_ok()
+    );
+    Ok(ClientReloadReport {
+        was_loaded,
+        consistent: result.is_ok(),
+        error: result.err(),
+    })
+}
+
+/// Why an epoch-aware decryption did not produce a file.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecryptError {
+    /// The client lock was held by someone else past the timeout.
+    Busy,
+    /// The client could not be created (missing init params or bad state on disk).
+    NotInitialized,
+    /// The file belongs to a different MLS epoch than the group is at, even after
+    /// reloading the client from disk. `expected` is the group epoch and `found`
+    /// is the epoch carried by the file, when the client reports them. Callers
+    /// should queue the file rather than discard it.
+    EpochMismatch {
+        expected: Option<u64>,
+        found: Option<u64>,
+    },
+    /// Any other error reported by the native layer.
+    Failed(String),
+}
+
+impl DecryptError {
+    // Errors that will hit every later file in the same queue as well.
+    fn is_fatal_for_group(&self) -> bool {
+        match self {
+            DecryptError::Busy | DecryptError::NotInitialized => true,
+            DecryptError::EpochMismatch {
+                expected: Some(expected),
+                found: Some(found),
+            } => found > expected,
+            DecryptError::EpochMismatch { .. } => false,
+            DecryptError::Failed(_) => false,
+        }
     }
 
-    match secluso_app_native::decrypt_video(&mut *client_guard, enc_filename) {
-        Ok(decrypted_filename) => {
-            return decrypted_filename;
+    // Keep the strings the Dart download/thumbnail tasks already match on.
+    fn to_legacy_string(&self, op: &str) -> String {
+        match self {
+            DecryptError::Busy => "Error: Busy".to_string(),
+            DecryptError::NotInitialized => "Error".to_string(),
+            DecryptError::EpochMismatch { expected, found } => {
+                let epoch = |e: &Option<u64>| e.map_or("unknown".to_string(), |e| e.to_string());
+                format!(
+                    "Error({}): message epoch {} does not match group epoch {}",
+                    op,
+                    epoch(found),
+                    epoch(expected)
+                )
+            }
+            DecryptError::Failed(e) => format!("Error({}): {}", op, e),
         }
-        Err(_e) => {
-            return format!("Error(decrypt_video): {}", _e);
+    }
+
+    fn metric_kind(&self) -> &'static str {
+        match self {
+            DecryptError::Busy => metrics::ERROR_BUSY,
+            DecryptError::NotInitialized => metrics::ERROR_NOT_INITIALIZED,
+            DecryptError::EpochMismatch { .. } => metrics::ERROR_EPOCH_MISMATCH,
+            DecryptError::Failed(_) => metrics::ERROR_FAILED,
         }
     }
 }
 
 #[flutter_rust_bridge::frb]
-pub fn decrypt_thumbnail(
+#[derive(Debug, Clone)]
+pub struct DecryptedFile {
+    pub filename: String,
+    /// MLS epoch of the group after decrypting the file, if the client reports it.
+    pub epoch: Option<u64>,
+    /// True if the in-memory client was behind and had to be reloaded from disk.
+    pub caught_up: bool,
+}
+
+// Drop the in-memory client and rebuild it from the state on disk. Another
+// process (e.g. the background download task) may have advanced the group.
+fn reload_client_from_disk(
+    client: &mut dyn CameraClientBackend,
+    camera_name: &str,
+    channel: &str,
+) -> bool {
+    client.reset();
+    ensure_client_initialized(client, camera_name, channel)
+}
+
+fn decrypt_with_epoch<F>(
+    camera_name: &str,
+    trace_id: Option<&str>,
+    op: &str,
+    assumed_epoch: u64,
+    decrypt: F,
+) -> Result<DecryptedFile, DecryptError>
+where
+    F: FnMut(&mut dyn CameraClientBackend) -> Result<String, ClientError>,
+{
+    let channel = CHANNEL_FIXED;
+    let client_mutex = get_or_create_channel_mutex(camera_name, channel);
+    let mut client_guard =
+        lock_client_with_owner(&client_mutex, camera_name, channel, op, trace_id)
+            .ok_or(DecryptError::Busy)?;
+    decrypt_locked(
+        &mut **client_guard,
+        camera_name,
+        channel,
+        op,
+        Some(assumed_epoch),
+        decrypt,
+    )
+    .inspect_err(|e| metrics::record_error(e.metric_kind()))
+}
+
+// Decrypt one file with the client lock already held. A client that is behind
+// `assumed_epoch`, or that rejects the file as from a later epoch, is reloaded
+// from disk (another process may have advanced the group) unless it was only
+// just loaded, and the file is tried once more.
+fn decrypt_locked<F>(
+    client: &mut dyn CameraClientBackend,
+    camera_name: &str,
+    channel: &str,
+    op: &str,
+    assumed_epoch: Option<u64>,
+    mut decrypt: F,
+) -> Result<DecryptedFile, DecryptError>
+where
+    F: FnMut(&mut dyn CameraClientBackend) -> Result<String, ClientError>,
+{
+    let just_loaded = !client.is_initialized();
+    if !ensure_client_initialized(client, camera_name, channel) {
+        return Err(DecryptError::NotInitialized);
+    }
+
+    let mut caught_up = false;
+    if let (Some(assumed), Some(group), false) = (assumed_epoch, client.group_epoch(), just_loaded)
+    {
+        if group < assumed {
+            info!(
+                "{} on camera {}: group epoch {} behind assumed epoch {}; reloading client",
+                op, camera_name, group, assumed
+            );
+            if !reload_client_from_disk(client, camera_name, channel) {
+                return Err(DecryptError::NotInitialized);
+            }
+            caught_up = true;
+        }
+    }
+
+    let (expected, found) = match decrypt(client) {
+        Ok(filename) => {
+            return Ok(DecryptedFile {
+                filename,
+                epoch: client.group_epoch(),
+                caught_up,
+            })
+        }
+        Err(ClientError::WrongEpoch {
+            group_epoch,
+            message_epoch,
+        }) => (group_epoch, message_epoch),
+        Err(e) => return Err(DecryptError::Failed(e.to_string())),
+    };
+
+    // A reload only helps a file from a later epoch than the group. If the client
+    // can't say which way it is off, try anyway.
+    let behind = match (expected, found) {
+        (Some(expected), Some(found)) => found > expected,
+        _ => true,
+    };
+    if just_loaded || caught_up || !behind {
+        return Err(DecryptError::EpochMismatch { expected, found });
+    }
+
+    info!(
+        "{} on camera {}: file epoch {:?} not at group epoch {:?} (assumed {:?}); reloading client",
+        op, camera_name, found, expected, assumed_epoch
+    );
+    if !reload_client_from_disk(client, camera_name, channel) {
+        return Err(DecryptError::NotInitialized);
+    }
+
+    match decrypt(client) {
+        Ok(filename) => Ok(DecryptedFile {
+            filename,
+            epoch: client.group_epoch(),
+            caught_up: true,
+        }),
+        Err(ClientError::WrongEpoch {
+            group_epoch,
+            message_epoch,
+        }) => Err(DecryptError::EpochMismatch {
+            expected: group_epoch,
+            found: message_epoch,
+        }),
+        Err(e) => Err(DecryptError::Failed(e.to_string())),
+    }
+}
+
+/// Decrypt a motion video, using `assumed_epoch` to decide whether the client
+/// needs to catch up with state written by another process.
+#[flutter_rust_bridge::frb]
+pub fn decrypt_video_at_epoch(
+    camera_name: String,
+    enc_filename: String,
+    assumed_epoch: u64,
+    context: Option<CallContext>,
+) -> Result<DecryptedFile, DecryptError> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("decrypt_video_at_epoch", camera = %camera_name).entered();
+    let _metrics = metrics::track("decrypt_video", &camera_name);
+    decrypt_with_epoch(
+        &camera_name,
+        trace_id,
+        "decrypt_video(motion)",
+        assumed_epoch,
+        |client| client.decrypt_video(enc_filename.clone()),
+    )
+}
+
+/// Thumbnail counterpart of [`decrypt_video_at_epoch`].
+#[flutter_rust_bridge::frb]
+pub fn decrypt_thumbnail_at_epoch(
     camera_name: String,
     enc_filename: String,
     pending_meta_directory: String,
-    _assumed_epoch: u64,
-) -> String {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
-    let channel = CHANNEL_FIXED;
-    let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
-    let op = "decrypt_thumbnail(thumbnail)".to_string();
-    let mut client_guard = lock_client_or_return!(
-        client_mutex,
+    assumed_epoch: u64,
+    context: Option<CallContext>,
+) -> Result<DecryptedFile, DecryptError> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("decrypt_thumbnail_at_epoch", camera = %camera_name).entered();
+    let _metrics = metrics::track("decrypt_thumbnail", &camera_name);
+    decrypt_with_epoch(
         &camera_name,
-        channel,
-        &op,
         trace_id,
-        "Error: Busy".to_string()
-    );
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
-        return "Error".to_string();
+        "decrypt_thumbnail(thumbnail)",
+        assumed_epoch,
+        |client| client.decrypt_thumbnail(enc_filename.clone(), pending_meta_directory.clone()),
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn decrypt_video(
+    camera_name: String,
+    enc_filename: String,
+    assumed_epoch: u64,
+    context: Option<CallContext>,
+) -> String {
+    match decrypt_video_at_epoch(camera_name, enc_filename, assumed_epoch, context) {
+        Ok(decrypted) => decrypted.filename,
+        Err(e) => e.to_legacy_string("decrypt_video"),
     }
+}
 
-    match secluso_app_native::decrypt_thumbnail(
-        &mut *client_guard,
+#[flutter_rust_bridge::frb]
+pub fn decrypt_thumbnail(
+    camera_name: String,
+    enc_filename: String,
+    pending_meta_directory: String,
+    assumed_epoch: u64,
+    context: Option<CallContext>,
+) -> String {
+    match decrypt_thumbnail_at_epoch(
+        camera_name,
         enc_filename,
         pending_meta_directory,
+        assumed_epoch,
+        context,
     ) {
-        Ok(decrypted_filename) => {
-            return decrypted_filename;
+        Ok(decrypted) => decrypted.filename,
+        Err(e) => e.to_legacy_string("decrypt_thumbnail"),
+    }
+}
+
+/// Per-file result of a batch decryption.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub enum DecryptOutcome {
+    Decrypted(DecryptedFile),
+    Failed(DecryptError),
+    /// Not attempted because an earlier file in the queue hit a fatal group error.
+    Skipped,
+}
+
+fn decrypt_batch<F>(
+    camera_name: &str,
+    trace_id: Option<&str>,
+    op: &str,
+    enc_filenames: Vec<String>,
+    mut decrypt: F,
+) -> Vec<DecryptOutcome>
+where
+    F: FnMut(&mut dyn CameraClientBackend, &str) -> Result<String, ClientError>,
+{
+    let channel = CHANNEL_FIXED;
+    let client_mutex = get_or_create_channel_mutex(camera_name, channel);
+    let Some(mut client_guard) =
+        lock_client_with_owner(&client_mutex, camera_name, channel, op, trace_id)
+    else {
+        return enc_filenames
+            .iter()
+            .map(|_| DecryptOutcome::Failed(DecryptError::Busy))
+            .collect();
+    };
+
+    let mut outcomes = Vec::with_capacity(enc_filenames.len());
+    let mut stopped = false;
+    for enc_filename in &enc_filenames {
+        if stopped {
+            outcomes.push(DecryptOutcome::Skipped);
+            continue;
         }
-        Err(_e) => {
-            return format!("Error(decrypt_thumbnail): {}", _e);
+
+        let res = decrypt_locked(
+            &mut **client_guard,
+            camera_name,
+            channel,
+            op,
+            None,
+            |client| decrypt(client, enc_filename),
+        );
+        match res {
+            Ok(decrypted) => outcomes.push(DecryptOutcome::Decrypted(decrypted)),
+            Err(e) => {
+                metrics::record_error(e.metric_kind());
+                if e.is_fatal_for_group() {
+                    warn!(
+                        "{} on camera {}: stopping batch at {} ({:?})",
+                        op, camera_name, enc_filename, e
+                    );
+                    stopped = true;
+                }
+                outcomes.push(DecryptOutcome::Failed(e));
+            }
         }
     }
+
+    outcomes
+}
+
+/// Decrypt a queue of motion videos in order under a single client lock.
+/// Returns one outcome per input file, in the same order.
+#[flutter_rust_bridge::frb]
+pub fn decrypt_videos_batch(
+    camera_name: String,
+    enc_filenames: Vec<String>,
+    context: Option<CallContext>,
+) -> Vec<DecryptOutcome> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("decrypt_videos_batch", camera = %camera_name).entered();
+    let _metrics = metrics::track("decrypt_videos_batch", &camera_name);
+    decrypt_batch(
+        &camera_name,
+        trace_id,
+        "decrypt_videos_batch(motion)",
+        enc_filenames,
+        |client, enc_filename| client.decrypt_video(enc_filename.to_string()),
+    )
+}
+
+/// Thumbnail counterpart of [`decrypt_videos_batch`].
+#[flutter_rust_bridge::frb]
+pub fn decrypt_thumbnails_batch(
+    camera_name: String,
+    enc_filenames: Vec<String>,
+    pending_meta_directory: String,
+    context: Option<CallContext>,
+) -> Vec<DecryptOutcome> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("decrypt_thumbnails_batch", camera = %camera_name).entered();
+    let _metrics = metrics::track("decrypt_thumbnails_batch", &camera_name);
+    decrypt_batch(
+        &camera_name,
+        trace_id,
+        "decrypt_thumbnails_batch(thumbnail)",
+        enc_filenames,
+        |client, enc_filename| {
+            client.decrypt_thumbnail(enc_filename.to_string(), pending_meta_directory.clone())
+        },
+    )
 }
 
+/// Pair with a camera and hand it the relay server `credentials`. `ip` and the
+/// credentials are checked before the camera is contacted.
+///
+/// The native client only pairs with a bare IPv4 address (optionally naming
+/// port 12348, the one it always dials), so hostnames, IPv6 addresses and other
+/// ports are refused before anything is resolved. Other backends take any
+/// address `probe_proprietary_device` accepts.
 #[flutter_rust_bridge::frb]
 pub fn flutter_add_camera(
     camera_name: String,
@@ -381,10 +879,64 @@ pub fn flutter_add_camera(
     ssid: String,
     password: String,
     pairing_token: String,
-    credentials_full: String,
+    credentials: ServerCredentials,
+    context: Option<CallContext>,
+) -> String {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let addr = match hotspot::device_addr(&ip) {
+        Ok(addr) => addr,
+        Err(e) => {
+            let _context_guard = logger::enter_call_context(context);
+            let _metrics = metrics::track("flutter_add_camera", &camera_name);
+            return refuse_pairing(&camera_name, e);
+        }
+    };
+    add_camera(
+        camera_name,
+        context,
+        addr,
+        secret,
+        standalone,
+        ssid,
+        password,
+        pairing_token,
+        credentials,
+    )
+}
+
+// Report a pairing turned down before the camera was contacted.
+fn refuse_pairing(camera_name: &str, error: String) -> String {
+    warn!("Refusing to pair {}: {}", camera_name, error);
+    let result = format!("Error(flutter_add_camera): {}", error);
+    metrics::record_error(metrics::ERROR_FAILED);
+    camera_events::emit(
+        camera_name,
+        CameraEventKind::PairingFailed,
+        Some(result.clone()),
+    );
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn add_camera(
+    camera_name: String,
+    context: CallContext,
+    addr: SocketAddr,
+    secret: Vec<u8>,
+    standalone: bool,
+    ssid: String,
+    password: String,
+    pairing_token: String,
+    credentials: ServerCredentials,
 ) -> String {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("flutter_add_camera", camera = %camera_name).entered();
+    let _metrics = metrics::track("flutter_add_camera", &camera_name);
+    if let Err(e) = credentials.validate() {
+        return refuse_pairing(&camera_name, e);
+    }
+
     let result = {
         let channel = CHANNEL_FIXED;
         let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
@@ -397,25 +949,25 @@ pub fn flutter_add_camera(
             trace_id,
             "Error: Busy".to_string()
         );
-        if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
+        if !ensure_client_initialized(&mut **client_guard, &camera_name, channel) {
             return "Error".to_string();
         }
 
         //TODO: Have this return a result, and then print the error (and return false)
-        secluso_app_native::add_camera(
-            &mut *client_guard,
+        client_guard.add_camera(
             camera_name.clone(),
-            ip,
+            addr,
             secret,
             standalone,
             ssid,
             password,
             pairing_token,
-            credentials_full,
+            credentials.encode(),
         )
     };
 
     if !result.starts_with("Error") {
+        camera_events::emit(&camera_name, CameraEventKind::Paired, Some(result.clone()));
         {
             let mut guard = INIT_PARAMS.lock();
             if let Some(params) = guard.get_mut(&camera_name) {
@@ -423,7 +975,7 @@ pub fn flutter_add_camera(
             }
         }
 
-        let entries: Vec<Arc<Mutex<Option<Box<Clients>>>>> = {
+        let entries: Vec<ClientSlot> = {
             let guard = CLIENTS.lock();
             guard
                 .iter()
@@ -433,18 +985,26 @@ pub fn flutter_add_camera(
         };
 
         for entry in entries {
-            match entry.try_lock_for(CLIENT_LOCK_TIMEOUT) {
+            match entry.try_lock_for(client_lock_timeout()) {
                 Some(mut guard) => {
-                    *guard = None;
+                    guard.reset();
                 }
                 None => {
                     warn!(
                         "Lock timeout after {:?} for reset_clients on camera {}",
-                        CLIENT_LOCK_TIMEOUT, camera_name
+                        client_lock_timeout(),
+                        camera_name
                     );
                 }
             }
         }
+    } else {
+        metrics::record_error(metrics::ERROR_FAILED);
+        camera_events::emit(
+            &camera_name,
+            CameraEventKind::PairingFailed,
+            Some(result.clone()),
+        );
     }
 
     result
@@ -467,6 +1027,11 @@ pub fn shutdown_app() {
         error!("logger shutdown error: {e:?}");
     }
 
+    let released = lock_manager::release_all_locks();
+    if released > 0 {
+        info!("shutdown_app(): released {} file lock(s)", released);
+    }
+
     // If there's ever a shtudown/cleanup function in the app_native layer,
     // we can call it here.
 
@@ -476,30 +1041,26 @@ pub fn shutdown_app() {
 #[flutter_rust_bridge::frb]
 pub fn ping_proprietary_device(camera_ip: String) -> bool {
     debug!("Pinging proprietary device at {}", camera_ip);
-    let addr = match SocketAddr::from_str(&(camera_ip + ":12348")) {
-        Ok(a) => a,
-        Err(e) => {
-            debug!("Invalid proprietary camera IP address: {e}");
-            return false;
-        }
-    };
-
-    match TcpStream::connect_timeout(&addr, Duration::from_secs(1)) {
-        Ok(stream) => {
-            let _ = stream.shutdown(Shutdown::Both);
-            true
-        }
+    match hotspot::probe_proprietary_device(camera_ip, None) {
+        Ok(reachable) => reachable,
         Err(e) => {
-            debug!("Proprietary device probe failed: {e}");
+            warn!("Not pinging proprietary device: {}", e);
             false
         }
     }
 }
 
 #[flutter_rust_bridge::frb]
-pub fn encrypt_settings_message(camera_name: String, data: Vec<u8>) -> Vec<u8> {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
+pub fn encrypt_settings_message(
+    camera_name: String,
+    data: Vec<u8>,
+    context: Option<CallContext>,
+) -> Vec<u8> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("encrypt_settings_message", camera = %camera_name).entered();
+    let _metrics = metrics::track("encrypt_settings_message", &camera_name);
     let channel = CHANNEL_FIXED;
     let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
     let op = "encrypt_settings_message(config)".to_string();
@@ -511,15 +1072,16 @@ pub fn encrypt_settings_message(camera_name: String, data: Vec<u8>) -> Vec<u8> {
         trace_id,
         Vec::new()
     );
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
+    if !ensure_client_initialized(&mut **client_guard, &camera_name, channel) {
         return Vec::new();
     }
 
-    match secluso_app_native::encrypt_settings_message(&mut *client_guard, data) {
+    match client_guard.encrypt_settings_message(data) {
         Ok(encrypted_message) => {
             return encrypted_message;
         }
         Err(e) => {
+            metrics::record_error(metrics::ERROR_FAILED);
             info!("Error: {}", e);
             return Vec::new();
         }
@@ -527,9 +1089,17 @@ pub fn encrypt_settings_message(camera_name: String, data: Vec<u8>) -> Vec<u8> {
 }
 
 #[flutter_rust_bridge::frb]
-pub fn decrypt_message(client_tag: String, camera_name: String, data: Vec<u8>) -> String {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
+pub fn decrypt_message(
+    client_tag: String,
+    camera_name: String,
+    data: Vec<u8>,
+    context: Option<CallContext>,
+) -> String {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("decrypt_message", camera = %camera_name).entered();
+    let _metrics = metrics::track("decrypt_message", &camera_name);
     let channel = CHANNEL_FIXED;
     let op = format!("decrypt_message({})", channel);
     let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
@@ -541,15 +1111,16 @@ pub fn decrypt_message(client_tag: String, camera_name: String, data: Vec<u8>) -
         trace_id,
         "Error: Busy".to_string()
     );
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
+    if !ensure_client_initialized(&mut **client_guard, &camera_name, channel) {
         return "Error".to_string();
     }
 
-    match secluso_app_native::decrypt_message(&mut *client_guard, &client_tag, data) {
+    match client_guard.decrypt_message(&client_tag, data) {
         Ok(timestamp) => {
             return timestamp;
         }
         Err(e) => {
+            metrics::record_error(metrics::ERROR_FAILED);
             info!("decrypt_message error: {}", e);
             return format!("Error(decrypt_message): {}", e);
         }
@@ -557,9 +1128,16 @@ pub fn decrypt_message(client_tag: String, camera_name: String, data: Vec<u8>) -
 }
 
 #[flutter_rust_bridge::frb]
-pub fn get_group_name(client_tag: String, camera_name: String) -> String {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
+pub fn get_group_name(
+    client_tag: String,
+    camera_name: String,
+    context: Option<CallContext>,
+) -> String {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("get_group_name", camera = %camera_name).entered();
+    let _metrics = metrics::track("get_group_name", &camera_name);
     let channel = CHANNEL_FIXED;
     let op = format!("get_group_name({})", channel);
     let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
@@ -571,15 +1149,16 @@ pub fn get_group_name(client_tag: String, camera_name: String) -> String {
         trace_id,
         "Error: Busy".to_string()
     );
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
+    if !ensure_client_initialized(&mut **client_guard, &camera_name, channel) {
         return "Error".to_string();
     }
 
-    match secluso_app_native::get_group_name(&mut *client_guard, &client_tag) {
+    match client_guard.get_group_name(&client_tag) {
         Ok(motion_group_name) => {
             return motion_group_name;
         }
         Err(e) => {
+            metrics::record_error(metrics::ERROR_FAILED);
             info!("get_group_name error: {}", e);
             return format!("Error(get_group_name): {}", e);
         }
@@ -587,27 +1166,13 @@ pub fn get_group_name(client_tag: String, camera_name: String) -> String {
 }
 
 #[flutter_rust_bridge::frb]
-pub fn livestream_update(camera_name: String, msg: Vec<u8>) -> bool {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
-    let channel = CHANNEL_FIXED;
-    let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
-    let op = "livestream_update(livestream)".to_string();
-    let mut client_guard =
-        lock_client_or_return!(client_mutex, &camera_name, channel, &op, trace_id, false);
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
-        return false;
-    }
-
-    match secluso_app_native::livestream_update(&mut *client_guard, msg) {
-        Ok(_) => {
-            return true;
-        }
-        Err(e) => {
-            info!("Error: {}", e);
-            return false;
-        }
-    }
+pub fn livestream_update(camera_name: String, msg: Vec<u8>, context: Option<CallContext>) -> bool {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("livestream_update", camera = %camera_name).entered();
+    let _metrics = metrics::track("livestream_update", &camera_name);
+    livestream_session::update(&camera_name, trace_id, None, msg).is_ok()
 }
 
 #[flutter_rust_bridge::frb]
@@ -615,31 +1180,15 @@ pub fn livestream_decrypt(
     camera_name: String,
     data: Vec<u8>,
     expected_chunk_number: u64,
+    context: Option<CallContext>,
 ) -> Vec<u8> {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
-    let channel = CHANNEL_FIXED;
-    let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
-    let op = "livestream_decrypt(livestream)".to_string();
-    let mut client_guard =
-        lock_client_or_return!(client_mutex, &camera_name, channel, &op, trace_id, vec![]);
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
-        return vec![];
-    }
-
-    let ret = match secluso_app_native::livestream_decrypt(
-        &mut *client_guard,
-        data,
-        expected_chunk_number,
-    ) {
-        Ok(dec_data) => dec_data,
-        Err(e) => {
-            info!("Error: {}", e);
-            vec![]
-        }
-    };
-
-    ret
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("livestream_decrypt", camera = %camera_name).entered();
+    let _metrics = metrics::track("livestream_decrypt", &camera_name);
+    livestream_session::decrypt(&camera_name, trace_id, None, data, expected_chunk_number)
+        .unwrap_or_default()
 }
 
 #[flutter_rust_bridge::frb]
@@ -648,24 +1197,30 @@ pub fn rust_lib_version() -> String {
 }
 
 #[flutter_rust_bridge::frb]
-pub fn generate_heartbeat_request_config_command(camera_name: String, timestamp: u64) -> Vec<u8> {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
+pub fn generate_heartbeat_request_config_command(
+    camera_name: String,
+    timestamp: u64,
+    context: Option<CallContext>,
+) -> Vec<u8> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span =
+        info_span!("generate_heartbeat_request_config_command", camera = %camera_name).entered();
+    let _metrics = metrics::track("generate_heartbeat_request_config_command", &camera_name);
     let channel = CHANNEL_FIXED;
     let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
     let op = "generate_heartbeat_request_config_command(config)".to_string();
     let mut client_guard =
         lock_client_or_return!(client_mutex, &camera_name, channel, &op, trace_id, vec![]);
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
+    if !ensure_client_initialized(&mut **client_guard, &camera_name, channel) {
         return vec![];
     }
 
-    let ret = match secluso_app_native::generate_heartbeat_request_config_command(
-        &mut *client_guard,
-        timestamp,
-    ) {
+    let ret = match client_guard.generate_heartbeat_request_config_command(timestamp) {
         Ok(config_msg_enc) => config_msg_enc,
         Err(e) => {
+            metrics::record_error(metrics::ERROR_FAILED);
             info!("Error: {}", e);
             vec![]
         }
@@ -679,9 +1234,13 @@ pub fn process_heartbeat_config_response(
     camera_name: String,
     config_response: Vec<u8>,
     expected_timestamp: u64,
+    context: Option<CallContext>,
 ) -> String {
-    let (camera_name, trace_id) = split_trace_camera(&camera_name);
-    let _trace_guard = logger::set_log_trace(trace_id);
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let trace_id = context.trace_id.as_deref();
+    let _span = info_span!("process_heartbeat_config_response", camera = %camera_name).entered();
+    let _metrics = metrics::track("process_heartbeat_config_response", &camera_name);
     let channel = CHANNEL_FIXED;
     let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
     let op = "process_heartbeat_config_response(config)".to_string();
@@ -693,21 +1252,30 @@ pub fn process_heartbeat_config_response(
         trace_id,
         "Error".to_string()
     );
-    if !ensure_client_initialized(&mut *client_guard, &camera_name, channel) {
+    if !ensure_client_initialized(&mut **client_guard, &camera_name, channel) {
         return "Error".to_string();
     }
 
-    match secluso_app_native::process_heartbeat_config_response(
-        &mut *client_guard,
-        config_response,
-        expected_timestamp,
-    ) {
-        Ok(heartbeat_response) => {
-            return heartbeat_response;
-        }
-        Err(e) => {
-            info!("process_heartbeat_config_response error: {}", e);
-            return format!("Error(process_heartbeat_config_response): {}", e);
-        }
+    let result =
+        match client_guard.process_heartbeat_config_response(config_response, expected_timestamp) {
+            Ok(heartbeat_response) => heartbeat_response,
+            Err(e) => {
+                metrics::record_error(metrics::ERROR_FAILED);
+                info!("process_heartbeat_config_response error: {}", e);
+                format!("Error(process_heartbeat_config_response): {}", e)
+            }
+        };
+    match result.strip_prefix("healthy_") {
+        Some(firmware_version) => camera_events::emit(
+            &camera_name,
+            CameraEventKind::HeartbeatHealthy,
+            Some(firmware_version.to_string()),
+        ),
+        None => camera_events::emit(
+            &camera_name,
+            CameraEventKind::HeartbeatFailed,
+            Some(result.clone()),
+        ),
     }
+    result
 }
@@ -0,0 +1,150 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Typed decoding of push notifications from the camera.
+//!
+//! `decrypt_message` hands Dart whatever string the native client produced and
+//! `firebase.dart` interprets it: "Download" means a video was uploaded, "None"
+//! means nothing to do, a JSON object is reserved for typed messages, and
+//! anything else is the timestamp of a motion event. This module does that
+//! interpretation once, on the Rust side.
+
+use log::info;
+use tracing::info_span;
+
+use crate::backend::{CameraClientBackend, ClientError};
+
+use super::logger::CallContext;
+use super::{
+    ensure_client_initialized, get_or_create_channel_mutex, lock_client_with_owner, logger,
+    metrics, resolve_call, CHANNEL_FIXED,
+};
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationPayload {
+    /// The camera detected motion and is recording. `timestamp` names the
+    /// thumbnail and video that follow, so it is kept as sent.
+    Motion { timestamp: String },
+    /// A motion video has been uploaded and can be downloaded.
+    VideoReady,
+    /// The message decrypted but carried nothing to act on.
+    Empty,
+    /// A JSON object with a `type` key. No firmware sends these yet; `json` is
+    /// the whole decoded message.
+    Typed { kind: String, json: String },
+    /// The message decrypted to something this version does not understand.
+    Unknown { raw: String },
+}
+
+/// Why a notification did not decrypt.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationError {
+    /// The client lock was held by someone else past the timeout.
+    Busy,
+    /// The client could not be created (missing init params or bad state on disk).
+    NotInitialized,
+    /// This message was already decrypted once; FCM delivered it again. Safe to drop.
+    DuplicateMessage,
+    /// Any other error reported by the native layer.
+    Failed(String),
+}
+
+impl NotificationError {
+    pub(super) fn metric_kind(&self) -> &'static str {
+        match self {
+            NotificationError::Busy => metrics::ERROR_BUSY,
+            NotificationError::NotInitialized => metrics::ERROR_NOT_INITIALIZED,
+            NotificationError::DuplicateMessage => metrics::ERROR_DUPLICATE,
+            NotificationError::Failed(_) => metrics::ERROR_FAILED,
+        }
+    }
+}
+
+/// Interpret the plaintext of a notification as the native client returns it.
+#[flutter_rust_bridge::frb(ignore)]
+pub fn parse_notification(plaintext: &str) -> NotificationPayload {
+    match plaintext {
+        "Download" => return NotificationPayload::VideoReady,
+        "None" => return NotificationPayload::Empty,
+        _ => {}
+    }
+    match serde_json::from_str::<serde_json::Value>(plaintext) {
+        Ok(serde_json::Value::Object(fields)) => {
+            match fields.get("type").and_then(|t| t.as_str()) {
+                Some(kind) => NotificationPayload::Typed {
+                    kind: kind.to_string(),
+                    json: plaintext.to_string(),
+                },
+                None => NotificationPayload::Unknown {
+                    raw: plaintext.to_string(),
+                },
+            }
+        }
+        _ if plaintext.is_empty() || plaintext.starts_with("Error") => {
+            NotificationPayload::Unknown {
+                raw: plaintext.to_string(),
+            }
+        }
+        _ => NotificationPayload::Motion {
+            timestamp: plaintext.to_string(),
+        },
+    }
+}
+
+/// Decrypt a push notification from `camera_name` and decode what it says.
+/// Replaces `decrypt_message` for callers that want a typed result.
+#[flutter_rust_bridge::frb]
+pub fn decrypt_notification(
+    camera_name: String,
+    client_tag: String,
+    data: Vec<u8>,
+    context: Option<CallContext>,
+) -> Result<NotificationPayload, NotificationError> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let _span = info_span!("decrypt_notification", camera = %camera_name).entered();
+    let _metrics = metrics::track("decrypt_notification", &camera_name);
+    let channel = CHANNEL_FIXED;
+    let op = format!("decrypt_notification({})", channel);
+    let client_mutex = get_or_create_channel_mutex(&camera_name, channel);
+
+    let result = lock_client_with_owner(
+        &client_mutex,
+        &camera_name,
+        channel,
+        &op,
+        context.trace_id.as_deref(),
+    )
+    .ok_or(NotificationError::Busy)
+    .and_then(|mut client_guard| {
+        decrypt_with_client(&mut **client_guard, &camera_name, &client_tag, data)
+    });
+    if let Err(e) = &result {
+        metrics::record_error(e.metric_kind());
+    }
+    result
+}
+
+/// Decrypt and decode one notification with the client lock already held.
+pub(super) fn decrypt_with_client(
+    client: &mut dyn CameraClientBackend,
+    camera_name: &str,
+    client_tag: &str,
+    data: Vec<u8>,
+) -> Result<NotificationPayload, NotificationError> {
+    if !ensure_client_initialized(client, camera_name, CHANNEL_FIXED) {
+        return Err(NotificationError::NotInitialized);
+    }
+    match client.decrypt_message(client_tag, data) {
+        Ok(plaintext) => Ok(parse_notification(&plaintext)),
+        Err(ClientError::SecretReuse) => {
+            info!("Dropping duplicate notification for {}", camera_name);
+            Err(NotificationError::DuplicateMessage)
+        }
+        Err(e) => {
+            info!("decrypt_notification error for {}: {}", camera_name, e);
+            Err(NotificationError::Failed(e.to_string()))
+        }
+    }
+}
@@ -0,0 +1,600 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Pairing as a sequence of resumable steps.
+//!
+//! `flutter_add_camera` does the whole handshake in one call, so a Wi-Fi drop
+//! on the camera hotspot means starting over. A pairing session instead moves
+//! through `PairingPhase`s one `pairing_step` at a time and saves its state to
+//! `<file_dir>/pairing_session.json` after every step, so a retry (even after
+//! the app restarts) continues with the phase that failed.
+//!
+//! The request, with the camera secret and the Wi-Fi and server passwords, is
+//! only kept in memory. A session resumed after a restart before its key
+//! exchange needs the request passed to `resume_pairing` again.
+//!
+//! The native client exchanges keys and hands over the Wi-Fi credentials in a
+//! single call, so `KeyExchange` covers both and `WifiProvisioning` only gives
+//! the camera time to read them before the phone leaves its hotspot.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::net::{SocketAddr, TcpStream};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::info_span;
+
+use crate::api::credentials::ServerCredentials;
+use crate::frb_generated::StreamSink;
+
+use super::hotspot::device_addr;
+use super::logger::{self, CallContext};
+use super::{add_camera, metrics, resolve_call, INIT_PARAMS};
+
+const SESSION_FILE: &str = "pairing_session.json";
+const SESSION_FORMAT_VERSION: u64 = 1;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+/// How long the camera needs to read the Wi-Fi payload after the key exchange.
+const CAMERA_READ_GRACE: Duration = Duration::from_secs(3);
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairingPhase {
+    /// Checking that the camera answers on its pairing port.
+    Connecting,
+    /// Exchanging keys with the camera and handing over server (and Wi-Fi) credentials.
+    KeyExchange,
+    /// Giving the camera time to read the Wi-Fi credentials before the phone
+    /// leaves its hotspot. Skipped when there are none.
+    WifiProvisioning,
+    /// Waiting for the server to report the camera paired.
+    WaitingForCameraOnline,
+    Done,
+    /// The camera refused to pair; starting over is the only option.
+    Failed,
+    Cancelled,
+}
+
+impl PairingPhase {
+    fn name(self) -> &'static str {
+        match self {
+            PairingPhase::Connecting => "connecting",
+            PairingPhase::KeyExchange => "key_exchange",
+            PairingPhase::WifiProvisioning => "wifi_provisioning",
+            PairingPhase::WaitingForCameraOnline => "waiting_for_camera_online",
+            PairingPhase::Done => "done",
+            PairingPhase::Failed => "failed",
+            PairingPhase::Cancelled => "cancelled",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            PairingPhase::Connecting,
+            PairingPhase::KeyExchange,
+            PairingPhase::WifiProvisioning,
+            PairingPhase::WaitingForCameraOnline,
+            PairingPhase::Done,
+            PairingPhase::Failed,
+            PairingPhase::Cancelled,
+        ]
+        .into_iter()
+        .find(|phase| phase.name() == name)
+    }
+
+    fn is_finished(self) -> bool {
+        matches!(
+            self,
+            PairingPhase::Done | PairingPhase::Failed | PairingPhase::Cancelled
+        )
+    }
+}
+
+/// Everything `flutter_add_camera` needs, given once when pairing begins.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct PairingRequest {
+    pub camera_name: String,
+    /// Any address `flutter_add_camera` accepts. It is resolved once in
+    /// `Connecting`, and the key exchange talks to the address that answered.
+    pub ip: String,
+    pub secret: Vec<u8>,
+    pub standalone: bool,
+    pub ssid: String,
+    pub wifi_password: String,
+    pub pairing_token: String,
+    pub credentials: ServerCredentials,
+}
+
+/// Where a pairing session stands, as returned by every call and sent to watchers.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairingSession {
+    pub camera_name: String,
+    pub phase: PairingPhase,
+    /// Set once the key exchange succeeded.
+    pub firmware_version: Option<String>,
+    /// Failed attempts at the current phase.
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub updated_at_millis: i64,
+}
+
+struct ActiveSession {
+    file: PathBuf,
+    session: PairingSession,
+    // Never saved, and cleared once the key exchange no longer needs it.
+    request: Option<PairingRequest>,
+    // Where `Connecting` reached the camera.
+    addr: Option<SocketAddr>,
+    key_exchanged_at_millis: Option<i64>,
+    watchers: Vec<StreamSink<PairingSession>>,
+}
+
+type SessionSlot = Arc<Mutex<ActiveSession>>;
+
+static SESSIONS: Lazy<Mutex<HashMap<String, SessionSlot>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| Duration::from_secs(0))
+        .as_millis() as i64
+}
+
+fn session_file(camera_name: &str) -> Result<PathBuf, String> {
+    INIT_PARAMS
+        .lock()
+        .get(camera_name)
+        .map(|params| PathBuf::from(&params.file_dir).join(SESSION_FILE))
+        .ok_or_else(|| format!("camera {} is not initialized", camera_name))
+}
+
+impl ActiveSession {
+    fn to_json(&self) -> Value {
+        json!({
+            "version": SESSION_FORMAT_VERSION,
+            "camera_name": self.session.camera_name,
+            "phase": self.session.phase.name(),
+            "firmware_version": self.session.firmware_version,
+            "attempts": self.session.attempts,
+            "last_error": self.session.last_error,
+            "updated_at_millis": self.session.updated_at_millis,
+            "key_exchanged_at_millis": self.key_exchanged_at_millis,
+        })
+    }
+
+    fn from_json(file: PathBuf, value: &Value) -> Result<Self, String> {
+        let bad = |field: &str| format!("pairing session has a bad {:?} field", field);
+        if value["version"].as_u64() != Some(SESSION_FORMAT_VERSION) {
+            return Err(format!(
+                "unsupported pairing session version {}",
+                value["version"]
+            ));
+        }
+        let text = |field: &str| value[field].as_str().map(str::to_string);
+        let camera_name = text("camera_name").ok_or_else(|| bad("camera_name"))?;
+        Ok(ActiveSession {
+            file,
+            session: PairingSession {
+                camera_name,
+                phase: text("phase")
+                    .as_deref()
+                    .and_then(PairingPhase::from_name)
+                    .ok_or_else(|| bad("phase"))?,
+                firmware_version: text("firmware_version"),
+                attempts: value["attempts"].as_u64().unwrap_or(0) as u32,
+                last_error: text("last_error"),
+                updated_at_millis: value["updated_at_millis"].as_i64().unwrap_or(0),
+            },
+            request: None,
+            addr: None,
+            key_exchanged_at_millis: value["key_exchanged_at_millis"].as_i64(),
+            watchers: Vec::new(),
+        })
+    }
+
+    // Save the new state (or remove it once pairing is over) and tell watchers.
+    fn publish(&mut self) {
+        self.session.updated_at_millis = now_millis();
+        let saved = if self.session.phase.is_finished() {
+            match fs::remove_file(&self.file) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        } else {
+            let tmp = self.file.with_extension("tmp");
+            fs::write(&tmp, self.to_json().to_string()).and_then(|_| fs::rename(&tmp, &self.file))
+        };
+        if let Err(e) = saved {
+            warn!("Failed to save pairing session to {:?}: {}", self.file, e);
+        }
+        let session = self.session.clone();
+        self.watchers
+            .retain(|sink| sink.add(session.clone()).is_ok());
+    }
+
+    fn advance(&mut self, phase: PairingPhase) {
+        info!(
+            "Pairing {} moved from {} to {}",
+            self.session.camera_name,
+            self.session.phase.name(),
+            phase.name()
+        );
+        self.session.phase = phase;
+        self.session.attempts = 0;
+        self.session.last_error = None;
+        self.publish();
+    }
+
+    fn fail_attempt(&mut self, error: String) -> String {
+        warn!(
+            "Pairing {} failed in {}: {}",
+            self.session.camera_name,
+            self.session.phase.name(),
+            error
+        );
+        self.session.attempts += 1;
+        self.session.last_error = Some(error.clone());
+        self.publish();
+        error
+    }
+
+    fn missing_request(&self) -> String {
+        format!(
+            "pairing {} was resumed without its request; pass it to resume_pairing",
+            self.session.camera_name
+        )
+    }
+
+    // Run the current phase once. On failure the phase stays put for a retry.
+    fn step(&mut self, camera_online: bool) -> Result<(), String> {
+        match self.session.phase {
+            PairingPhase::Connecting => {
+                let Some(request) = &self.request else {
+                    return Err(self.missing_request());
+                };
+                let probe = device_addr(&request.ip).and_then(|addr| {
+                    TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT)
+                        .map(|_| addr)
+                        .map_err(|e| format!("camera at {} is unreachable: {}", addr, e))
+                });
+                match probe {
+                    Ok(addr) => {
+                        self.addr = Some(addr);
+                        self.advance(PairingPhase::KeyExchange);
+                    }
+                    Err(e) => return Err(self.fail_attempt(e)),
+                }
+            }
+            PairingPhase::KeyExchange => {
+                let Some(request) = self.request.clone() else {
+                    return Err(self.missing_request());
+                };
+                // Resumed straight into this phase; resolve again.
+                let addr = match self.addr {
+                    Some(addr) => addr,
+                    None => device_addr(&request.ip).map_err(|e| self.fail_attempt(e))?,
+                };
+                let result = add_camera(
+                    request.camera_name,
+                    logger::current_call_context(),
+                    addr,
+                    request.secret,
+                    request.standalone,
+                    request.ssid.clone(),
+                    request.wifi_password,
+                    request.pairing_token,
+                    request.credentials,
+                );
+                if result == "PairVersionIncompatible" {
+                    let error = "incompatible firmware version".to_string();
+                    self.session.phase = PairingPhase::Failed;
+                    self.session.last_error = Some(error.clone());
+                    self.publish();
+                    return Err(error);
+                }
+                if result.starts_with("Error") {
+                    return Err(self.fail_attempt(result));
+                }
+                self.session.firmware_version = Some(result);
+                self.key_exchanged_at_millis = Some(now_millis());
+                self.request = None;
+                if request.ssid.is_empty() {
+                    self.advance(PairingPhase::WaitingForCameraOnline);
+                } else {
+                    self.advance(PairingPhase::WifiProvisioning);
+                }
+            }
+            PairingPhase::WifiProvisioning => {
+                let since = now_millis() - self.key_exchanged_at_millis.unwrap_or(0);
+                let remaining =
+                    CAMERA_READ_GRACE.saturating_sub(Duration::from_millis(since.max(0) as u64));
+                thread::sleep(remaining);
+                self.advance(PairingPhase::WaitingForCameraOnline);
+            }
+            PairingPhase::WaitingForCameraOnline => {
+                if camera_online {
+                    self.advance(PairingPhase::Done);
+                }
+            }
+            PairingPhase::Done | PairingPhase::Failed | PairingPhase::Cancelled => {
+                return Err(format!("pairing already {}", self.session.phase.name()));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_request(request: &PairingRequest) -> Result<(), String> {
+    request.credentials.validate()?;
+    device_addr(&request.ip)?;
+    Ok(())
+}
+
+fn session_slot(camera_name: &str) -> Result<SessionSlot, String> {
+    SESSIONS
+        .lock()
+        .get(camera_name)
+        .cloned()
+        .ok_or_else(|| format!("no pairing in progress for {}", camera_name))
+}
+
+// Drop `slot` from the registry, unless `begin_pairing` has replaced it while
+// the caller held it.
+fn remove_slot(camera_name: &str, slot: &SessionSlot) {
+    let mut sessions = SESSIONS.lock();
+    if sessions
+        .get(camera_name)
+        .is_some_and(|current| Arc::ptr_eq(current, slot))
+    {
+        sessions.remove(camera_name);
+    }
+}
+
+/// Start pairing `request.camera_name`, replacing any earlier unfinished
+/// session. `initialize_camera` must have been called for it. Nothing is sent
+/// to the camera until the first `pairing_step`.
+#[flutter_rust_bridge::frb]
+pub fn begin_pairing(
+    request: PairingRequest,
+    context: Option<CallContext>,
+) -> Result<PairingSession, String> {
+    let (camera_name, context) = resolve_call(&request.camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("begin_pairing", camera = %camera_name).entered();
+    let _metrics = metrics::track("begin_pairing", &camera_name);
+    check_request(&request)?;
+    let file = session_file(&camera_name)?;
+
+    let mut active = ActiveSession {
+        file,
+        session: PairingSession {
+            camera_name: camera_name.clone(),
+            phase: PairingPhase::Connecting,
+            firmware_version: None,
+            attempts: 0,
+            last_error: None,
+            updated_at_millis: 0,
+        },
+        request: Some(PairingRequest {
+            camera_name: camera_name.clone(),
+            ..request
+        }),
+        addr: None,
+        key_exchanged_at_millis: None,
+        watchers: Vec::new(),
+    };
+    // Keep the watchers of a session being replaced; they are watching the camera.
+    // The slot is locked on its own, as a step may be holding it.
+    let previous = SESSIONS.lock().get(&camera_name).cloned();
+    if let Some(previous) = previous {
+        active.watchers = std::mem::take(&mut previous.lock().watchers);
+    }
+    active.publish();
+    let session = active.session.clone();
+    SESSIONS
+        .lock()
+        .insert(camera_name, Arc::new(Mutex::new(active)));
+    Ok(session)
+}
+
+/// Run the current phase of the pairing for `camera_name` once and return the
+/// new state. On failure the phase stays the same, so calling this again (or
+/// `resume_pairing` after a restart, then this) retries just that phase.
+///
+/// The server, not the camera, confirms the camera is online, so Dart passes
+/// what the server reports in `camera_online`; it only matters in
+/// `WaitingForCameraOnline`.
+#[flutter_rust_bridge::frb]
+pub fn pairing_step(
+    camera_name: String,
+    camera_online: bool,
+    context: Option<CallContext>,
+) -> Result<PairingSession, String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("pairing_step", camera = %camera_name).entered();
+    let _metrics = metrics::track("pairing_step", &camera_name);
+    let slot = session_slot(&camera_name)?;
+    let Some(mut active) = slot.try_lock() else {
+        return Err(format!(
+            "a pairing step for {} is already running",
+            camera_name
+        ));
+    };
+    let result = active.step(camera_online);
+    let session = active.session.clone();
+    drop(active);
+    if session.phase.is_finished() {
+        remove_slot(&camera_name, &slot);
+    }
+    result.inspect_err(|_| metrics::record_error(metrics::ERROR_FAILED))?;
+    Ok(session)
+}
+
+/// The unfinished pairing for `camera_name`, loaded from disk if this process
+/// has not seen it yet (e.g. after a restart). Sessions that have not finished
+/// their key exchange need `request` again, since it is never saved; later
+/// ones ignore it.
+#[flutter_rust_bridge::frb]
+pub fn resume_pairing(
+    camera_name: String,
+    request: Option<PairingRequest>,
+    context: Option<CallContext>,
+) -> Result<PairingSession, String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("resume_pairing", camera = %camera_name).entered();
+    if let Some(request) = &request {
+        check_request(request)?;
+    }
+    let needs_request =
+        |phase: PairingPhase| matches!(phase, PairingPhase::Connecting | PairingPhase::KeyExchange);
+    let request = request.map(|request| PairingRequest {
+        camera_name: camera_name.clone(),
+        ..request
+    });
+
+    if let Ok(slot) = session_slot(&camera_name) {
+        let mut active = slot.lock();
+        if active.request.is_none() && needs_request(active.session.phase) {
+            active.request = request;
+        }
+        return Ok(active.session.clone());
+    }
+
+    let file = session_file(&camera_name)?;
+    let text = fs::read_to_string(&file)
+        .map_err(|_| format!("no pairing in progress for {}", camera_name))?;
+    let value: Value = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+    let mut active = ActiveSession::from_json(file, &value)?;
+    if active.session.camera_name != camera_name {
+        return Err(format!(
+            "pairing session on disk belongs to {}",
+            active.session.camera_name
+        ));
+    }
+    if needs_request(active.session.phase) {
+        active.request = request;
+    }
+    info!(
+        "Resumed pairing {} at {}",
+        camera_name,
+        active.session.phase.name()
+    );
+    let session = active.session.clone();
+    SESSIONS
+        .lock()
+        .entry(camera_name)
+        .or_insert_with(|| Arc::new(Mutex::new(active)));
+    Ok(session)
+}
+
+/// Send every later state of the pairing for `camera_name` to `sink`,
+/// starting with the current one.
+#[flutter_rust_bridge::frb]
+pub fn watch_pairing(
+    camera_name: String,
+    sink: StreamSink<PairingSession>,
+    context: Option<CallContext>,
+) -> Result<(), String> {
+    let (camera_name, _) = resolve_call(&camera_name, context);
+    let slot = session_slot(&camera_name)?;
+    let mut active = slot.lock();
+    if sink.add(active.session.clone()).is_ok() {
+        active.watchers.push(sink);
+    }
+    Ok(())
+}
+
+/// Abandon the pairing for `camera_name` and delete its saved state. If the key
+/// exchange already happened, the camera stays in the client state until it is
+/// deregistered.
+#[flutter_rust_bridge::frb]
+pub fn cancel_pairing(
+    camera_name: String,
+    context: Option<CallContext>,
+) -> Result<PairingSession, String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("cancel_pairing", camera = %camera_name).entered();
+    if session_slot(&camera_name).is_err() {
+        resume_pairing(
+            camera_name.clone(),
+            None,
+            Some(logger::current_call_context()),
+        )?;
+    }
+    let slot = session_slot(&camera_name)?;
+    // Waits for a running step; the camera handshake can't be interrupted midway.
+    let mut active = slot.lock();
+    active.request = None;
+    active.advance(PairingPhase::Cancelled);
+    let session = active.session.clone();
+    drop(active);
+    remove_slot(&camera_name, &slot);
+    Ok(session)
+}
+
+// Called from `deregister_camera`: the session's state on disk goes with the camera.
+pub(super) fn forget(camera_name: &str) {
+    let slot = SESSIONS.lock().remove(camera_name);
+    let file = match slot {
+        Some(slot) => Some(slot.lock().file.clone()),
+        None => session_file(camera_name).ok(),
+    };
+    if let Some(file) = file {
+        let _ = fs::remove_file(file);
+    }
+}
+
+/// Drop in-memory sessions as if the app had restarted.
+#[cfg(feature = "test-support")]
+#[flutter_rust_bridge::frb(ignore)]
+pub fn forget_pairing_sessions_for_tests() {
+    SESSIONS.lock().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(camera_name: &str) -> SessionSlot {
+        Arc::new(Mutex::new(ActiveSession {
+            file: PathBuf::new(),
+            session: PairingSession {
+                camera_name: camera_name.to_string(),
+                phase: PairingPhase::Connecting,
+                firmware_version: None,
+                attempts: 0,
+                last_error: None,
+                updated_at_millis: 0,
+            },
+            request: None,
+            addr: None,
+            key_exchanged_at_millis: None,
+            watchers: Vec::new(),
+        }))
+    }
+
+    #[test]
+    fn a_replaced_slot_does_not_remove_its_replacement() {
+        let camera_name = "replaced-slot";
+        let old = slot(camera_name);
+        let new = slot(camera_name);
+        SESSIONS.lock().insert(camera_name.to_string(), old.clone());
+        SESSIONS.lock().insert(camera_name.to_string(), new.clone());
+
+        remove_slot(camera_name, &old);
+        assert!(Arc::ptr_eq(&session_slot(camera_name).unwrap(), &new));
+        remove_slot(camera_name, &new);
+        assert!(session_slot(camera_name).is_err());
+    }
+}
@@ -0,0 +1,403 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! A persistent inbox for push messages, so FCM handlers only have to store what
+//! arrives and decryption can happen in one ordered pass later.
+//!
+//! Each camera's queue lives in `<file_dir>/push_queue`, one file per message,
+//! named `<arrival seq>-<sha256 of the message>.msg`. The hash in the name is
+//! what deduplicates redeliveries. An flock on `push_queue/.lock` serializes
+//! changes to the directory across isolates and processes. It is only held
+//! while files are listed, written or removed, never while waiting for the
+//! client lock or decrypting, so enqueues don't stall behind a drain.
+//!
+//! The native client can't read a message's ratchet generation without
+//! decrypting it, so messages are ordered by the time the push service says
+//! they were sent, and by generation on top of that when the client can tell.
+
+use log::{debug, info, warn};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::info_span;
+
+use super::lock_manager::{self, LockHandle};
+use super::logger::CallContext;
+use super::notifications::{decrypt_with_client, NotificationError, NotificationPayload};
+use super::{
+    get_or_create_channel_mutex, lock_client_with_owner, logger, metrics, resolve_call,
+    CHANNEL_FIXED, INIT_PARAMS,
+};
+
+const QUEUE_DIR: &str = "push_queue";
+const LOCK_FILE: &str = ".lock";
+const MESSAGE_EXT: &str = "msg";
+/// A message that fails this many drains is dropped rather than retried forever.
+const MAX_DECRYPT_ATTEMPTS: u32 = 3;
+const LOCK_ATTEMPTS: u32 = 3;
+const LOCK_RETRY_DELAY: Duration = Duration::from_millis(500);
+const QUEUE_LOCK_TIMEOUT_MILLIS: u64 = 2000;
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct DrainedPushMessage {
+    /// Hex SHA-256 of the encrypted message, as used for deduplication.
+    pub content_hash: String,
+    pub received_at_millis: i64,
+    /// When the push service says the message was sent, if the caller knew.
+    pub sent_at_millis: Option<i64>,
+    /// Ratchet generation the message was ordered by, when the client could read it.
+    pub generation: Option<u64>,
+    pub payload: NotificationPayload,
+}
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Default)]
+pub struct PushDrainReport {
+    /// Messages that decrypted, in the order they were decrypted.
+    pub messages: Vec<DrainedPushMessage>,
+    /// Messages the client had already seen, dropped from the queue.
+    pub duplicates: u32,
+    /// Messages dropped after failing `MAX_DECRYPT_ATTEMPTS` drains.
+    pub dropped: u32,
+    /// Messages still queued for a later drain.
+    pub remaining: u32,
+    /// The client lock stayed busy, so nothing was decrypted.
+    pub busy: bool,
+}
+
+struct QueuedMessage {
+    path: PathBuf,
+    seq: u64,
+    content_hash: String,
+    received_at_millis: i64,
+    sent_at_millis: Option<i64>,
+    attempts: u32,
+    client_tag: String,
+    data: Vec<u8>,
+}
+
+const HEADER_LEN: usize = 23;
+
+// File layout: received_at_millis i64 | sent_at present u8 | sent_at_millis i64 |
+// attempts u32 | tag length u16 | tag | data
+impl QueuedMessage {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_LEN + self.client_tag.len() + self.data.len());
+        out.extend_from_slice(&self.received_at_millis.to_be_bytes());
+        out.push(self.sent_at_millis.is_some() as u8);
+        out.extend_from_slice(&self.sent_at_millis.unwrap_or(0).to_be_bytes());
+        out.extend_from_slice(&self.attempts.to_be_bytes());
+        out.extend_from_slice(&(self.client_tag.len() as u16).to_be_bytes());
+        out.extend_from_slice(self.client_tag.as_bytes());
+        out.extend_from_slice(&self.data);
+        out
+    }
+
+    fn read(path: PathBuf, seq: u64, content_hash: String) -> io::Result<Self> {
+        let bytes = fs::read(&path)?;
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "truncated queue entry");
+        if bytes.len() < HEADER_LEN {
+            return Err(invalid());
+        }
+        let received_at_millis = i64::from_be_bytes(bytes[0..8].try_into().unwrap());
+        let sent_at_millis = i64::from_be_bytes(bytes[9..17].try_into().unwrap());
+        let attempts = u32::from_be_bytes(bytes[17..21].try_into().unwrap());
+        let tag_len = u16::from_be_bytes(bytes[21..23].try_into().unwrap()) as usize;
+        let tag = bytes
+            .get(HEADER_LEN..HEADER_LEN + tag_len)
+            .ok_or_else(invalid)?;
+        Ok(QueuedMessage {
+            path,
+            seq,
+            content_hash,
+            received_at_millis,
+            sent_at_millis: (bytes[8] != 0).then_some(sent_at_millis),
+            attempts,
+            client_tag: String::from_utf8_lossy(tag).into_owned(),
+            data: bytes[HEADER_LEN + tag_len..].to_vec(),
+        })
+    }
+
+    // Write to a temporary name first so a crash never leaves half an entry.
+    fn write(&self) -> io::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, self.encode())?;
+        fs::rename(&tmp, &self.path)
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| Duration::from_secs(0))
+        .as_millis() as i64
+}
+
+fn content_hash(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn queue_dir(camera_name: &str) -> Result<PathBuf, String> {
+    INIT_PARAMS
+        .lock()
+        .get(camera_name)
+        .map(|params| Path::new(&params.file_dir).join(QUEUE_DIR))
+        .ok_or_else(|| format!("camera {} is not initialized", camera_name))
+}
+
+// Held until the returned handle is dropped. Gives up after
+// `QUEUE_LOCK_TIMEOUT_MILLIS` rather than hang on a stuck holder.
+fn lock_queue(dir: &Path) -> Result<LockHandle, String> {
+    fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let path = dir.join(LOCK_FILE).to_string_lossy().into_owned();
+    lock_manager::acquire_lock_with_timeout(path, QUEUE_LOCK_TIMEOUT_MILLIS, None)?
+        .ok_or_else(|| "push queue is busy".to_string())
+}
+
+// Every readable entry in the queue, in arrival order. Unreadable ones are removed.
+fn read_queue(dir: &Path) -> Result<Vec<QueuedMessage>, String> {
+    let mut queued = Vec::new();
+    for (seq, hash, path) in list_entries(dir)? {
+        match QueuedMessage::read(path.clone(), seq, hash) {
+            Ok(message) => queued.push(message),
+            Err(e) => {
+                warn!("Dropping unreadable push queue entry {:?}: {}", path, e);
+                let _ = fs::remove_file(&path);
+            }
+        }
+    }
+    Ok(queued)
+}
+
+// Queue entries as (seq, hash, path), in arrival order. Leftover temporaries
+// and foreign files are ignored.
+fn list_entries(dir: &Path) -> Result<Vec<(u64, String, PathBuf)>, String> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(MESSAGE_EXT) {
+            continue;
+        }
+        let Some((seq, hash)) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.split_once('-'))
+        else {
+            continue;
+        };
+        let Ok(seq) = u64::from_str_radix(seq, 16) else {
+            continue;
+        };
+        entries.push((seq, hash.to_string(), path));
+    }
+    entries.sort_by_key(|(seq, _, _)| *seq);
+    Ok(entries)
+}
+
+// Order by sender time, falling back to arrival for messages without one.
+fn order_by_sent_time(messages: &mut [QueuedMessage]) {
+    messages.sort_by_key(|m| (m.sent_at_millis.unwrap_or(m.received_at_millis), m.seq));
+}
+
+// Put messages with a known generation in generation order, within the slots
+// they already occupy. Messages without one keep their arrival position.
+fn order_by_generation(
+    messages: Vec<(Option<u64>, QueuedMessage)>,
+) -> Vec<(Option<u64>, QueuedMessage)> {
+    let slots: Vec<usize> = (0..messages.len())
+        .filter(|&i| messages[i].0.is_some())
+        .collect();
+    let mut sources = slots.clone();
+    sources.sort_by_key(|&i| (messages[i].0, messages[i].1.seq));
+    let mut order: Vec<usize> = (0..messages.len()).collect();
+    for (slot, source) in slots.iter().zip(sources) {
+        order[*slot] = source;
+    }
+    let mut messages: Vec<Option<_>> = messages.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .map(|i| messages[i].take().expect("each message is placed once"))
+        .collect()
+}
+
+fn remove_entry(message: &QueuedMessage) {
+    if let Err(e) = fs::remove_file(&message.path) {
+        // Harmless: the next drain sees it as a duplicate.
+        warn!("Failed to remove {:?} from push queue: {}", message.path, e);
+    }
+}
+
+/// Store a push message for `camera_name` until the next `drain_push_messages`.
+/// `sent_at_millis` is the push service's send time (FCM `sentTime`), used to
+/// order the drain. Returns false if the same message is already queued.
+#[flutter_rust_bridge::frb]
+pub fn enqueue_push_message(
+    camera_name: String,
+    client_tag: String,
+    data: Vec<u8>,
+    sent_at_millis: Option<i64>,
+    context: Option<CallContext>,
+) -> Result<bool, String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context);
+    let _span = info_span!("enqueue_push_message", camera = %camera_name).entered();
+    let _metrics = metrics::track("enqueue_push_message", &camera_name);
+    if client_tag.len() > u16::MAX as usize {
+        return Err("client tag too long".to_string());
+    }
+
+    let enqueue = || {
+        let dir = queue_dir(&camera_name)?;
+        let _queue_lock = lock_queue(&dir)?;
+        let entries = list_entries(&dir)?;
+        let hash = content_hash(&data);
+        if entries.iter().any(|(_, queued, _)| *queued == hash) {
+            debug!("Push message {} for {} already queued", hash, camera_name);
+            return Ok(false);
+        }
+        let seq = entries.last().map_or(0, |(seq, _, _)| seq + 1);
+        let message = QueuedMessage {
+            path: dir.join(format!("{:016x}-{}.{}", seq, hash, MESSAGE_EXT)),
+            seq,
+            content_hash: hash,
+            received_at_millis: now_millis(),
+            sent_at_millis,
+            attempts: 0,
+            client_tag,
+            data,
+        };
+        message.write().map_err(|e| e.to_string())?;
+        Ok(true)
+    };
+    enqueue().inspect_err(|_| metrics::record_error(metrics::ERROR_FAILED))
+}
+
+/// Decrypt everything queued for `camera_name`, oldest generation first, and
+/// remove what was handled. Messages that fail to decrypt stay queued for the
+/// next drain, up to `MAX_DECRYPT_ATTEMPTS` tries.
+#[flutter_rust_bridge::frb]
+pub fn drain_push_messages(
+    camera_name: String,
+    context: Option<CallContext>,
+) -> Result<PushDrainReport, String> {
+    let (camera_name, context) = resolve_call(&camera_name, context);
+    let _context_guard = logger::enter_call_context(context.clone());
+    let _span = info_span!("drain_push_messages", camera = %camera_name).entered();
+    let _metrics = metrics::track("drain_push_messages", &camera_name);
+    let mut report = PushDrainReport::default();
+
+    let dir = queue_dir(&camera_name)?;
+    let pending = {
+        let _queue_lock = lock_queue(&dir)?;
+        read_queue(&dir)?.len()
+    };
+    if pending == 0 {
+        return Ok(report);
+    }
+
+    let client_mutex = get_or_create_channel_mutex(&camera_name, CHANNEL_FIXED);
+    let op = "drain_push_messages".to_string();
+    let mut attempt = 1;
+    let mut client_guard = loop {
+        match lock_client_with_owner(
+            &client_mutex,
+            &camera_name,
+            CHANNEL_FIXED,
+            &op,
+            context.trace_id.as_deref(),
+        ) {
+            Some(guard) => break guard,
+            None if attempt < LOCK_ATTEMPTS => {
+                attempt += 1;
+                thread::sleep(LOCK_RETRY_DELAY);
+            }
+            None => {
+                report.busy = true;
+                report.remaining = pending as u32;
+                return Ok(report);
+            }
+        }
+    };
+
+    // Holding the client lock keeps other drains out from here on; the queue
+    // lock is only taken again to update entries.
+    let mut queued = {
+        let _queue_lock = lock_queue(&dir)?;
+        read_queue(&dir)?
+    };
+    order_by_sent_time(&mut queued);
+    let queued: Vec<_> = queued
+        .into_iter()
+        .map(|message| (client_guard.message_generation(&message.data), message))
+        .collect();
+    let total = queued.len();
+    let mut handled = Vec::new();
+    let mut retry = Vec::new();
+    for (index, (generation, mut message)) in order_by_generation(queued).into_iter().enumerate() {
+        let data = message.data.clone();
+        match decrypt_with_client(&mut **client_guard, &camera_name, &message.client_tag, data) {
+            Ok(payload) => {
+                report.messages.push(DrainedPushMessage {
+                    content_hash: message.content_hash.clone(),
+                    received_at_millis: message.received_at_millis,
+                    sent_at_millis: message.sent_at_millis,
+                    generation,
+                    payload,
+                });
+                handled.push(message);
+            }
+            Err(NotificationError::DuplicateMessage) => {
+                report.duplicates += 1;
+                handled.push(message);
+            }
+            Err(NotificationError::Failed(e)) => {
+                message.attempts += 1;
+                if message.attempts >= MAX_DECRYPT_ATTEMPTS {
+                    warn!(
+                        "Dropping push message {} for {} after {} attempts: {}",
+                        message.content_hash, camera_name, message.attempts, e
+                    );
+                    report.dropped += 1;
+                    handled.push(message);
+                } else {
+                    report.remaining += 1;
+                    retry.push(message);
+                }
+            }
+            // The client is unusable; leave this and everything after it queued.
+            Err(e) => {
+                metrics::record_error(e.metric_kind());
+                report.remaining += (total - index) as u32;
+                break;
+            }
+        }
+    }
+    match lock_queue(&dir) {
+        Ok(_queue_lock) => {
+            handled.iter().for_each(remove_entry);
+            for message in &retry {
+                if let Err(e) = message.write() {
+                    warn!("Failed to update push queue entry: {}", e);
+                }
+            }
+        }
+        // Left as they are, handled messages come back as duplicates next drain.
+        Err(e) => warn!("Failed to update push queue for {}: {}", camera_name, e),
+    }
+
+    info!(
+        "Drained push queue for {}: {} decrypted, {} duplicates, {} dropped, {} remaining",
+        camera_name,
+        report.messages.len(),
+        report.duplicates,
+        report.dropped,
+        report.remaining
+    );
+    Ok(report)
+}
@@ -0,0 +1,174 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Ids for waits that Dart may cancel from another call.
+//!
+//! Dart reserves an id, starts the wait with it, and may cancel it at any
+//! point, even before the wait has started, in which case the wait gives up at
+//! once. A wait removes its id when it ends or is cancelled. Ids no wait took
+//! expire, so an abandoned reservation is not kept forever.
+
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How long a reserved id stays usable if no wait takes it.
+const RESERVATION_TTL: Duration = Duration::from_secs(60);
+
+struct Entry {
+    cancel: Arc<AtomicBool>,
+    reserved_at: Instant,
+    waiting: bool,
+}
+
+pub(crate) struct Waiters {
+    /// What is waited for, e.g. "lock". Ids and errors are named after it.
+    kind: &'static str,
+    ttl: Duration,
+    next_id: AtomicU64,
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+/// A running wait. Its id is released when this is dropped.
+pub(crate) struct Waiting<'a> {
+    waiters: &'a Waiters,
+    id: String,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Waiting<'_> {
+    /// Set once the wait is cancelled.
+    pub(crate) fn cancel_flag(&self) -> &AtomicBool {
+        &self.cancel
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.waiters.entries.lock().remove(&self.id);
+    }
+}
+
+impl Waiters {
+    pub(crate) fn new(kind: &'static str) -> Self {
+        Self::with_ttl(kind, RESERVATION_TTL)
+    }
+
+    fn with_ttl(kind: &'static str, ttl: Duration) -> Self {
+        Waiters {
+            kind,
+            ttl,
+            next_id: AtomicU64::new(1),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Forget reservations no wait took in time, cancelled ones included.
+    fn expire(&self, entries: &mut HashMap<String, Entry>) {
+        entries.retain(|_, entry| entry.waiting || entry.reserved_at.elapsed() < self.ttl);
+    }
+
+    pub(crate) fn reserve(&self) -> String {
+        let id = format!(
+            "{}-wait-{}",
+            self.kind,
+            self.next_id.fetch_add(1, Ordering::SeqCst)
+        );
+        let mut entries = self.entries.lock();
+        self.expire(&mut entries);
+        entries.insert(
+            id.clone(),
+            Entry {
+                cancel: Arc::new(AtomicBool::new(false)),
+                reserved_at: Instant::now(),
+                waiting: false,
+            },
+        );
+        id
+    }
+
+    /// Start the wait reserved as `id`. Each id serves one wait.
+    pub(crate) fn begin(&self, id: &str) -> Result<Waiting<'_>, String> {
+        let mut entries = self.entries.lock();
+        self.expire(&mut entries);
+        match entries.get_mut(id) {
+            Some(entry) if !entry.waiting => {
+                entry.waiting = true;
+                Ok(Waiting {
+                    waiters: self,
+                    id: id.to_string(),
+                    cancel: entry.cancel.clone(),
+                })
+            }
+            _ => Err(format!("unknown {} waiter {}", self.kind, id)),
+        }
+    }
+
+    /// Cancel the wait for `id`, running or not yet started. Returns false if
+    /// the id is unknown, has expired or its wait has already ended.
+    pub(crate) fn cancel(&self, id: &str) -> bool {
+        let mut entries = self.entries.lock();
+        self.expire(&mut entries);
+        let Some(entry) = entries.get(id) else {
+            return false;
+        };
+        entry.cancel.store(true, Ordering::SeqCst);
+        // A running wait holds its own flag; one not started yet finds it
+        // through the id until the reservation expires.
+        if entry.waiting {
+            entries.remove(id);
+        }
+        true
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_released_when_the_wait_ends_or_is_cancelled() {
+        let waiters = Waiters::new("test");
+        let id = waiters.reserve();
+        let waiting = waiters.begin(&id).unwrap();
+        assert!(waiters.begin(&id).is_err());
+        drop(waiting);
+        assert_eq!(waiters.len(), 0);
+        assert!(!waiters.cancel(&id));
+
+        let id = waiters.reserve();
+        let waiting = waiters.begin(&id).unwrap();
+        assert!(waiters.cancel(&id));
+        assert!(waiting.is_cancelled());
+        assert_eq!(waiters.len(), 0);
+    }
+
+    #[test]
+    fn a_wait_cancelled_before_it_starts_gives_up() {
+        let waiters = Waiters::new("test");
+        let id = waiters.reserve();
+        assert!(waiters.cancel(&id));
+        assert!(waiters.begin(&id).unwrap().is_cancelled());
+        assert_eq!(waiters.len(), 0);
+    }
+
+    #[test]
+    fn reservations_no_wait_takes_expire() {
+        let waiters = Waiters::with_ttl("test", Duration::ZERO);
+        let unused = waiters.reserve();
+        let cancelled = waiters.reserve();
+        assert!(!waiters.cancel(&cancelled));
+        assert!(waiters.begin(&unused).is_err());
+        assert_eq!(waiters.len(), 0);
+    }
+}
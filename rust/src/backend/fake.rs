@@ -0,0 +1,430 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! In-memory stand-in for the native MLS client, for exercising the bridge
+//! layer (locking, lazy init, trace handling, error mapping) without real state.
+//!
+//! Until the fake is paired, operations echo their input. Once `add_camera` has
+//! paired it with a `SimulatedCamera`, it opens and seals payloads with the
+//! session key using the AEAD below, and reads/writes files under the camera's
+//! `file_dir` the way the native client does.
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use parking_lot::{Mutex, MutexGuard};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use super::{set_backend_factory, CameraClientBackend, ClientError};
+
+/// Behaviour and call log shared by every client a `FakeCamera` hands out.
+#[derive(Default)]
+pub struct FakeCameraState {
+    /// Make `initialize` fail with this message.
+    pub initialize_error: Option<String>,
+    /// Make `deregister` panic, to exercise `catch_unwind` in the bridge.
+    pub panic_on_deregister: bool,
+    /// Sleep this long inside every call, to hold the client lock.
+    pub call_delay: Duration,
+    /// Make the named operation (e.g. "decrypt_video") fail with the given message.
+    pub failures: HashMap<String, String>,
+    /// Returned by `add_camera` and in healthy heartbeat responses.
+    pub firmware_version: String,
+    /// Every operation performed, in order.
+    pub calls: Vec<String>,
+    pub initialize_count: usize,
+    pub deregistered: bool,
+    /// Epoch of the group state "on disk". Clients load it when initialized, so
+    /// raising it is how a test plays another process advancing the group.
+    pub group_epoch: u64,
+    /// Epochs that encrypted files were sent at, by file name. Decrypting one of
+    /// these with a client at another epoch fails with `WrongEpoch`.
+    pub file_epochs: HashMap<String, u64>,
+    /// Set once paired with a simulated camera.
+    pub session_key: Option<Vec<u8>>,
+    /// Counters of the notifications opened so far. Opening one again fails the
+    /// way MLS does when a message secret is reused.
+    pub opened_notifications: HashSet<u64>,
+    /// Report no message generation, as the native client can't read one.
+    pub hide_generation: bool,
+}
+
+/// Handle to a fake camera. Clones share state.
+#[derive(Clone, Default)]
+pub struct FakeCamera {
+    state: Arc<Mutex<FakeCameraState>>,
+}
+
+impl FakeCamera {
+    pub fn new() -> Self {
+        let camera = FakeCamera::default();
+        camera.state().firmware_version = "fake-1.0.0".to_string();
+        camera
+    }
+
+    /// Route every newly created client through this fake.
+    pub fn install(&self) {
+        let camera = self.clone();
+        set_backend_factory(move || Box::new(FakeBackend::new(camera.clone())));
+    }
+
+    pub fn state(&self) -> MutexGuard<'_, FakeCameraState> {
+        self.state.lock()
+    }
+
+    pub fn fail(&self, op: &str, error: &str) {
+        self.state()
+            .failures
+            .insert(op.to_string(), error.to_string());
+    }
+
+    pub fn calls(&self) -> Vec<String> {
+        self.state().calls.clone()
+    }
+}
+
+// Payloads are sealed with ChaCha20-Poly1305 under a key hashed from the
+// session key, with the counter as nonce and associated data. This stands in
+// for the MLS session: it is real authenticated encryption, so wrong keys,
+// truncation and tampering fail the way they would, but it is not the secluso
+// wire format.
+fn cipher(key: &[u8]) -> ChaCha20Poly1305 {
+    ChaCha20Poly1305::new(&Sha256::digest(key))
+}
+
+fn nonce(counter: u64) -> Nonce {
+    let mut nonce = Nonce::default();
+    nonce[4..].copy_from_slice(&counter.to_be_bytes());
+    nonce
+}
+
+/// Seal `plaintext` under `key`, binding it to `counter` (epoch, chunk number or timestamp).
+pub fn seal(key: &[u8], counter: u64, plaintext: &[u8]) -> Vec<u8> {
+    let header = counter.to_be_bytes();
+    let sealed = cipher(key)
+        .encrypt(
+            &nonce(counter),
+            Payload {
+                msg: plaintext,
+                aad: &header,
+            },
+        )
+        .expect("ChaCha20-Poly1305 sealing does not fail for in-memory payloads");
+    let mut out = header.to_vec();
+    out.extend(sealed);
+    out
+}
+
+/// Open data produced by [`seal`], returning the counter and plaintext.
+pub fn open(key: &[u8], sealed: &[u8]) -> Result<(u64, Vec<u8>), String> {
+    if sealed.len() < 8 + 16 {
+        return Err("sealed payload too short".to_string());
+    }
+    let (header, body) = sealed.split_at(8);
+    let counter = u64::from_be_bytes(header.try_into().unwrap());
+    let plaintext = cipher(key)
+        .decrypt(
+            &nonce(counter),
+            Payload {
+                msg: body,
+                aad: header,
+            },
+        )
+        .map_err(|_| "sealed payload failed authentication".to_string())?;
+    Ok((counter, plaintext))
+}
+
+pub(crate) fn write_frame(stream: &mut TcpStream, data: &[u8]) -> io::Result<()> {
+    stream.write_all(&(data.len() as u32).to_be_bytes())?;
+    stream.write_all(data)
+}
+
+pub(crate) fn read_frame(stream: &mut TcpStream) -> io::Result<Vec<u8>> {
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > 1 << 20 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "frame too large",
+        ));
+    }
+    let mut data = vec![0u8; len];
+    stream.read_exact(&mut data)?;
+    Ok(data)
+}
+
+pub struct FakeBackend {
+    camera: FakeCamera,
+    initialized: bool,
+    file_dir: String,
+    loaded_epoch: u64,
+}
+
+impl FakeBackend {
+    pub fn new(camera: FakeCamera) -> Self {
+        FakeBackend {
+            camera,
+            initialized: false,
+            file_dir: String::new(),
+            loaded_epoch: 0,
+        }
+    }
+
+    fn session_key(&self) -> Option<Vec<u8>> {
+        self.camera.state().session_key.clone()
+    }
+
+    // Pairing handshake with a SimulatedCamera listening on `addr`.
+    fn pair(
+        &self,
+        addr: SocketAddr,
+        secret: &[u8],
+        pairing_token: &str,
+        credentials_full: &str,
+    ) -> io::Result<String> {
+        let mut stream = TcpStream::connect_timeout(&addr, Duration::from_secs(2))?;
+        stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+        write_frame(&mut stream, secret)?;
+        write_frame(&mut stream, pairing_token.as_bytes())?;
+        write_frame(&mut stream, credentials_full.as_bytes())?;
+
+        let status = String::from_utf8_lossy(&read_frame(&mut stream)?).into_owned();
+        if status != "ok" {
+            return Err(io::Error::new(io::ErrorKind::PermissionDenied, status));
+        }
+        let key = read_frame(&mut stream)?;
+        let firmware_version = String::from_utf8_lossy(&read_frame(&mut stream)?).into_owned();
+
+        let mut state = self.camera.state();
+        state.session_key = Some(key);
+        state.firmware_version = firmware_version.clone();
+        Ok(firmware_version)
+    }
+
+    // Open `encrypted/<enc_filename>` and write the plaintext to `videos/<prefix>_<epoch>.<ext>`.
+    fn decrypt_file(
+        &self,
+        key: &[u8],
+        enc_filename: &str,
+        prefix: &str,
+        ext: &str,
+    ) -> Result<String, String> {
+        let dir = Path::new(&self.file_dir);
+        let sealed =
+            fs::read(dir.join("encrypted").join(enc_filename)).map_err(|e| e.to_string())?;
+        let (epoch, plaintext) = open(key, &sealed)?;
+        let filename = format!("{}_{}.{}", prefix, epoch, ext);
+        fs::create_dir_all(dir.join("videos")).map_err(|e| e.to_string())?;
+        fs::write(dir.join("videos").join(&filename), plaintext).map_err(|e| e.to_string())?;
+        Ok(filename)
+    }
+
+    fn check_epoch(&self, enc_filename: &str) -> Result<(), ClientError> {
+        match self.camera.state().file_epochs.get(enc_filename) {
+            Some(&epoch) if epoch != self.loaded_epoch => Err(ClientError::WrongEpoch {
+                group_epoch: Some(self.loaded_epoch),
+                message_epoch: Some(epoch),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    // Record the call, apply the configured delay and return the configured failure.
+    fn call(&self, op: &str) -> Result<(), String> {
+        let (delay, failure) = {
+            let mut state = self.camera.state();
+            state.calls.push(op.to_string());
+            (state.call_delay, state.failures.get(op).cloned())
+        };
+        if !delay.is_zero() {
+            thread::sleep(delay);
+        }
+        if !self.initialized {
+            return Err(format!("{}: client not initialized", op));
+        }
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl CameraClientBackend for FakeBackend {
+    fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn initialize(&mut self, file_dir: String, _first_time: bool) -> Result<(), String> {
+        let mut state = self.camera.state();
+        state.calls.push("initialize".to_string());
+        if let Some(e) = &state.initialize_error {
+            return Err(e.clone());
+        }
+        state.initialize_count += 1;
+        self.initialized = true;
+        self.loaded_epoch = state.group_epoch;
+        self.file_dir = file_dir;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.initialized = false;
+    }
+
+    fn deregister(&mut self) {
+        let panic_on_deregister = {
+            let mut state = self.camera.state();
+            state.calls.push("deregister".to_string());
+            state.panic_on_deregister
+        };
+        if panic_on_deregister {
+            panic!("fake deregister panic");
+        }
+        self.camera.state().deregistered = true;
+    }
+
+    fn add_camera(
+        &mut self,
+        _camera_name: String,
+        addr: SocketAddr,
+        secret: Vec<u8>,
+        _standalone: bool,
+        _ssid: String,
+        _password: String,
+        pairing_token: String,
+        credentials_full: String,
+    ) -> String {
+        if let Err(e) = self.call("add_camera") {
+            return format!("Error: {}", e);
+        }
+        match self.pair(addr, &secret, &pairing_token, &credentials_full) {
+            Ok(firmware_version) => firmware_version,
+            Err(e) => format!("Error: pairing failed: {}", e),
+        }
+    }
+
+    fn group_epoch(&self) -> Option<u64> {
+        self.initialized.then_some(self.loaded_epoch)
+    }
+
+    fn decrypt_video(&mut self, enc_filename: String) -> Result<String, ClientError> {
+        self.call("decrypt_video")?;
+        self.check_epoch(&enc_filename)?;
+        match self.session_key() {
+            Some(key) => Ok(self.decrypt_file(&key, &enc_filename, "video", "mp4")?),
+            None => Ok(format!("video_{}.mp4", enc_filename)),
+        }
+    }
+
+    fn decrypt_thumbnail(
+        &mut self,
+        enc_filename: String,
+        _pending_meta_directory: String,
+    ) -> Result<String, ClientError> {
+        self.call("decrypt_thumbnail")?;
+        self.check_epoch(&enc_filename)?;
+        match self.session_key() {
+            Some(key) => Ok(self.decrypt_file(&key, &enc_filename, "thumbnail", "png")?),
+            None => Ok(format!("thumbnail_{}.png", enc_filename)),
+        }
+    }
+
+    fn encrypt_settings_message(&mut self, data: Vec<u8>) -> Result<Vec<u8>, String> {
+        self.call("encrypt_settings_message")?;
+        match self.session_key() {
+            Some(key) => Ok(seal(&key, 0, &data)),
+            None => Ok(data),
+        }
+    }
+
+    fn decrypt_message(&mut self, _client_tag: &str, data: Vec<u8>) -> Result<String, ClientError> {
+        self.call("decrypt_message")?;
+        let data = match self.session_key() {
+            Some(key) => {
+                let (counter, plaintext) = open(&key, &data)?;
+                if !self.camera.state().opened_notifications.insert(counter) {
+                    return Err(ClientError::SecretReuse);
+                }
+                plaintext
+            }
+            None => data,
+        };
+        Ok(String::from_utf8_lossy(&data).into_owned())
+    }
+
+    fn message_generation(&self, data: &[u8]) -> Option<u64> {
+        if self.camera.state().hide_generation {
+            return None;
+        }
+        let key = self.session_key()?;
+        open(&key, data).ok().map(|(counter, _)| counter)
+    }
+
+    fn get_group_name(&mut self, client_tag: &str) -> Result<String, String> {
+        self.call("get_group_name")?;
+        Ok(format!("{}_group", client_tag))
+    }
+
+    fn livestream_update(&mut self, _msg: Vec<u8>) -> Result<(), ClientError> {
+        Ok(self.call("livestream_update")?)
+    }
+
+    fn livestream_decrypt(
+        &mut self,
+        data: Vec<u8>,
+        expected_chunk_number: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.call("livestream_decrypt")?;
+        let Some(key) = self.session_key() else {
+            return Ok(data);
+        };
+        let (chunk_number, plaintext) = open(&key, &data)?;
+        if chunk_number != expected_chunk_number {
+            return Err(format!(
+                "chunk number {} does not match expected {}",
+                chunk_number, expected_chunk_number
+            ));
+        }
+        Ok(plaintext)
+    }
+
+    fn generate_heartbeat_request_config_command(
+        &mut self,
+        timestamp: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.call("generate_heartbeat_request_config_command")?;
+        match self.session_key() {
+            Some(key) => Ok(seal(&key, timestamp, b"heartbeat")),
+            None => Ok(timestamp.to_be_bytes().to_vec()),
+        }
+    }
+
+    fn process_heartbeat_config_response(
+        &mut self,
+        config_response: Vec<u8>,
+        expected_timestamp: u64,
+    ) -> Result<String, String> {
+        self.call("process_heartbeat_config_response")?;
+        let Some(key) = self.session_key() else {
+            if config_response != expected_timestamp.to_be_bytes() {
+                return Err("heartbeat timestamp mismatch".to_string());
+            }
+            return Ok(format!("healthy_{}", self.camera.state().firmware_version));
+        };
+        let (timestamp, firmware_version) = open(&key, &config_response)?;
+        if timestamp != expected_timestamp {
+            return Err("heartbeat timestamp mismatch".to_string());
+        }
+        Ok(format!(
+            "healthy_{}",
+            String::from_utf8_lossy(&firmware_version)
+        ))
+    }
+}
@@ -0,0 +1,51 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! The plaintext header of an MLS message (RFC 9420, section 6).
+//!
+//! `PublicMessage` and `PrivateMessage` both start with the group id and the
+//! epoch they were sent in, unencrypted, so the epoch of a file can be read
+//! before (or without) handing it to the native client.
+
+const MLS_10: u16 = 1;
+const WIRE_FORMAT_PUBLIC_MESSAGE: u16 = 1;
+const WIRE_FORMAT_PRIVATE_MESSAGE: u16 = 2;
+
+/// Bytes enough to hold the header of any message with a group id of up to
+/// 1 KiB.
+pub(crate) const HEADER_READ_LEN: usize = 1024 + 16;
+
+fn u16_at(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(data.get(at..at + 2)?.try_into().ok()?))
+}
+
+// A variable-length vector length (RFC 9420, section 2.1.2): the top two bits
+// of the first byte give its size. Returns the length and the bytes it took.
+fn varint(data: &[u8]) -> Option<(usize, usize)> {
+    let first = *data.first()?;
+    let size = 1usize << (first >> 6);
+    if size > 4 {
+        return None;
+    }
+    let mut value = (first & 0x3f) as usize;
+    for byte in data.get(1..size)? {
+        value = (value << 8) | *byte as usize;
+    }
+    Some((value, size))
+}
+
+/// The epoch of the `PublicMessage` or `PrivateMessage` at the start of
+/// `message`, or `None` if it doesn't start with one.
+pub fn message_epoch(message: &[u8]) -> Option<u64> {
+    if u16_at(message, 0)? != MLS_10 {
+        return None;
+    }
+    let wire_format = u16_at(message, 2)?;
+    if wire_format != WIRE_FORMAT_PUBLIC_MESSAGE && wire_format != WIRE_FORMAT_PRIVATE_MESSAGE {
+        return None;
+    }
+    let (group_id_len, len_size) = varint(message.get(4..)?)?;
+    let at = 4 + len_size + group_id_len;
+    Some(u64::from_be_bytes(
+        message.get(at..at + 8)?.try_into().ok()?,
+    ))
+}
@@ -0,0 +1,141 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! The MLS client behind each camera channel, abstracted so the bridge layer in
+//! `api` can run against something other than `secluso_app_native`.
+
+#[cfg(any(test, feature = "test-support"))]
+pub mod fake;
+pub mod mls;
+mod native;
+#[cfg(any(test, feature = "test-support"))]
+pub mod simulator;
+
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use std::fmt;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+pub use native::NativeBackend;
+
+/// Why a client failed to open a file or message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientError {
+    /// The data was sent in a different MLS epoch than the group is at. The
+    /// epochs are given when the client knows them.
+    WrongEpoch {
+        group_epoch: Option<u64>,
+        message_epoch: Option<u64>,
+    },
+    /// The message secret was already used, i.e. this message was opened before.
+    SecretReuse,
+    Other(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::WrongEpoch {
+                group_epoch,
+                message_epoch,
+            } => write!(
+                f,
+                "WrongEpoch (message epoch {:?}, group epoch {:?})",
+                message_epoch, group_epoch
+            ),
+            ClientError::SecretReuse => write!(f, "SecretReuseError"),
+            ClientError::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<String> for ClientError {
+    fn from(e: String) -> Self {
+        ClientError::Other(e)
+    }
+}
+
+/// One camera's client state plus the operations the bridge performs on it.
+/// A fresh backend starts uninitialized; `initialize` loads (or creates) state on disk.
+pub trait CameraClientBackend: Send {
+    fn is_initialized(&self) -> bool;
+    fn initialize(&mut self, file_dir: String, first_time: bool) -> Result<(), String>;
+    /// Drop in-memory state without touching what's on disk.
+    fn reset(&mut self);
+    fn deregister(&mut self);
+
+    /// Check that the camera address Dart gave, before it is resolved, is one
+    /// `add_camera` can pair with. Any address is, unless the client says otherwise.
+    fn check_pairing_address(&self, _address: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Pair with the camera listening at `addr`, already resolved by the caller.
+    #[allow(clippy::too_many_arguments)]
+    fn add_camera(
+        &mut self,
+        camera_name: String,
+        addr: SocketAddr,
+        secret: Vec<u8>,
+        standalone: bool,
+        ssid: String,
+        password: String,
+        pairing_token: String,
+        credentials_full: String,
+    ) -> String;
+
+    /// Epoch of the MLS group the client has loaded, if it can tell. A client
+    /// that only knows a lower bound reports that.
+    fn group_epoch(&self) -> Option<u64> {
+        None
+    }
+    fn decrypt_video(&mut self, enc_filename: String) -> Result<String, ClientError>;
+    fn decrypt_thumbnail(
+        &mut self,
+        enc_filename: String,
+        pending_meta_directory: String,
+    ) -> Result<String, ClientError>;
+    fn encrypt_settings_message(&mut self, data: Vec<u8>) -> Result<Vec<u8>, String>;
+    fn decrypt_message(&mut self, client_tag: &str, data: Vec<u8>) -> Result<String, ClientError>;
+    /// Position of a push message in the sender's ratchet, if it can be read
+    /// without decrypting. MLS keeps the generation inside the encrypted sender
+    /// data, so the native client can't.
+    fn message_generation(&self, _data: &[u8]) -> Option<u64> {
+        None
+    }
+    fn get_group_name(&mut self, client_tag: &str) -> Result<String, String>;
+    fn livestream_update(&mut self, msg: Vec<u8>) -> Result<(), ClientError>;
+    fn livestream_decrypt(
+        &mut self,
+        data: Vec<u8>,
+        expected_chunk_number: u64,
+    ) -> Result<Vec<u8>, String>;
+    fn generate_heartbeat_request_config_command(
+        &mut self,
+        timestamp: u64,
+    ) -> Result<Vec<u8>, String>;
+    fn process_heartbeat_config_response(
+        &mut self,
+        config_response: Vec<u8>,
+        expected_timestamp: u64,
+    ) -> Result<String, String>;
+}
+
+type BackendFactory = Arc<dyn Fn() -> Box<dyn CameraClientBackend> + Send + Sync>;
+
+static BACKEND_FACTORY: Lazy<RwLock<BackendFactory>> =
+    Lazy::new(|| RwLock::new(Arc::new(|| Box::new(NativeBackend::default()))));
+
+pub(crate) fn new_backend() -> Box<dyn CameraClientBackend> {
+    let factory = BACKEND_FACTORY.read().clone();
+    factory()
+}
+
+/// Swap the backend used for channels created from now on. Existing channels keep theirs.
+#[cfg(any(test, feature = "test-support"))]
+pub fn set_backend_factory<F>(factory: F)
+where
+    F: Fn() -> Box<dyn CameraClientBackend> + Send + Sync + 'static,
+{
+    *BACKEND_FACTORY.write() = Arc::new(factory);
+}
@@ -0,0 +1,266 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+use secluso_app_native::{self, Clients};
+use std::fs::File;
+use std::io::{self, Read};
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::path::PathBuf;
+use tracing::instrument;
+
+use super::{mls, CameraClientBackend, ClientError};
+use crate::api::hotspot::PAIRING_PORT;
+
+// secluso_app_native hands back openmls errors flattened into io::Error with
+// their Debug text, so the variant name is all that identifies them. Neither
+// carries the epochs; `NativeBackend::opened` fills them in.
+fn client_error(e: io::Error) -> ClientError {
+    let text = e.to_string();
+    if text.contains("WrongEpoch") {
+        ClientError::WrongEpoch {
+            group_epoch: None,
+            message_epoch: None,
+        }
+    } else if text.contains("SecretReuseError") {
+        ClientError::SecretReuse
+    } else {
+        ClientError::Other(text)
+    }
+}
+
+/// The real MLS client from `secluso_app_native`.
+#[derive(Default)]
+pub struct NativeBackend {
+    clients: Option<Box<Clients>>,
+    /// The camera directory; encrypted files are under `encrypted/`.
+    file_dir: Option<PathBuf>,
+    /// The highest epoch of a file the client opened. secluso_app_native
+    /// doesn't report the group epoch, but the group is at least here, and
+    /// stays so across a reload from disk.
+    opened_epoch: Option<u64>,
+}
+
+impl NativeBackend {
+    /// A backend that reads encrypted files from `file_dir` without loading a
+    /// client, for driving [`NativeBackend::open_for_tests`].
+    #[cfg(feature = "test-support")]
+    pub fn with_file_dir_for_tests(file_dir: impl Into<PathBuf>) -> Self {
+        NativeBackend {
+            file_dir: Some(file_dir.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Run `result`, standing in for what secluso_app_native returned for
+    /// `enc_filename`, through the same path a real decrypt takes.
+    #[cfg(feature = "test-support")]
+    pub fn open_for_tests(
+        &mut self,
+        enc_filename: &str,
+        result: io::Result<String>,
+    ) -> Result<String, ClientError> {
+        let message_epoch = self.file_epoch(enc_filename);
+        self.opened(message_epoch, result)
+    }
+
+    // The epoch in the MLS header of an encrypted file, if it has one.
+    fn file_epoch(&self, enc_filename: &str) -> Option<u64> {
+        let path = self.file_dir.as_ref()?.join("encrypted").join(enc_filename);
+        let mut header = Vec::with_capacity(mls::HEADER_READ_LEN);
+        File::open(path)
+            .ok()?
+            .take(mls::HEADER_READ_LEN as u64)
+            .read_to_end(&mut header)
+            .ok()?;
+        mls::message_epoch(&header)
+    }
+
+    // Note the epoch of a file the client opened, or give its error the
+    // epochs known on this side.
+    fn opened<T>(
+        &mut self,
+        message_epoch: Option<u64>,
+        result: io::Result<T>,
+    ) -> Result<T, ClientError> {
+        match result {
+            Ok(value) => {
+                if let Some(epoch) = message_epoch {
+                    self.opened_epoch = self.opened_epoch.max(Some(epoch));
+                }
+                Ok(value)
+            }
+            Err(e) => match client_error(e) {
+                ClientError::WrongEpoch { .. } => Err(ClientError::WrongEpoch {
+                    group_epoch: self.opened_epoch,
+                    message_epoch,
+                }),
+                e => Err(e),
+            },
+        }
+    }
+}
+
+impl CameraClientBackend for NativeBackend {
+    fn is_initialized(&self) -> bool {
+        self.clients.is_some()
+    }
+
+    #[instrument(name = "native.initialize", level = "debug", skip_all)]
+    fn initialize(&mut self, file_dir: String, first_time: bool) -> Result<(), String> {
+        self.file_dir = Some(PathBuf::from(&file_dir));
+        secluso_app_native::initialize(&mut self.clients, file_dir, first_time)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    fn reset(&mut self) {
+        self.clients = None;
+    }
+
+    #[instrument(name = "native.deregister", level = "debug", skip_all)]
+    fn deregister(&mut self) {
+        self.opened_epoch = None;
+        secluso_app_native::deregister(&mut self.clients);
+    }
+
+    fn group_epoch(&self) -> Option<u64> {
+        self.opened_epoch
+    }
+
+    // secluso_app_native takes a bare IP and always dials the pairing port, and
+    // only forms an address from an IPv4 one.
+    fn check_pairing_address(&self, address: &str) -> Result<(), String> {
+        let address = address.trim();
+        let supported = address.parse::<Ipv4Addr>().is_ok()
+            || address
+                .parse::<SocketAddrV4>()
+                .is_ok_and(|addr| addr.port() == PAIRING_PORT);
+        if supported {
+            Ok(())
+        } else {
+            Err(format!(
+                "the native client only pairs with an IPv4 address on port {}, not {:?}",
+                PAIRING_PORT, address
+            ))
+        }
+    }
+
+    #[instrument(name = "native.add_camera", level = "debug", skip_all)]
+    fn add_camera(
+        &mut self,
+        camera_name: String,
+        addr: SocketAddr,
+        secret: Vec<u8>,
+        standalone: bool,
+        ssid: String,
+        password: String,
+        pairing_token: String,
+        credentials_full: String,
+    ) -> String {
+        // Already refused by `check_pairing_address`, unless the caller skipped it.
+        if addr.port() != PAIRING_PORT {
+            return format!(
+                "Error: the native client only pairs on port {}, not {}",
+                PAIRING_PORT,
+                addr.port()
+            );
+        }
+        secluso_app_native::add_camera(
+            &mut self.clients,
+            camera_name,
+            addr.ip().to_string(),
+            secret,
+            standalone,
+            ssid,
+            password,
+            pairing_token,
+            credentials_full,
+        )
+    }
+
+    #[instrument(name = "native.decrypt_video", level = "debug", skip_all)]
+    fn decrypt_video(&mut self, enc_filename: String) -> Result<String, ClientError> {
+        let message_epoch = self.file_epoch(&enc_filename);
+        let result = secluso_app_native::decrypt_video(&mut self.clients, enc_filename);
+        self.opened(message_epoch, result)
+    }
+
+    #[instrument(name = "native.decrypt_thumbnail", level = "debug", skip_all)]
+    fn decrypt_thumbnail(
+        &mut self,
+        enc_filename: String,
+        pending_meta_directory: String,
+    ) -> Result<String, ClientError> {
+        let message_epoch = self.file_epoch(&enc_filename);
+        let result = secluso_app_native::decrypt_thumbnail(
+            &mut self.clients,
+            enc_filename,
+            pending_meta_directory,
+        );
+        self.opened(message_epoch, result)
+    }
+
+    #[instrument(name = "native.encrypt_settings_message", level = "debug", skip_all)]
+    fn encrypt_settings_message(&mut self, data: Vec<u8>) -> Result<Vec<u8>, String> {
+        secluso_app_native::encrypt_settings_message(&mut self.clients, data)
+            .map_err(|e| e.to_string())
+    }
+
+    #[instrument(name = "native.decrypt_message", level = "debug", skip_all)]
+    fn decrypt_message(&mut self, client_tag: &str, data: Vec<u8>) -> Result<String, ClientError> {
+        secluso_app_native::decrypt_message(&mut self.clients, client_tag, data)
+            .map_err(client_error)
+    }
+
+    #[instrument(name = "native.get_group_name", level = "debug", skip_all)]
+    fn get_group_name(&mut self, client_tag: &str) -> Result<String, String> {
+        secluso_app_native::get_group_name(&mut self.clients, client_tag).map_err(|e| e.to_string())
+    }
+
+    #[instrument(name = "native.livestream_update", level = "debug", skip_all)]
+    fn livestream_update(&mut self, msg: Vec<u8>) -> Result<(), ClientError> {
+        secluso_app_native::livestream_update(&mut self.clients, msg)
+            .map(|_| ())
+            .map_err(client_error)
+    }
+
+    #[instrument(name = "native.livestream_decrypt", level = "debug", skip_all)]
+    fn livestream_decrypt(
+        &mut self,
+        data: Vec<u8>,
+        expected_chunk_number: u64,
+    ) -> Result<Vec<u8>, String> {
+        secluso_app_native::livestream_decrypt(&mut self.clients, data, expected_chunk_number)
+            .map_err(|e| e.to_string())
+    }
+
+    #[instrument(
+        name = "native.generate_heartbeat_request_config_command",
+        level = "debug",
+        skip_all
+    )]
+    fn generate_heartbeat_request_config_command(
+        &mut self,
+        timestamp: u64,
+    ) -> Result<Vec<u8>, String> {
+        secluso_app_native::generate_heartbeat_request_config_command(&mut self.clients, timestamp)
+            .map_err(|e| e.to_string())
+    }
+
+    #[instrument(
+        name = "native.process_heartbeat_config_response",
+        level = "debug",
+        skip_all
+    )]
+    fn process_heartbeat_config_response(
+        &mut self,
+        config_response: Vec<u8>,
+        expected_timestamp: u64,
+    ) -> Result<String, String> {
+        secluso_app_native::process_heartbeat_config_response(
+            &mut self.clients,
+            config_response,
+            expected_timestamp,
+        )
+        .map_err(|e| e.to_string())
+    }
+}
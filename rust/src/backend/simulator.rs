@@ -0,0 +1,236 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! A simulated camera on localhost, paired with the fake backend through the
+//! `flutter_add_camera` path, so the bridge API can be exercised end to end
+//! without hardware.
+//!
+//! The message flow follows a real camera: pairing over a direct TCP connection,
+//! then motion videos, thumbnails, livestream chunks and heartbeat responses that
+//! reach the app through the server. The pairing framing and payload sealing
+//! are the fake backend's own, not the secluso protocol: the camera side of
+//! that protocol lives in the camera hub, and `secluso_app_native` exposes only
+//! the app side, so there is nothing in this crate's dependencies to drive it.
+
+use log::debug;
+use parking_lot::Mutex;
+use std::fs;
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use super::fake::{open, read_frame, seal, write_frame};
+use crate::api::credentials::ServerCredentials;
+
+#[derive(Default)]
+struct SimulatedCameraState {
+    session_key: Option<Vec<u8>>,
+    pairing_token: Option<String>,
+    credentials_full: Option<String>,
+}
+
+pub struct SimulatedCamera {
+    addr: SocketAddr,
+    secret: Vec<u8>,
+    firmware_version: String,
+    state: Arc<Mutex<SimulatedCameraState>>,
+    stop: Arc<AtomicBool>,
+    listener_thread: Option<JoinHandle<()>>,
+}
+
+impl SimulatedCamera {
+    /// Start listening for pairing on an ephemeral localhost port.
+    pub fn start() -> io::Result<Self> {
+        Self::start_inner(None)
+    }
+
+    /// Like [`start`](Self::start), but hand out `session_key` when paired, so
+    /// payloads recorded in one process open in another (e.g. fuzz corpora).
+    pub fn start_with_session_key(session_key: Vec<u8>) -> io::Result<Self> {
+        Self::start_inner(Some(session_key))
+    }
+
+    fn start_inner(session_key: Option<Vec<u8>>) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+
+        let secret = rand::random::<[u8; 16]>().to_vec();
+        let firmware_version = "sim-0.1.0".to_string();
+        let state = Arc::new(Mutex::new(SimulatedCameraState::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let listener_thread = {
+            let secret = secret.clone();
+            let firmware_version = firmware_version.clone();
+            let state = state.clone();
+            let stop = stop.clone();
+            thread::spawn(move || {
+                while !stop.load(Ordering::SeqCst) {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            if let Err(e) = handle_pairing(
+                                stream,
+                                &secret,
+                                session_key.as_deref(),
+                                &firmware_version,
+                                &state,
+                            ) {
+                                debug!("Simulated camera pairing failed: {}", e);
+                            }
+                        }
+                        Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                            thread::sleep(Duration::from_millis(10));
+                        }
+                        Err(e) => {
+                            debug!("Simulated camera accept failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+            })
+        };
+
+        Ok(SimulatedCamera {
+            addr,
+            secret,
+            firmware_version,
+            state,
+            stop,
+            listener_thread: Some(listener_thread),
+        })
+    }
+
+    /// Address to pass as `ip` to `flutter_add_camera`.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The secret a real camera would show in its QR code.
+    pub fn secret(&self) -> Vec<u8> {
+        self.secret.clone()
+    }
+
+    pub fn firmware_version(&self) -> &str {
+        &self.firmware_version
+    }
+
+    pub fn is_paired(&self) -> bool {
+        self.state.lock().session_key.is_some()
+    }
+
+    /// Server credentials the app handed over while pairing.
+    pub fn credentials_full(&self) -> Option<String> {
+        self.state.lock().credentials_full.clone()
+    }
+
+    /// The handed-over credentials, parsed the way the camera parses them.
+    pub fn server_credentials(&self) -> Option<Result<ServerCredentials, String>> {
+        self.credentials_full()
+            .map(|encoded| ServerCredentials::decode(&encoded))
+    }
+
+    pub fn pairing_token(&self) -> Option<String> {
+        self.state.lock().pairing_token.clone()
+    }
+
+    fn session_key(&self) -> io::Result<Vec<u8>> {
+        self.state
+            .lock()
+            .session_key
+            .clone()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "camera not paired"))
+    }
+
+    /// Record a motion video for `epoch` and drop it where the app downloads to
+    /// (`<file_dir>/encrypted/encVideo<epoch>`). Returns the encrypted file name.
+    pub fn record_motion(&self, file_dir: &Path, epoch: u64, video: &[u8]) -> io::Result<String> {
+        self.write_encrypted(file_dir, format!("encVideo{}", epoch), epoch, video)
+    }
+
+    /// Thumbnail counterpart of [`record_motion`](Self::record_motion).
+    pub fn record_thumbnail(
+        &self,
+        file_dir: &Path,
+        epoch: u64,
+        thumbnail: &[u8],
+    ) -> io::Result<String> {
+        self.write_encrypted(file_dir, format!("encThumbnail{}", epoch), epoch, thumbnail)
+    }
+
+    fn write_encrypted(
+        &self,
+        file_dir: &Path,
+        filename: String,
+        epoch: u64,
+        data: &[u8],
+    ) -> io::Result<String> {
+        let key = self.session_key()?;
+        let dir = file_dir.join("encrypted");
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(&filename), seal(&key, epoch, data))?;
+        Ok(filename)
+    }
+
+    /// One encrypted livestream chunk, as it would arrive from the server.
+    pub fn livestream_chunk(&self, chunk_number: u64, data: &[u8]) -> io::Result<Vec<u8>> {
+        Ok(seal(&self.session_key()?, chunk_number, data))
+    }
+
+    /// An encrypted notification, as delivered by push for `decrypt_message`.
+    pub fn notification(&self, counter: u64, payload: &[u8]) -> io::Result<Vec<u8>> {
+        Ok(seal(&self.session_key()?, counter, payload))
+    }
+
+    /// Answer a heartbeat request produced by `generate_heartbeat_request_config_command`.
+    pub fn answer_heartbeat(&self, request: &[u8]) -> io::Result<Vec<u8>> {
+        let key = self.session_key()?;
+        let (timestamp, _) =
+            open(&key, request).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(seal(&key, timestamp, self.firmware_version.as_bytes()))
+    }
+}
+
+impl Drop for SimulatedCamera {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.listener_thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn handle_pairing(
+    mut stream: TcpStream,
+    secret: &[u8],
+    session_key: Option<&[u8]>,
+    firmware_version: &str,
+    state: &Mutex<SimulatedCameraState>,
+) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let offered_secret = read_frame(&mut stream)?;
+    let pairing_token = read_frame(&mut stream)?;
+    let credentials_full = read_frame(&mut stream)?;
+
+    if offered_secret != secret {
+        return write_frame(&mut stream, b"error: wrong secret");
+    }
+
+    let key = match session_key {
+        Some(key) => key.to_vec(),
+        None => rand::random::<[u8; 32]>().to_vec(),
+    };
+    {
+        let mut state = state.lock();
+        state.session_key = Some(key.clone());
+        state.pairing_token = Some(String::from_utf8_lossy(&pairing_token).into_owned());
+        state.credentials_full = Some(String::from_utf8_lossy(&credentials_full).into_owned());
+    }
+    write_frame(&mut stream, b"ok")?;
+    write_frame(&mut stream, &key)?;
+    write_frame(&mut stream, firmware_version.as_bytes())
+}
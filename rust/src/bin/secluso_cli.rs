@@ -0,0 +1,9 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Headless access to the bridge API; see `rust_lib_secluso_flutter::cli`.
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    rust_lib_secluso_flutter::cli::main()
+}
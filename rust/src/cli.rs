@@ -0,0 +1,305 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Headless access to the bridge API, for scripting and for debugging camera
+//! state directories outside the Flutter app.
+//!
+//! Bridge logs go to stderr at `--log-level`. `tail-logs COMMAND` instead
+//! prints the entries the Dart log viewer would show for the command, with
+//! their trace ids and span timings, once it finishes.
+
+use std::fs;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::LevelFilter;
+use simplelog::{ColorChoice, ConfigBuilder, TermLogger, TerminalMode};
+
+use crate::api;
+use crate::api::logger::{self, CallContext, LogEntry};
+
+const USAGE: &str = "\
+usage: secluso-cli --camera NAME --dir FILE_DIR [options] COMMAND [ARGS]
+
+commands:
+  init                              create (with --first-time) or load camera state
+  group-name TAG                    print the MLS group name for a client tag
+  decrypt-video ENC_FILE            decrypt <dir>/encrypted/ENC_FILE
+  decrypt-thumbnail ENC_FILE META_DIR
+  heartbeat-request TIMESTAMP       write a heartbeat config command
+  heartbeat-response FILE TIMESTAMP process a heartbeat config response
+  encrypt-settings FILE             encrypt a settings message
+  tail-logs COMMAND [ARGS]          run COMMAND, then print its log entries
+
+options:
+  --first-time        treat the state directory as new (init only)
+  --epoch N           assumed MLS epoch for decrypt-video/decrypt-thumbnail
+  --out FILE          write binary output to FILE instead of hex on stdout
+  --trace ID          trace id to pass to the bridge, as the app does
+  --log-level LEVEL   off, error, warn (default; info for tail-logs), info,
+                      debug or trace
+";
+
+#[derive(Default)]
+struct Args {
+    camera: Option<String>,
+    dir: Option<String>,
+    trace: Option<String>,
+    out: Option<String>,
+    epoch: u64,
+    first_time: bool,
+    log_level: Option<LevelFilter>,
+    tail_logs: bool,
+    command: Vec<String>,
+}
+
+fn parse_args(argv: Vec<String>) -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut iter = argv.into_iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |name: &str| iter.next().ok_or(format!("{} needs a value", name));
+        match arg.as_str() {
+            "--camera" => args.camera = Some(value("--camera")?),
+            "--dir" => args.dir = Some(value("--dir")?),
+            "--trace" => args.trace = Some(value("--trace")?),
+            "--out" => args.out = Some(value("--out")?),
+            "--epoch" => {
+                args.epoch = value("--epoch")?
+                    .parse()
+                    .map_err(|e| format!("--epoch: {}", e))?
+            }
+            "--log-level" => {
+                args.log_level = Some(
+                    value("--log-level")?
+                        .parse()
+                        .map_err(|_| "--log-level: unknown level".to_string())?,
+                )
+            }
+            "--first-time" => args.first_time = true,
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+            _ => args.command.push(arg),
+        }
+    }
+    if args.command.first().map(String::as_str) == Some("tail-logs") {
+        args.command.remove(0);
+        args.tail_logs = true;
+    }
+    if args.command.is_empty() {
+        return Err(String::new());
+    }
+    Ok(args)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn emit_bytes(bytes: &[u8], path: Option<&str>, out: &mut dyn Write) -> Result<(), String> {
+    if bytes.is_empty() {
+        return Err("operation returned no data".to_string());
+    }
+    match path {
+        Some(path) => fs::write(path, bytes).map_err(|e| format!("{}: {}", path, e)),
+        None => writeln!(out, "{}", hex(bytes)).map_err(|e| e.to_string()),
+    }
+}
+
+fn check(result: String, out: &mut dyn Write) -> Result<(), String> {
+    if result.starts_with("Error") {
+        return Err(result);
+    }
+    writeln!(out, "{}", result).map_err(|e| e.to_string())
+}
+
+fn print_decrypted(dec: api::DecryptedFile, out: &mut dyn Write) -> Result<(), String> {
+    match dec.epoch {
+        Some(epoch) => writeln!(out, "{} (epoch {})", dec.filename, epoch),
+        None => writeln!(out, "{}", dec.filename),
+    }
+    .map_err(|e| e.to_string())
+}
+
+// Levels as LogEntry numbers them.
+const LEVEL_NAMES: [&str; 5] = ["TRACE", "DEBUG", "INFO", "WARN", "ERROR"];
+
+fn min_entry_level(level: LevelFilter) -> Option<i32> {
+    match level {
+        LevelFilter::Off => None,
+        LevelFilter::Error => Some(4),
+        LevelFilter::Warn => Some(3),
+        LevelFilter::Info => Some(2),
+        LevelFilter::Debug => Some(1),
+        LevelFilter::Trace => Some(0),
+    }
+}
+
+fn print_entry(entry: &LogEntry, out: &mut dyn Write) -> io::Result<()> {
+    let level = usize::try_from(entry.level)
+        .ok()
+        .and_then(|level| LEVEL_NAMES.get(level))
+        .unwrap_or(&"?");
+    write!(out, "{} {:5}", entry.time_millis, level)?;
+    if let Some(trace_id) = &entry.trace_id {
+        write!(out, " trace={}", trace_id)?;
+    }
+    if let Some(source) = &entry.source {
+        write!(out, " source={}", source)?;
+    }
+    if let Some(span_id) = &entry.span_id {
+        write!(out, " span={}", span_id)?;
+    }
+    if let Some(parent_span) = &entry.parent_span {
+        write!(out, " parent={}", parent_span)?;
+    }
+    writeln!(out, " {}: {}", entry.tag, entry.msg)
+}
+
+fn parse_u64(value: &str, what: &str) -> Result<u64, String> {
+    value.parse().map_err(|e| format!("{}: {}", what, e))
+}
+
+/// Run one command line (without the program name), writing its output to
+/// `out`. Usage errors come back as `Err` carrying the usage text.
+pub fn run(argv: Vec<String>, out: &mut dyn Write) -> Result<(), String> {
+    match parse_args(argv) {
+        Ok(args) => execute(&args, out),
+        Err(e) if e.is_empty() => Err(USAGE.to_string()),
+        Err(e) => Err(format!("{}\n\n{}", e, USAGE)),
+    }
+}
+
+fn execute(args: &Args, out: &mut dyn Write) -> Result<(), String> {
+    let log_level = args.log_level.unwrap_or(LevelFilter::Info);
+    let started_millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as i64);
+    if args.tail_logs {
+        logger::init_buffered_logger(log_level);
+    }
+    let result = run_command(args, out);
+    if let (true, Some(min_level)) = (args.tail_logs, min_entry_level(log_level)) {
+        for entry in logger::recent_logs(started_millis, min_level, args.trace.clone()) {
+            print_entry(&entry, out).map_err(|e| e.to_string())?;
+        }
+    }
+    result
+}
+
+fn run_command(args: &Args, out: &mut dyn Write) -> Result<(), String> {
+    let camera = args.camera.as_deref().ok_or("--camera is required")?;
+    let dir = args.dir.as_deref().ok_or("--dir is required")?;
+    let camera = camera.to_string();
+    let context = CallContext {
+        trace_id: args.trace.clone(),
+        source: Some("cli".to_string()),
+        parent_span: None,
+    };
+    // Anything these calls run inside inherits this context.
+    let _context_guard = logger::enter_call_context(context.clone());
+
+    let command: Vec<&str> = args.command.iter().map(String::as_str).collect();
+    let first_time = args.first_time && command.first() == Some(&"init");
+    if first_time {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir, e))?;
+    }
+    api::initialize_camera(
+        camera.clone(),
+        dir.to_string(),
+        first_time,
+        Some(context.clone()),
+    );
+
+    let result = match command.as_slice() {
+        // Clients are created lazily, so touch the group to force the load.
+        ["init"] => check(
+            api::get_group_name("motion".to_string(), camera.clone(), Some(context.clone())),
+            out,
+        ),
+        ["group-name", tag] => check(
+            api::get_group_name(tag.to_string(), camera.clone(), Some(context.clone())),
+            out,
+        ),
+        ["decrypt-video", enc_file] => api::decrypt_video_at_epoch(
+            camera.clone(),
+            enc_file.to_string(),
+            args.epoch,
+            Some(context.clone()),
+        )
+        .map_err(|e| format!("{:?}", e))
+        .and_then(|dec| print_decrypted(dec, out)),
+        ["decrypt-thumbnail", enc_file, meta_dir] => api::decrypt_thumbnail_at_epoch(
+            camera.clone(),
+            enc_file.to_string(),
+            meta_dir.to_string(),
+            args.epoch,
+            Some(context.clone()),
+        )
+        .map_err(|e| format!("{:?}", e))
+        .and_then(|dec| print_decrypted(dec, out)),
+        ["heartbeat-request", timestamp] => {
+            let timestamp = parse_u64(timestamp, "timestamp")?;
+            let command = api::generate_heartbeat_request_config_command(
+                camera.clone(),
+                timestamp,
+                Some(context.clone()),
+            );
+            emit_bytes(&command, args.out.as_deref(), out)
+        }
+        ["heartbeat-response", file, timestamp] => {
+            let timestamp = parse_u64(timestamp, "timestamp")?;
+            let response = fs::read(file).map_err(|e| format!("{}: {}", file, e))?;
+            check(
+                api::process_heartbeat_config_response(
+                    camera.clone(),
+                    response,
+                    timestamp,
+                    Some(context.clone()),
+                ),
+                out,
+            )
+        }
+        ["encrypt-settings", file] => {
+            let data = fs::read(file).map_err(|e| format!("{}: {}", file, e))?;
+            let encrypted =
+                api::encrypt_settings_message(camera.clone(), data, Some(context.clone()));
+            emit_bytes(&encrypted, args.out.as_deref(), out)
+        }
+        _ => Err(format!("unknown command\n\n{}", USAGE)),
+    };
+
+    api::shutdown_app();
+    result
+}
+
+/// Entry point of the `secluso-cli` binary.
+pub fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1).collect()) {
+        Ok(args) => args,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("secluso-cli: {}\n", e);
+            }
+            eprint!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    // tail-logs keeps entries in the bridge's buffer and prints them itself.
+    if !args.tail_logs {
+        let _ = TermLogger::init(
+            args.log_level.unwrap_or(LevelFilter::Warn),
+            ConfigBuilder::new().build(),
+            TerminalMode::Stderr,
+            ColorChoice::Auto,
+        );
+    }
+
+    match execute(&args, &mut io::stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("secluso-cli: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
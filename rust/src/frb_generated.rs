@@ -25,6 +25,7 @@
 
 // Section: imports
 
+use crate::api::lock_manager::*;
 use crate::api::logger::*;
 use crate::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
@@ -42,7 +43,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1355579577;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 444440919;
 
 // Section: executor
 
@@ -50,6 +51,54 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__lock_manager__LockHandle_id_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "LockHandle_id",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::lock_manager::LockHandle::id(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__logger__SendToDartLogger_new_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -155,7 +204,7 @@ fn wire__crate__api__lock_manager__acquire_lock_impl(
         },
     )
 }
-fn wire__crate__api__logger__create_log_stream_impl(
+fn wire__crate__api__lock_manager__acquire_lock_as_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -163,7 +212,7 @@ fn wire__crate__api__logger__create_log_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_log_stream",
+            debug_name: "acquire_lock_as",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -177,23 +226,19 @@ fn wire__crate__api__logger__create_log_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_s = <StreamSink<
-                crate::api::logger::LogEntry,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_owner = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::create_log_stream(api_s);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::lock_manager::acquire_lock_as(api_path, api_owner)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__decrypt_message_impl(
+fn wire__crate__api__lock_manager__acquire_lock_with_timeout_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -201,7 +246,7 @@ fn wire__crate__api__decrypt_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decrypt_message",
+            debug_name: "acquire_lock_with_timeout",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -215,24 +260,24 @@ fn wire__crate__api__decrypt_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_client_tag = <String>::sse_decode(&mut deserializer);
-            let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_timeout_millis = <u64>::sse_decode(&mut deserializer);
+            let api_owner = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::decrypt_message(
-                        api_client_tag,
-                        api_camera_name,
-                        api_data,
-                    ))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::lock_manager::acquire_lock_with_timeout(
+                        api_path,
+                        api_timeout_millis,
+                        api_owner,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__decrypt_thumbnail_impl(
+fn wire__crate__api__lock_manager__acquire_shared_lock_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -240,7 +285,7 @@ fn wire__crate__api__decrypt_thumbnail_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decrypt_thumbnail",
+            debug_name: "acquire_shared_lock",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -254,26 +299,20 @@ fn wire__crate__api__decrypt_thumbnail_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_enc_filename = <String>::sse_decode(&mut deserializer);
-            let api_pending_meta_directory = <String>::sse_decode(&mut deserializer);
-            let api__assumed_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_owner = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::decrypt_thumbnail(
-                        api_camera_name,
-                        api_enc_filename,
-                        api_pending_meta_directory,
-                        api__assumed_epoch,
-                    ))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::lock_manager::acquire_shared_lock(api_path, api_owner)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__decrypt_video_impl(
+fn wire__crate__api__pairing__begin_pairing_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -281,7 +320,7 @@ fn wire__crate__api__decrypt_video_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decrypt_video",
+            debug_name: "begin_pairing",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -295,24 +334,20 @@ fn wire__crate__api__decrypt_video_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_enc_filename = <String>::sse_decode(&mut deserializer);
-            let api__assumed_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_request = <crate::api::pairing::PairingRequest>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::decrypt_video(
-                        api_camera_name,
-                        api_enc_filename,
-                        api__assumed_epoch,
-                    ))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pairing::begin_pairing(api_request, api_context)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__deregister_camera_impl(
+fn wire__crate__api__logger__call_context_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -320,7 +355,7 @@ fn wire__crate__api__deregister_camera_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "deregister_camera",
+            debug_name: "call_context_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -334,20 +369,18 @@ fn wire__crate__api__deregister_camera_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_camera_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::deregister_camera(api_camera_name);
-                    })?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::logger::CallContext::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__encrypt_settings_message_impl(
+fn wire__crate__api__hotspot__cancel_device_wait_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -355,7 +388,7 @@ fn wire__crate__api__encrypt_settings_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "encrypt_settings_message",
+            debug_name: "cancel_device_wait",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -369,14 +402,12 @@ fn wire__crate__api__encrypt_settings_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_waiter_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::encrypt_settings_message(
-                        api_camera_name,
-                        api_data,
+                    let output_ok = Result::<_, ()>::Ok(crate::api::hotspot::cancel_device_wait(
+                        api_waiter_id,
                     ))?;
                     Ok(output_ok)
                 })())
@@ -384,7 +415,7 @@ fn wire__crate__api__encrypt_settings_message_impl(
         },
     )
 }
-fn wire__crate__api__flutter_add_camera_impl(
+fn wire__crate__api__lock_manager__cancel_lock_wait_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -392,7 +423,7 @@ fn wire__crate__api__flutter_add_camera_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "flutter_add_camera",
+            debug_name: "cancel_lock_wait",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -406,34 +437,20 @@ fn wire__crate__api__flutter_add_camera_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_ip = <String>::sse_decode(&mut deserializer);
-            let api_secret = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_standalone = <bool>::sse_decode(&mut deserializer);
-            let api_ssid = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
-            let api_pairing_token = <String>::sse_decode(&mut deserializer);
-            let api_credentials_full = <String>::sse_decode(&mut deserializer);
+            let api_waiter_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::flutter_add_camera(
-                        api_camera_name,
-                        api_ip,
-                        api_secret,
-                        api_standalone,
-                        api_ssid,
-                        api_password,
-                        api_pairing_token,
-                        api_credentials_full,
-                    ))?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::lock_manager::cancel_lock_wait(api_waiter_id),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__generate_heartbeat_request_config_command_impl(
+fn wire__crate__api__pairing__cancel_pairing_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -441,7 +458,7 @@ fn wire__crate__api__generate_heartbeat_request_config_command_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_heartbeat_request_config_command",
+            debug_name: "cancel_pairing",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -456,23 +473,20 @@ fn wire__crate__api__generate_heartbeat_request_config_command_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_timestamp = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::generate_heartbeat_request_config_command(
-                            api_camera_name,
-                            api_timestamp,
-                        ),
-                    )?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::pairing::cancel_pairing(api_camera_name, api_context)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__get_group_name_impl(
+fn wire__crate__api__livestream_session__close_livestream_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -480,7 +494,7 @@ fn wire__crate__api__get_group_name_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group_name",
+            debug_name: "close_livestream_session",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -494,52 +508,27 @@ fn wire__crate__api__get_group_name_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_client_tag = <String>::sse_decode(&mut deserializer);
             let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::get_group_name(
-                        api_client_tag,
-                        api_camera_name,
-                    ))?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__simple__greet_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
+                transform_result_sse::<_, crate::api::livestream_session::LivestreamError>(
+                    (move || {
+                        let output_ok = crate::api::livestream_session::close_livestream_session(
+                            api_camera_name,
+                            api_session_id,
+                            api_context,
+                        )?;
+                        Ok(output_ok)
+                    })(),
                 )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            }
         },
     )
 }
-fn wire__crate__api__init_app_impl(
+fn wire__crate__api__logger__create_log_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -547,7 +536,7 @@ fn wire__crate__api__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "create_log_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -561,11 +550,15 @@ fn wire__crate__api__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_s = <StreamSink<
+                crate::api::logger::LogEntry,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init_app();
+                        crate::api::logger::create_log_stream(api_s);
                     })?;
                     Ok(output_ok)
                 })())
@@ -573,7 +566,7 @@ fn wire__crate__api__init_app_impl(
         },
     )
 }
-fn wire__crate__api__logger__init_logger_impl(
+fn wire__crate__api__livestream_session__current_livestream_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -581,7 +574,7 @@ fn wire__crate__api__logger__init_logger_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_logger",
+            debug_name: "current_livestream_session",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -595,19 +588,25 @@ fn wire__crate__api__logger__init_logger_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::init_logger();
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::livestream_session::current_livestream_session(
+                            api_camera_name,
+                            api_context,
+                        ),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__initialize_camera_impl(
+fn wire__crate__api__decrypt_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -615,7 +614,7 @@ fn wire__crate__api__initialize_camera_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_camera",
+            debug_name: "decrypt_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -629,16 +628,19 @@ fn wire__crate__api__initialize_camera_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_client_tag = <String>::sse_decode(&mut deserializer);
             let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_file_dir = <String>::sse_decode(&mut deserializer);
-            let api_first_time = <bool>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::initialize_camera(
+                    let output_ok = Result::<_, ()>::Ok(crate::api::decrypt_message(
+                        api_client_tag,
                         api_camera_name,
-                        api_file_dir,
-                        api_first_time,
+                        api_data,
+                        api_context,
                     ))?;
                     Ok(output_ok)
                 })())
@@ -646,7 +648,7 @@ fn wire__crate__api__initialize_camera_impl(
         },
     )
 }
-fn wire__crate__api__lock_manager__is_lock_held_impl(
+fn wire__crate__api__notifications__decrypt_notification_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -654,7 +656,7 @@ fn wire__crate__api__lock_manager__is_lock_held_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_lock_held",
+            debug_name: "decrypt_notification",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -668,18 +670,29 @@ fn wire__crate__api__lock_manager__is_lock_held_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_client_tag = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::lock_manager::is_lock_held(api_path)?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, crate::api::notifications::NotificationError>(
+                    (move || {
+                        let output_ok = crate::api::notifications::decrypt_notification(
+                            api_camera_name,
+                            api_client_tag,
+                            api_data,
+                            api_context,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__livestream_decrypt_impl(
+fn wire__crate__api__decrypt_thumbnail_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -687,7 +700,7 @@ fn wire__crate__api__livestream_decrypt_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "livestream_decrypt",
+            debug_name: "decrypt_thumbnail",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -702,15 +715,20 @@ fn wire__crate__api__livestream_decrypt_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_expected_chunk_number = <u64>::sse_decode(&mut deserializer);
+            let api_enc_filename = <String>::sse_decode(&mut deserializer);
+            let api_pending_meta_directory = <String>::sse_decode(&mut deserializer);
+            let api_assumed_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::livestream_decrypt(
+                    let output_ok = Result::<_, ()>::Ok(crate::api::decrypt_thumbnail(
                         api_camera_name,
-                        api_data,
-                        api_expected_chunk_number,
+                        api_enc_filename,
+                        api_pending_meta_directory,
+                        api_assumed_epoch,
+                        api_context,
                     ))?;
                     Ok(output_ok)
                 })())
@@ -718,7 +736,7 @@ fn wire__crate__api__livestream_decrypt_impl(
         },
     )
 }
-fn wire__crate__api__livestream_update_impl(
+fn wire__crate__api__decrypt_thumbnail_at_epoch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -726,7 +744,7 @@ fn wire__crate__api__livestream_update_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "livestream_update",
+            debug_name: "decrypt_thumbnail_at_epoch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -741,21 +759,28 @@ fn wire__crate__api__livestream_update_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_msg = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_enc_filename = <String>::sse_decode(&mut deserializer);
+            let api_pending_meta_directory = <String>::sse_decode(&mut deserializer);
+            let api_assumed_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::livestream_update(
+                transform_result_sse::<_, crate::api::DecryptError>((move || {
+                    let output_ok = crate::api::decrypt_thumbnail_at_epoch(
                         api_camera_name,
-                        api_msg,
-                    ))?;
+                        api_enc_filename,
+                        api_pending_meta_directory,
+                        api_assumed_epoch,
+                        api_context,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__ping_proprietary_device_impl(
+fn wire__crate__api__decrypt_thumbnails_batch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -763,7 +788,7 @@ fn wire__crate__api__ping_proprietary_device_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ping_proprietary_device",
+            debug_name: "decrypt_thumbnails_batch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -777,19 +802,27 @@ fn wire__crate__api__ping_proprietary_device_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_camera_ip = <String>::sse_decode(&mut deserializer);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_enc_filenames = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_pending_meta_directory = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::ping_proprietary_device(api_camera_ip))?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::decrypt_thumbnails_batch(
+                        api_camera_name,
+                        api_enc_filenames,
+                        api_pending_meta_directory,
+                        api_context,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__process_heartbeat_config_response_impl(
+fn wire__crate__api__decrypt_video_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -797,7 +830,7 @@ fn wire__crate__api__process_heartbeat_config_response_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "process_heartbeat_config_response",
+            debug_name: "decrypt_video",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -812,24 +845,26 @@ fn wire__crate__api__process_heartbeat_config_response_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_camera_name = <String>::sse_decode(&mut deserializer);
-            let api_config_response = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_expected_timestamp = <u64>::sse_decode(&mut deserializer);
+            let api_enc_filename = <String>::sse_decode(&mut deserializer);
+            let api_assumed_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::process_heartbeat_config_response(
-                            api_camera_name,
-                            api_config_response,
-                            api_expected_timestamp,
-                        ))?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::decrypt_video(
+                        api_camera_name,
+                        api_enc_filename,
+                        api_assumed_epoch,
+                        api_context,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__lock_manager__release_lock_impl(
+fn wire__crate__api__decrypt_video_at_epoch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -837,7 +872,7 @@ fn wire__crate__api__lock_manager__release_lock_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "release_lock",
+            debug_name: "decrypt_video_at_epoch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -851,18 +886,27 @@ fn wire__crate__api__lock_manager__release_lock_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_enc_filename = <String>::sse_decode(&mut deserializer);
+            let api_assumed_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::lock_manager::release_lock(api_path)?;
+                transform_result_sse::<_, crate::api::DecryptError>((move || {
+                    let output_ok = crate::api::decrypt_video_at_epoch(
+                        api_camera_name,
+                        api_enc_filename,
+                        api_assumed_epoch,
+                        api_context,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__rust_lib_version_impl(
+fn wire__crate__api__decrypt_videos_batch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -870,7 +914,7 @@ fn wire__crate__api__rust_lib_version_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_lib_version",
+            debug_name: "decrypt_videos_batch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -884,17 +928,25 @@ fn wire__crate__api__rust_lib_version_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_enc_filenames = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::rust_lib_version())?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::decrypt_videos_batch(
+                        api_camera_name,
+                        api_enc_filenames,
+                        api_context,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger__rust_set_up_impl(
+fn wire__crate__api__deregister_camera_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -902,7 +954,7 @@ fn wire__crate__api__logger__rust_set_up_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_set_up",
+            debug_name: "deregister_camera",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -916,11 +968,14 @@ fn wire__crate__api__logger__rust_set_up_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::rust_set_up();
+                        crate::api::deregister_camera(api_camera_name, api_context);
                     })?;
                     Ok(output_ok)
                 })())
@@ -928,7 +983,7 @@ fn wire__crate__api__logger__rust_set_up_impl(
         },
     )
 }
-fn wire__crate__api__logger__rust_shutdown_impl(
+fn wire__crate__api__push_queue__drain_push_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -936,7 +991,7 @@ fn wire__crate__api__logger__rust_shutdown_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_shutdown",
+            debug_name: "drain_push_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -950,17 +1005,21 @@ fn wire__crate__api__logger__rust_shutdown_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::logger::rust_shutdown()?;
+                    let output_ok =
+                        crate::api::push_queue::drain_push_messages(api_camera_name, api_context)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__shutdown_app_impl(
+fn wire__crate__api__encrypt_settings_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -968,7 +1027,7 @@ fn wire__crate__api__shutdown_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "shutdown_app",
+            debug_name: "encrypt_settings_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -982,19 +1041,25 @@ fn wire__crate__api__shutdown_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::shutdown_app();
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::encrypt_settings_message(
+                        api_camera_name,
+                        api_data,
+                        api_context,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__lock_manager__try_acquire_lock_impl(
+fn wire__crate__api__push_queue__enqueue_push_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1002,7 +1067,7 @@ fn wire__crate__api__lock_manager__try_acquire_lock_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "try_acquire_lock",
+            debug_name: "enqueue_push_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1016,414 +1081,4493 @@ fn wire__crate__api__lock_manager__try_acquire_lock_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_client_tag = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sent_at_millis = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::lock_manager::try_acquire_lock(api_path)?;
+                    let output_ok = crate::api::push_queue::enqueue_push_message(
+                        api_camera_name,
+                        api_client_tag,
+                        api_data,
+                        api_sent_at_millis,
+                        api_context,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>
-);
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
+fn wire__crate__api__backup__export_camera_backup_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_camera_backup",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::backup::export_camera_backup(
+                        api_camera_name,
+                        api_passphrase,
+                        api_context,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for LevelFilter {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+fn wire__crate__api__flutter_add_camera_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "flutter_add_camera",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_ip = <String>::sse_decode(&mut deserializer);
+            let api_secret = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_standalone = <bool>::sse_decode(&mut deserializer);
+            let api_ssid = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_pairing_token = <String>::sse_decode(&mut deserializer);
+            let api_credentials =
+                <crate::api::credentials::ServerCredentials>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::flutter_add_camera(
+                        api_camera_name,
+                        api_ip,
+                        api_secret,
+                        api_standalone,
+                        api_ssid,
+                        api_password,
+                        api_pairing_token,
+                        api_credentials,
+                        api_context,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for SendToDartLogger {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+fn wire__crate__api__generate_heartbeat_request_config_command_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_heartbeat_request_config_command",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_timestamp = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::generate_heartbeat_request_config_command(
+                            api_camera_name,
+                            api_timestamp,
+                            api_context,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
-    }
+fn wire__crate__api__get_group_name_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_group_name",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_client_tag = <String>::sse_decode(&mut deserializer);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_group_name(
+                        api_client_tag,
+                        api_camera_name,
+                        api_context,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
+fn wire__crate__api__metrics__get_metrics_snapshot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_metrics_snapshot",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::metrics::get_metrics_snapshot())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__backup__import_camera_backup_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_camera_backup",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_backup = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_file_dir = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::backup::import_camera_backup(
+                        api_backup,
+                        api_passphrase,
+                        api_file_dir,
+                        api_context,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__init_logger_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_logger",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::init_logger();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__initialize_camera_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_camera",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_file_dir = <String>::sse_decode(&mut deserializer);
+            let api_first_time = <bool>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::initialize_camera(
+                        api_camera_name,
+                        api_file_dir,
+                        api_first_time,
+                        api_context,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__is_lock_held_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_lock_held",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::lock_manager::is_lock_held(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__list_held_locks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_held_locks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::lock_manager::list_held_locks())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_decrypt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "livestream_decrypt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_expected_chunk_number = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::livestream_decrypt(
+                        api_camera_name,
+                        api_data,
+                        api_expected_chunk_number,
+                        api_context,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_session__livestream_session_decrypt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "livestream_session_decrypt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <u64>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_expected_chunk_number = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::livestream_session::LivestreamError>(
+                    (move || {
+                        let output_ok = crate::api::livestream_session::livestream_session_decrypt(
+                            api_camera_name,
+                            api_session_id,
+                            api_data,
+                            api_expected_chunk_number,
+                            api_context,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_session__livestream_session_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "livestream_session_update",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <u64>::sse_decode(&mut deserializer);
+            let api_msg = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::livestream_session::LivestreamError>(
+                    (move || {
+                        let output_ok = crate::api::livestream_session::livestream_session_update(
+                            api_camera_name,
+                            api_session_id,
+                            api_msg,
+                            api_context,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_stats__livestream_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "livestream_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::livestream_stats::livestream_stats(
+                            api_camera_name,
+                            api_context,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_stats__livestream_stats_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "livestream_stats_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::livestream_stats::LivestreamStats::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "livestream_update",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_msg = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::livestream_update(
+                        api_camera_name,
+                        api_msg,
+                        api_context,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__lock_owner_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lock_owner_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::lock_manager::lock_owner_info(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hotspot__new_device_waiter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_device_waiter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::hotspot::new_device_waiter())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__new_lock_waiter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_lock_waiter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::lock_manager::new_lock_waiter())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_session__open_livestream_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_livestream_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_take_over = <bool>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::livestream_session::LivestreamError>(
+                    (move || {
+                        let output_ok = crate::api::livestream_session::open_livestream_session(
+                            api_camera_name,
+                            api_take_over,
+                            api_context,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pairing__pairing_step_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pairing_step",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_camera_online = <bool>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pairing::pairing_step(
+                        api_camera_name,
+                        api_camera_online,
+                        api_context,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__ping_proprietary_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ping_proprietary_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_ip = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::ping_proprietary_device(api_camera_ip))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hotspot__probe_proprietary_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_proprietary_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            let api_port = <Option<u16>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::hotspot::probe_proprietary_device(api_address, api_port)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__process_heartbeat_config_response_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_heartbeat_config_response",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_config_response = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_expected_timestamp = <u64>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::process_heartbeat_config_response(
+                            api_camera_name,
+                            api_config_response,
+                            api_expected_timestamp,
+                            api_context,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__push_queue__push_drain_report_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "push_drain_report_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::push_queue::PushDrainReport::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__recent_logs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recent_logs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_since_millis = <i64>::sse_decode(&mut deserializer);
+            let api_min_level = <i32>::sse_decode(&mut deserializer);
+            let api_trace_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::logger::recent_logs(
+                        api_since_millis,
+                        api_min_level,
+                        api_trace_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__release_lock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "release_lock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::lock_manager::release_lock(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__release_lock_handle_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "release_lock_handle",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <LockHandle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::lock_manager::release_lock_handle(api_handle),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__reload_camera_client_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reload_camera_client",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::reload_camera_client(api_camera_name, api_context)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__metrics__reset_metrics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_metrics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::metrics::reset_metrics();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pairing__resume_pairing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_pairing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_request =
+                <Option<crate::api::pairing::PairingRequest>>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::pairing::resume_pairing(
+                        api_camera_name,
+                        api_request,
+                        api_context,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__rust_lib_version_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_lib_version",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::rust_lib_version())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__rust_set_up_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_set_up",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::rust_set_up();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__rust_shutdown_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_shutdown",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::logger::rust_shutdown()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__credentials__server_credentials_validate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "server_credentials_validate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::credentials::ServerCredentials>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::credentials::ServerCredentials::validate(&api_that)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__shutdown_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "shutdown_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::shutdown_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_recording__start_livestream_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_livestream_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_out_path = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::livestream_recording::start_livestream_recording(
+                        api_camera_name,
+                        api_out_path,
+                        api_context,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__livestream_recording__stop_livestream_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_livestream_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::livestream_recording::stop_livestream_recording(
+                        api_camera_name,
+                        api_context,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera_events__subscribe_camera_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_camera_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::camera_events::CameraEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera_events::subscribe_camera_events(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__try_acquire_lock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "try_acquire_lock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::lock_manager::try_acquire_lock(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__try_acquire_lock_as_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "try_acquire_lock_as",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_owner = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::lock_manager::try_acquire_lock_as(api_path, api_owner)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__try_acquire_shared_lock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "try_acquire_shared_lock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_owner = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::lock_manager::try_acquire_shared_lock(api_path, api_owner)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lock_manager__wait_for_lock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wait_for_lock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_timeout_millis = <u64>::sse_decode(&mut deserializer);
+            let api_owner = <Option<String>>::sse_decode(&mut deserializer);
+            let api_waiter_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::lock_manager::wait_for_lock(
+                        api_path,
+                        api_timeout_millis,
+                        api_owner,
+                        api_waiter_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hotspot__wait_for_proprietary_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wait_for_proprietary_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_ip = <String>::sse_decode(&mut deserializer);
+            let api_port = <Option<u16>>::sse_decode(&mut deserializer);
+            let api_timeout_millis = <u64>::sse_decode(&mut deserializer);
+            let api_poll_interval_millis = <u64>::sse_decode(&mut deserializer);
+            let api_required_stable_polls = <u32>::sse_decode(&mut deserializer);
+            let api_settle_delay_millis = <u64>::sse_decode(&mut deserializer);
+            let api_waiter_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::hotspot::wait_for_proprietary_device(
+                        api_camera_ip,
+                        api_port,
+                        api_timeout_millis,
+                        api_poll_interval_millis,
+                        api_required_stable_polls,
+                        api_settle_delay_millis,
+                        api_waiter_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pairing__watch_pairing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_pairing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_camera_name = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::pairing::PairingSession,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_context =
+                <Option<crate::api::logger::CallContext>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::pairing::watch_pairing(api_camera_name, api_sink, api_context)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for LevelFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for LockHandle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for SendToDartLogger {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
 impl SseDecode
+    for StreamSink<
+        crate::api::camera_events::CameraEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::logger::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::pairing::PairingSession,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::logger::CallContext {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_traceId = <Option<String>>::sse_decode(deserializer);
+        let mut var_source = <Option<String>>::sse_decode(deserializer);
+        let mut var_parentSpan = <Option<String>>::sse_decode(deserializer);
+        return crate::api::logger::CallContext {
+            trace_id: var_traceId,
+            source: var_source,
+            parent_span: var_parentSpan,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera_events::CameraEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeMillis = <i64>::sse_decode(deserializer);
+        let mut var_cameraName = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::camera_events::CameraEventKind>::sse_decode(deserializer);
+        let mut var_detail = <Option<String>>::sse_decode(deserializer);
+        let mut var_traceId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::camera_events::CameraEvent {
+            time_millis: var_timeMillis,
+            camera_name: var_cameraName,
+            kind: var_kind,
+            detail: var_detail,
+            trace_id: var_traceId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera_events::CameraEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::camera_events::CameraEventKind::Registered,
+            1 => crate::api::camera_events::CameraEventKind::ClientInitialized,
+            2 => crate::api::camera_events::CameraEventKind::ClientInitFailed,
+            3 => crate::api::camera_events::CameraEventKind::Paired,
+            4 => crate::api::camera_events::CameraEventKind::PairingFailed,
+            5 => crate::api::camera_events::CameraEventKind::Deregistered,
+            6 => crate::api::camera_events::CameraEventKind::HeartbeatHealthy,
+            7 => crate::api::camera_events::CameraEventKind::HeartbeatFailed,
+            8 => crate::api::camera_events::CameraEventKind::LockTimeout,
+            _ => unreachable!("Invalid variant for CameraEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::ClientReloadReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_wasLoaded = <bool>::sse_decode(deserializer);
+        let mut var_consistent = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::ClientReloadReport {
+            was_loaded: var_wasLoaded,
+            consistent: var_consistent,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DecryptError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::DecryptError::Busy;
+            }
+            1 => {
+                return crate::api::DecryptError::NotInitialized;
+            }
+            2 => {
+                let mut var_expected = <Option<u64>>::sse_decode(deserializer);
+                let mut var_found = <Option<u64>>::sse_decode(deserializer);
+                return crate::api::DecryptError::EpochMismatch {
+                    expected: var_expected,
+                    found: var_found,
+                };
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::DecryptError::Failed(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::DecryptOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::DecryptedFile>::sse_decode(deserializer);
+                return crate::api::DecryptOutcome::Decrypted(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <crate::api::DecryptError>::sse_decode(deserializer);
+                return crate::api::DecryptOutcome::Failed(var_field0);
+            }
+            2 => {
+                return crate::api::DecryptOutcome::Skipped;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::DecryptedFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_filename = <String>::sse_decode(deserializer);
+        let mut var_epoch = <Option<u64>>::sse_decode(deserializer);
+        let mut var_caughtUp = <bool>::sse_decode(deserializer);
+        return crate::api::DecryptedFile {
+            filename: var_filename,
+            epoch: var_epoch,
+            caught_up: var_caughtUp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::push_queue::DrainedPushMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_contentHash = <String>::sse_decode(deserializer);
+        let mut var_receivedAtMillis = <i64>::sse_decode(deserializer);
+        let mut var_sentAtMillis = <Option<i64>>::sse_decode(deserializer);
+        let mut var_generation = <Option<u64>>::sse_decode(deserializer);
+        let mut var_payload =
+            <crate::api::notifications::NotificationPayload>::sse_decode(deserializer);
+        return crate::api::push_queue::DrainedPushMessage {
+            content_hash: var_contentHash,
+            received_at_millis: var_receivedAtMillis,
+            sent_at_millis: var_sentAtMillis,
+            generation: var_generation,
+            payload: var_payload,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metrics::ErrorCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::metrics::ErrorCount {
+            kind: var_kind,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lock_manager::HeldLockInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_handle = <u64>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_shared = <bool>::sse_decode(deserializer);
+        let mut var_owner = <String>::sse_decode(deserializer);
+        let mut var_heldMillis = <u64>::sse_decode(deserializer);
+        return crate::api::lock_manager::HeldLockInfo {
+            handle: var_handle,
+            path: var_path,
+            shared: var_shared,
+            owner: var_owner,
+            held_millis: var_heldMillis,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::DecryptOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::DecryptOutcome>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::push_queue::DrainedPushMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::push_queue::DrainedPushMessage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::metrics::ErrorCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::metrics::ErrorCount>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::lock_manager::HeldLockInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::lock_manager::HeldLockInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::logger::LogEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::logger::LogEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::metrics::OperationMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::metrics::OperationMetrics>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::livestream_session::LivestreamError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::livestream_session::LivestreamError::Busy;
+            }
+            1 => {
+                return crate::api::livestream_session::LivestreamError::NotInitialized;
+            }
+            2 => {
+                let mut var_sessionId = <u64>::sse_decode(deserializer);
+                return crate::api::livestream_session::LivestreamError::NoSession {
+                    session_id: var_sessionId,
+                };
+            }
+            3 => {
+                let mut var_sessionId = <u64>::sse_decode(deserializer);
+                return crate::api::livestream_session::LivestreamError::AlreadyOpen {
+                    session_id: var_sessionId,
+                };
+            }
+            4 => {
+                let mut var_expected = <u64>::sse_decode(deserializer);
+                let mut var_got = <u64>::sse_decode(deserializer);
+                return crate::api::livestream_session::LivestreamError::SessionMismatch {
+                    expected: var_expected,
+                    got: var_got,
+                };
+            }
+            5 => {
+                let mut var_sessionId = <u64>::sse_decode(deserializer);
+                return crate::api::livestream_session::LivestreamError::SessionClosed {
+                    session_id: var_sessionId,
+                };
+            }
+            6 => {
+                return crate::api::livestream_session::LivestreamError::AwaitingKeyUpdate;
+            }
+            7 => {
+                let mut var_expected = <Option<u64>>::sse_decode(deserializer);
+                let mut var_found = <Option<u64>>::sse_decode(deserializer);
+                return crate::api::livestream_session::LivestreamError::EpochMismatch {
+                    expected: var_expected,
+                    found: var_found,
+                };
+            }
+            8 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::livestream_session::LivestreamError::Failed(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::livestream_session::LivestreamSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sessionId = <u64>::sse_decode(deserializer);
+        let mut var_cameraName = <String>::sse_decode(deserializer);
+        let mut var_openedAtMillis = <i64>::sse_decode(deserializer);
+        let mut var_keyUpdated = <bool>::sse_decode(deserializer);
+        let mut var_chunksDecrypted = <u64>::sse_decode(deserializer);
+        return crate::api::livestream_session::LivestreamSession {
+            session_id: var_sessionId,
+            camera_name: var_cameraName,
+            opened_at_millis: var_openedAtMillis,
+            key_updated: var_keyUpdated,
+            chunks_decrypted: var_chunksDecrypted,
+        };
+    }
+}
+
+impl SseDecode for crate::api::livestream_stats::LivestreamStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sessionStartedAtMillis = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updateFailures = <u64>::sse_decode(deserializer);
+        let mut var_chunksReceived = <u64>::sse_decode(deserializer);
+        let mut var_chunksDecrypted = <u64>::sse_decode(deserializer);
+        let mut var_chunksFailed = <u64>::sse_decode(deserializer);
+        let mut var_chunksOutOfOrder = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        let mut var_bytesDecrypted = <u64>::sse_decode(deserializer);
+        let mut var_lastChunkNumber = <Option<u64>>::sse_decode(deserializer);
+        let mut var_lastChunkAtMillis = <Option<i64>>::sse_decode(deserializer);
+        let mut var_decryptP50Micros = <u64>::sse_decode(deserializer);
+        let mut var_decryptP90Micros = <u64>::sse_decode(deserializer);
+        let mut var_decryptP99Micros = <u64>::sse_decode(deserializer);
+        let mut var_decryptMaxMicros = <u64>::sse_decode(deserializer);
+        let mut var_lockWaitTotalMicros = <u64>::sse_decode(deserializer);
+        let mut var_lockWaitMaxMicros = <u64>::sse_decode(deserializer);
+        let mut var_lockTimeouts = <u64>::sse_decode(deserializer);
+        return crate::api::livestream_stats::LivestreamStats {
+            session_started_at_millis: var_sessionStartedAtMillis,
+            update_failures: var_updateFailures,
+            chunks_received: var_chunksReceived,
+            chunks_decrypted: var_chunksDecrypted,
+            chunks_failed: var_chunksFailed,
+            chunks_out_of_order: var_chunksOutOfOrder,
+            bytes_received: var_bytesReceived,
+            bytes_decrypted: var_bytesDecrypted,
+            last_chunk_number: var_lastChunkNumber,
+            last_chunk_at_millis: var_lastChunkAtMillis,
+            decrypt_p50_micros: var_decryptP50Micros,
+            decrypt_p90_micros: var_decryptP90Micros,
+            decrypt_p99_micros: var_decryptP99Micros,
+            decrypt_max_micros: var_decryptMaxMicros,
+            lock_wait_total_micros: var_lockWaitTotalMicros,
+            lock_wait_max_micros: var_lockWaitMaxMicros,
+            lock_timeouts: var_lockTimeouts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lock_manager::LockOwnerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_owner = <String>::sse_decode(deserializer);
+        let mut var_shared = <bool>::sse_decode(deserializer);
+        let mut var_acquiredAtMillis = <i64>::sse_decode(deserializer);
+        return crate::api::lock_manager::LockOwnerInfo {
+            pid: var_pid,
+            owner: var_owner,
+            shared: var_shared,
+            acquired_at_millis: var_acquiredAtMillis,
+        };
+    }
+}
+
+impl SseDecode for crate::api::logger::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeMillis = <i64>::sse_decode(deserializer);
+        let mut var_level = <i32>::sse_decode(deserializer);
+        let mut var_tag = <String>::sse_decode(deserializer);
+        let mut var_msg = <String>::sse_decode(deserializer);
+        let mut var_traceId = <Option<String>>::sse_decode(deserializer);
+        let mut var_source = <Option<String>>::sse_decode(deserializer);
+        let mut var_parentSpan = <Option<String>>::sse_decode(deserializer);
+        let mut var_spanId = <Option<String>>::sse_decode(deserializer);
+        let mut var_durationMicros = <i64>::sse_decode(deserializer);
+        return crate::api::logger::LogEntry {
+            time_millis: var_timeMillis,
+            level: var_level,
+            tag: var_tag,
+            msg: var_msg,
+            trace_id: var_traceId,
+            source: var_source,
+            parent_span: var_parentSpan,
+            span_id: var_spanId,
+            duration_micros: var_durationMicros,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metrics::MetricsSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sinceMillis = <i64>::sse_decode(deserializer);
+        let mut var_takenAtMillis = <i64>::sse_decode(deserializer);
+        let mut var_latencyBucketBoundsMillis = <Vec<u64>>::sse_decode(deserializer);
+        let mut var_byFunction =
+            <Vec<crate::api::metrics::OperationMetrics>>::sse_decode(deserializer);
+        let mut var_byCamera =
+            <Vec<crate::api::metrics::OperationMetrics>>::sse_decode(deserializer);
+        return crate::api::metrics::MetricsSnapshot {
+            since_millis: var_sinceMillis,
+            taken_at_millis: var_takenAtMillis,
+            latency_bucket_bounds_millis: var_latencyBucketBoundsMillis,
+            by_function: var_byFunction,
+            by_camera: var_byCamera,
+        };
+    }
+}
+
+impl SseDecode for crate::api::notifications::NotificationError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::notifications::NotificationError::Busy;
+            }
+            1 => {
+                return crate::api::notifications::NotificationError::NotInitialized;
+            }
+            2 => {
+                return crate::api::notifications::NotificationError::DuplicateMessage;
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::notifications::NotificationError::Failed(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::notifications::NotificationPayload {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_timestamp = <String>::sse_decode(deserializer);
+                return crate::api::notifications::NotificationPayload::Motion {
+                    timestamp: var_timestamp,
+                };
+            }
+            1 => {
+                return crate::api::notifications::NotificationPayload::VideoReady;
+            }
+            2 => {
+                return crate::api::notifications::NotificationPayload::Empty;
+            }
+            3 => {
+                let mut var_kind = <String>::sse_decode(deserializer);
+                let mut var_json = <String>::sse_decode(deserializer);
+                return crate::api::notifications::NotificationPayload::Typed {
+                    kind: var_kind,
+                    json: var_json,
+                };
+            }
+            4 => {
+                let mut var_raw = <String>::sse_decode(deserializer);
+                return crate::api::notifications::NotificationPayload::Unknown { raw: var_raw };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::metrics::OperationMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_function = <String>::sse_decode(deserializer);
+        let mut var_camera = <String>::sse_decode(deserializer);
+        let mut var_calls = <u64>::sse_decode(deserializer);
+        let mut var_errors = <Vec<crate::api::metrics::ErrorCount>>::sse_decode(deserializer);
+        let mut var_latencyCounts = <Vec<u64>>::sse_decode(deserializer);
+        let mut var_totalMicros = <u64>::sse_decode(deserializer);
+        let mut var_maxMicros = <u64>::sse_decode(deserializer);
+        return crate::api::metrics::OperationMetrics {
+            function: var_function,
+            camera: var_camera,
+            calls: var_calls,
+            errors: var_errors,
+            latency_counts: var_latencyCounts,
+            total_micros: var_totalMicros,
+            max_micros: var_maxMicros,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<LockHandle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<LockHandle>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::logger::CallContext> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::logger::CallContext>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::livestream_session::LivestreamSession> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::livestream_session::LivestreamSession>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::lock_manager::LockOwnerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::lock_manager::LockOwnerInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::pairing::PairingRequest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::pairing::PairingRequest>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::pairing::PairingPhase {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::pairing::PairingPhase::Connecting,
+            1 => crate::api::pairing::PairingPhase::KeyExchange,
+            2 => crate::api::pairing::PairingPhase::WifiProvisioning,
+            3 => crate::api::pairing::PairingPhase::WaitingForCameraOnline,
+            4 => crate::api::pairing::PairingPhase::Done,
+            5 => crate::api::pairing::PairingPhase::Failed,
+            6 => crate::api::pairing::PairingPhase::Cancelled,
+            _ => unreachable!("Invalid variant for PairingPhase: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::pairing::PairingRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cameraName = <String>::sse_decode(deserializer);
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_secret = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_standalone = <bool>::sse_decode(deserializer);
+        let mut var_ssid = <String>::sse_decode(deserializer);
+        let mut var_wifiPassword = <String>::sse_decode(deserializer);
+        let mut var_pairingToken = <String>::sse_decode(deserializer);
+        let mut var_credentials =
+            <crate::api::credentials::ServerCredentials>::sse_decode(deserializer);
+        return crate::api::pairing::PairingRequest {
+            camera_name: var_cameraName,
+            ip: var_ip,
+            secret: var_secret,
+            standalone: var_standalone,
+            ssid: var_ssid,
+            wifi_password: var_wifiPassword,
+            pairing_token: var_pairingToken,
+            credentials: var_credentials,
+        };
+    }
+}
+
+impl SseDecode for crate::api::pairing::PairingSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cameraName = <String>::sse_decode(deserializer);
+        let mut var_phase = <crate::api::pairing::PairingPhase>::sse_decode(deserializer);
+        let mut var_firmwareVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_attempts = <u32>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAtMillis = <i64>::sse_decode(deserializer);
+        return crate::api::pairing::PairingSession {
+            camera_name: var_cameraName,
+            phase: var_phase,
+            firmware_version: var_firmwareVersion,
+            attempts: var_attempts,
+            last_error: var_lastError,
+            updated_at_millis: var_updatedAtMillis,
+        };
+    }
+}
+
+impl SseDecode for crate::api::push_queue::PushDrainReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_messages =
+            <Vec<crate::api::push_queue::DrainedPushMessage>>::sse_decode(deserializer);
+        let mut var_duplicates = <u32>::sse_decode(deserializer);
+        let mut var_dropped = <u32>::sse_decode(deserializer);
+        let mut var_remaining = <u32>::sse_decode(deserializer);
+        let mut var_busy = <bool>::sse_decode(deserializer);
+        return crate::api::push_queue::PushDrainReport {
+            messages: var_messages,
+            duplicates: var_duplicates,
+            dropped: var_dropped,
+            remaining: var_remaining,
+            busy: var_busy,
+        };
+    }
+}
+
+impl SseDecode for crate::api::livestream_recording::RecordingSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_fragments = <u32>::sse_decode(deserializer);
+        let mut var_frames = <u32>::sse_decode(deserializer);
+        let mut var_durationMillis = <u64>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        return crate::api::livestream_recording::RecordingSummary {
+            path: var_path,
+            fragments: var_fragments,
+            frames: var_frames,
+            duration_millis: var_durationMillis,
+            bytes: var_bytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::credentials::ServerCredentials {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_username = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        return crate::api::credentials::ServerCredentials {
+            address: var_address,
+            username: var_username,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::hotspot::WaitOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_status = <crate::api::hotspot::WaitStatus>::sse_decode(deserializer);
+        let mut var_attempts = <u32>::sse_decode(deserializer);
+        let mut var_stablePolls = <u32>::sse_decode(deserializer);
+        let mut var_probeLatenciesMicros = <Vec<u64>>::sse_decode(deserializer);
+        let mut var_elapsedMillis = <u64>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return crate::api::hotspot::WaitOutcome {
+            status: var_status,
+            attempts: var_attempts,
+            stable_polls: var_stablePolls,
+            probe_latencies_micros: var_probeLatenciesMicros,
+            elapsed_millis: var_elapsedMillis,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hotspot::WaitStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::hotspot::WaitStatus::Ready,
+            1 => crate::api::hotspot::WaitStatus::TimedOut,
+            2 => crate::api::hotspot::WaitStatus::Cancelled,
+            _ => unreachable!("Invalid variant for WaitStatus: {}", inner),
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire__crate__api__logger__SendToDartLogger_new_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__logger__SendToDartLogger_set_stream_sink_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__lock_manager__acquire_lock_impl(port, ptr, rust_vec_len, data_len),
+        5 => {
+            wire__crate__api__lock_manager__acquire_lock_as_impl(port, ptr, rust_vec_len, data_len)
+        }
+        6 => wire__crate__api__lock_manager__acquire_lock_with_timeout_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__lock_manager__acquire_shared_lock_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__pairing__begin_pairing_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__logger__call_context_default_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__hotspot__cancel_device_wait_impl(port, ptr, rust_vec_len, data_len),
+        11 => {
+            wire__crate__api__lock_manager__cancel_lock_wait_impl(port, ptr, rust_vec_len, data_len)
+        }
+        12 => wire__crate__api__pairing__cancel_pairing_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__livestream_session__close_livestream_session_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__logger__create_log_stream_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__livestream_session__current_livestream_session_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__decrypt_message_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__notifications__decrypt_notification_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__decrypt_thumbnail_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__decrypt_thumbnail_at_epoch_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__decrypt_thumbnails_batch_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__decrypt_video_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__decrypt_video_at_epoch_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__decrypt_videos_batch_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__deregister_camera_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__push_queue__drain_push_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__encrypt_settings_message_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__push_queue__enqueue_push_message_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => {
+            wire__crate__api__backup__export_camera_backup_impl(port, ptr, rust_vec_len, data_len)
+        }
+        29 => wire__crate__api__flutter_add_camera_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__generate_heartbeat_request_config_command_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__get_group_name_impl(port, ptr, rust_vec_len, data_len),
+        32 => {
+            wire__crate__api__metrics__get_metrics_snapshot_impl(port, ptr, rust_vec_len, data_len)
+        }
+        34 => {
+            wire__crate__api__backup__import_camera_backup_impl(port, ptr, rust_vec_len, data_len)
+        }
+        35 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__logger__init_logger_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__initialize_camera_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__lock_manager__is_lock_held_impl(port, ptr, rust_vec_len, data_len),
+        39 => {
+            wire__crate__api__lock_manager__list_held_locks_impl(port, ptr, rust_vec_len, data_len)
+        }
+        40 => wire__crate__api__livestream_decrypt_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__livestream_session__livestream_session_decrypt_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__livestream_session__livestream_session_update_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__livestream_stats__livestream_stats_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__livestream_stats__livestream_stats_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__livestream_update_impl(port, ptr, rust_vec_len, data_len),
+        46 => {
+            wire__crate__api__lock_manager__lock_owner_info_impl(port, ptr, rust_vec_len, data_len)
+        }
+        47 => wire__crate__api__hotspot__new_device_waiter_impl(port, ptr, rust_vec_len, data_len),
+        48 => {
+            wire__crate__api__lock_manager__new_lock_waiter_impl(port, ptr, rust_vec_len, data_len)
+        }
+        49 => wire__crate__api__livestream_session__open_livestream_session_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__pairing__pairing_step_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__ping_proprietary_device_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__hotspot__probe_proprietary_device_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__process_heartbeat_config_response_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__push_queue__push_drain_report_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        55 => wire__crate__api__logger__recent_logs_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__lock_manager__release_lock_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__lock_manager__release_lock_handle_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__reload_camera_client_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__metrics__reset_metrics_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__pairing__resume_pairing_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__rust_lib_version_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__logger__rust_set_up_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__logger__rust_shutdown_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__credentials__server_credentials_validate_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => wire__crate__api__shutdown_app_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__livestream_recording__start_livestream_recording_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => wire__crate__api__livestream_recording__stop_livestream_recording_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        68 => wire__crate__api__camera_events__subscribe_camera_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => {
+            wire__crate__api__lock_manager__try_acquire_lock_impl(port, ptr, rust_vec_len, data_len)
+        }
+        70 => wire__crate__api__lock_manager__try_acquire_lock_as_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        71 => wire__crate__api__lock_manager__try_acquire_shared_lock_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__api__lock_manager__wait_for_lock_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__hotspot__wait_for_proprietary_device_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        74 => wire__crate__api__pairing__watch_pairing_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__lock_manager__LockHandle_id_impl(ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<LevelFilter> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<LevelFilter> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LevelFilter>> for LevelFilter {
+    fn into_into_dart(self) -> FrbWrapper<LevelFilter> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<LockHandle> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<LockHandle> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LockHandle>> for LockHandle {
+    fn into_into_dart(self) -> FrbWrapper<LockHandle> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SendToDartLogger> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SendToDartLogger> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SendToDartLogger>> for SendToDartLogger {
+    fn into_into_dart(self) -> FrbWrapper<SendToDartLogger> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::logger::CallContext {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.trace_id.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.parent_span.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::logger::CallContext
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logger::CallContext>
+    for crate::api::logger::CallContext
+{
+    fn into_into_dart(self) -> crate::api::logger::CallContext {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera_events::CameraEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time_millis.into_into_dart().into_dart(),
+            self.camera_name.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+            self.trace_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera_events::CameraEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera_events::CameraEvent>
+    for crate::api::camera_events::CameraEvent
+{
+    fn into_into_dart(self) -> crate::api::camera_events::CameraEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera_events::CameraEventKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Registered => 0.into_dart(),
+            Self::ClientInitialized => 1.into_dart(),
+            Self::ClientInitFailed => 2.into_dart(),
+            Self::Paired => 3.into_dart(),
+            Self::PairingFailed => 4.into_dart(),
+            Self::Deregistered => 5.into_dart(),
+            Self::HeartbeatHealthy => 6.into_dart(),
+            Self::HeartbeatFailed => 7.into_dart(),
+            Self::LockTimeout => 8.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera_events::CameraEventKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera_events::CameraEventKind>
+    for crate::api::camera_events::CameraEventKind
+{
+    fn into_into_dart(self) -> crate::api::camera_events::CameraEventKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ClientReloadReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.was_loaded.into_into_dart().into_dart(),
+            self.consistent.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ClientReloadReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ClientReloadReport>
+    for crate::api::ClientReloadReport
+{
+    fn into_into_dart(self) -> crate::api::ClientReloadReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DecryptError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::DecryptError::Busy => [0.into_dart()].into_dart(),
+            crate::api::DecryptError::NotInitialized => [1.into_dart()].into_dart(),
+            crate::api::DecryptError::EpochMismatch { expected, found } => [
+                2.into_dart(),
+                expected.into_into_dart().into_dart(),
+                found.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::DecryptError::Failed(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DecryptError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DecryptError> for crate::api::DecryptError {
+    fn into_into_dart(self) -> crate::api::DecryptError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DecryptOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::DecryptOutcome::Decrypted(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::DecryptOutcome::Failed(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::DecryptOutcome::Skipped => [2.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DecryptOutcome {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DecryptOutcome> for crate::api::DecryptOutcome {
+    fn into_into_dart(self) -> crate::api::DecryptOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DecryptedFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.filename.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+            self.caught_up.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DecryptedFile {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DecryptedFile> for crate::api::DecryptedFile {
+    fn into_into_dart(self) -> crate::api::DecryptedFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::push_queue::DrainedPushMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content_hash.into_into_dart().into_dart(),
+            self.received_at_millis.into_into_dart().into_dart(),
+            self.sent_at_millis.into_into_dart().into_dart(),
+            self.generation.into_into_dart().into_dart(),
+            self.payload.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::push_queue::DrainedPushMessage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::push_queue::DrainedPushMessage>
+    for crate::api::push_queue::DrainedPushMessage
+{
+    fn into_into_dart(self) -> crate::api::push_queue::DrainedPushMessage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metrics::ErrorCount {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metrics::ErrorCount
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metrics::ErrorCount>
+    for crate::api::metrics::ErrorCount
+{
+    fn into_into_dart(self) -> crate::api::metrics::ErrorCount {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lock_manager::HeldLockInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.handle.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.shared.into_into_dart().into_dart(),
+            self.owner.into_into_dart().into_dart(),
+            self.held_millis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::lock_manager::HeldLockInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lock_manager::HeldLockInfo>
+    for crate::api::lock_manager::HeldLockInfo
+{
+    fn into_into_dart(self) -> crate::api::lock_manager::HeldLockInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::livestream_session::LivestreamError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::livestream_session::LivestreamError::Busy => [0.into_dart()].into_dart(),
+            crate::api::livestream_session::LivestreamError::NotInitialized => {
+                [1.into_dart()].into_dart()
+            }
+            crate::api::livestream_session::LivestreamError::NoSession { session_id } => {
+                [2.into_dart(), session_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::livestream_session::LivestreamError::AlreadyOpen { session_id } => {
+                [3.into_dart(), session_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::livestream_session::LivestreamError::SessionMismatch { expected, got } => [
+                4.into_dart(),
+                expected.into_into_dart().into_dart(),
+                got.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::livestream_session::LivestreamError::SessionClosed { session_id } => {
+                [5.into_dart(), session_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::livestream_session::LivestreamError::AwaitingKeyUpdate => {
+                [6.into_dart()].into_dart()
+            }
+            crate::api::livestream_session::LivestreamError::EpochMismatch { expected, found } => [
+                7.into_dart(),
+                expected.into_into_dart().into_dart(),
+                found.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::livestream_session::LivestreamError::Failed(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::livestream_session::LivestreamError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::livestream_session::LivestreamError>
+    for crate::api::livestream_session::LivestreamError
+{
+    fn into_into_dart(self) -> crate::api::livestream_session::LivestreamError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::livestream_session::LivestreamSession {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.session_id.into_into_dart().into_dart(),
+            self.camera_name.into_into_dart().into_dart(),
+            self.opened_at_millis.into_into_dart().into_dart(),
+            self.key_updated.into_into_dart().into_dart(),
+            self.chunks_decrypted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::livestream_session::LivestreamSession
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::livestream_session::LivestreamSession>
+    for crate::api::livestream_session::LivestreamSession
+{
+    fn into_into_dart(self) -> crate::api::livestream_session::LivestreamSession {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::livestream_stats::LivestreamStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.session_started_at_millis.into_into_dart().into_dart(),
+            self.update_failures.into_into_dart().into_dart(),
+            self.chunks_received.into_into_dart().into_dart(),
+            self.chunks_decrypted.into_into_dart().into_dart(),
+            self.chunks_failed.into_into_dart().into_dart(),
+            self.chunks_out_of_order.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
+            self.bytes_decrypted.into_into_dart().into_dart(),
+            self.last_chunk_number.into_into_dart().into_dart(),
+            self.last_chunk_at_millis.into_into_dart().into_dart(),
+            self.decrypt_p50_micros.into_into_dart().into_dart(),
+            self.decrypt_p90_micros.into_into_dart().into_dart(),
+            self.decrypt_p99_micros.into_into_dart().into_dart(),
+            self.decrypt_max_micros.into_into_dart().into_dart(),
+            self.lock_wait_total_micros.into_into_dart().into_dart(),
+            self.lock_wait_max_micros.into_into_dart().into_dart(),
+            self.lock_timeouts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::livestream_stats::LivestreamStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::livestream_stats::LivestreamStats>
+    for crate::api::livestream_stats::LivestreamStats
+{
+    fn into_into_dart(self) -> crate::api::livestream_stats::LivestreamStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lock_manager::LockOwnerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.owner.into_into_dart().into_dart(),
+            self.shared.into_into_dart().into_dart(),
+            self.acquired_at_millis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::lock_manager::LockOwnerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lock_manager::LockOwnerInfo>
+    for crate::api::lock_manager::LockOwnerInfo
+{
+    fn into_into_dart(self) -> crate::api::lock_manager::LockOwnerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::logger::LogEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time_millis.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+            self.tag.into_into_dart().into_dart(),
+            self.msg.into_into_dart().into_dart(),
+            self.trace_id.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.parent_span.into_into_dart().into_dart(),
+            self.span_id.into_into_dart().into_dart(),
+            self.duration_micros.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::logger::LogEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logger::LogEntry>
+    for crate::api::logger::LogEntry
+{
+    fn into_into_dart(self) -> crate::api::logger::LogEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metrics::MetricsSnapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.since_millis.into_into_dart().into_dart(),
+            self.taken_at_millis.into_into_dart().into_dart(),
+            self.latency_bucket_bounds_millis
+                .into_into_dart()
+                .into_dart(),
+            self.by_function.into_into_dart().into_dart(),
+            self.by_camera.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metrics::MetricsSnapshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metrics::MetricsSnapshot>
+    for crate::api::metrics::MetricsSnapshot
+{
+    fn into_into_dart(self) -> crate::api::metrics::MetricsSnapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::notifications::NotificationError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::notifications::NotificationError::Busy => [0.into_dart()].into_dart(),
+            crate::api::notifications::NotificationError::NotInitialized => {
+                [1.into_dart()].into_dart()
+            }
+            crate::api::notifications::NotificationError::DuplicateMessage => {
+                [2.into_dart()].into_dart()
+            }
+            crate::api::notifications::NotificationError::Failed(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::notifications::NotificationError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::notifications::NotificationError>
+    for crate::api::notifications::NotificationError
+{
+    fn into_into_dart(self) -> crate::api::notifications::NotificationError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::notifications::NotificationPayload {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::notifications::NotificationPayload::Motion { timestamp } => {
+                [0.into_dart(), timestamp.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::notifications::NotificationPayload::VideoReady => {
+                [1.into_dart()].into_dart()
+            }
+            crate::api::notifications::NotificationPayload::Empty => [2.into_dart()].into_dart(),
+            crate::api::notifications::NotificationPayload::Typed { kind, json } => [
+                3.into_dart(),
+                kind.into_into_dart().into_dart(),
+                json.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::notifications::NotificationPayload::Unknown { raw } => {
+                [4.into_dart(), raw.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::notifications::NotificationPayload
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::notifications::NotificationPayload>
+    for crate::api::notifications::NotificationPayload
+{
+    fn into_into_dart(self) -> crate::api::notifications::NotificationPayload {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metrics::OperationMetrics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.function.into_into_dart().into_dart(),
+            self.camera.into_into_dart().into_dart(),
+            self.calls.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+            self.latency_counts.into_into_dart().into_dart(),
+            self.total_micros.into_into_dart().into_dart(),
+            self.max_micros.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metrics::OperationMetrics
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metrics::OperationMetrics>
+    for crate::api::metrics::OperationMetrics
+{
+    fn into_into_dart(self) -> crate::api::metrics::OperationMetrics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pairing::PairingPhase {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Connecting => 0.into_dart(),
+            Self::KeyExchange => 1.into_dart(),
+            Self::WifiProvisioning => 2.into_dart(),
+            Self::WaitingForCameraOnline => 3.into_dart(),
+            Self::Done => 4.into_dart(),
+            Self::Failed => 5.into_dart(),
+            Self::Cancelled => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pairing::PairingPhase
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pairing::PairingPhase>
+    for crate::api::pairing::PairingPhase
+{
+    fn into_into_dart(self) -> crate::api::pairing::PairingPhase {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pairing::PairingRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.camera_name.into_into_dart().into_dart(),
+            self.ip.into_into_dart().into_dart(),
+            self.secret.into_into_dart().into_dart(),
+            self.standalone.into_into_dart().into_dart(),
+            self.ssid.into_into_dart().into_dart(),
+            self.wifi_password.into_into_dart().into_dart(),
+            self.pairing_token.into_into_dart().into_dart(),
+            self.credentials.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pairing::PairingRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pairing::PairingRequest>
+    for crate::api::pairing::PairingRequest
+{
+    fn into_into_dart(self) -> crate::api::pairing::PairingRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pairing::PairingSession {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.camera_name.into_into_dart().into_dart(),
+            self.phase.into_into_dart().into_dart(),
+            self.firmware_version.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+            self.updated_at_millis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pairing::PairingSession
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pairing::PairingSession>
+    for crate::api::pairing::PairingSession
+{
+    fn into_into_dart(self) -> crate::api::pairing::PairingSession {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::push_queue::PushDrainReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.messages.into_into_dart().into_dart(),
+            self.duplicates.into_into_dart().into_dart(),
+            self.dropped.into_into_dart().into_dart(),
+            self.remaining.into_into_dart().into_dart(),
+            self.busy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::push_queue::PushDrainReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::push_queue::PushDrainReport>
+    for crate::api::push_queue::PushDrainReport
+{
+    fn into_into_dart(self) -> crate::api::push_queue::PushDrainReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::livestream_recording::RecordingSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.fragments.into_into_dart().into_dart(),
+            self.frames.into_into_dart().into_dart(),
+            self.duration_millis.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::livestream_recording::RecordingSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::livestream_recording::RecordingSummary>
+    for crate::api::livestream_recording::RecordingSummary
+{
+    fn into_into_dart(self) -> crate::api::livestream_recording::RecordingSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::credentials::ServerCredentials {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::credentials::ServerCredentials
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::credentials::ServerCredentials>
+    for crate::api::credentials::ServerCredentials
+{
+    fn into_into_dart(self) -> crate::api::credentials::ServerCredentials {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hotspot::WaitOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.status.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+            self.stable_polls.into_into_dart().into_dart(),
+            self.probe_latencies_micros.into_into_dart().into_dart(),
+            self.elapsed_millis.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hotspot::WaitOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hotspot::WaitOutcome>
+    for crate::api::hotspot::WaitOutcome
+{
+    fn into_into_dart(self) -> crate::api::hotspot::WaitOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hotspot::WaitStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Ready => 0.into_dart(),
+            Self::TimedOut => 1.into_dart(),
+            Self::Cancelled => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hotspot::WaitStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hotspot::WaitStatus>
+    for crate::api::hotspot::WaitStatus
+{
+    fn into_into_dart(self) -> crate::api::hotspot::WaitStatus {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for LevelFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for LockHandle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for SendToDartLogger {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode
+impl SseEncode
+    for StreamSink<
+        crate::api::camera_events::CameraEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
     for StreamSink<crate::api::logger::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for String {
+impl SseEncode
+    for StreamSink<
+        crate::api::pairing::PairingSession,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for crate::api::logger::CallContext {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.trace_id, serializer);
+        <Option<String>>::sse_encode(self.source, serializer);
+        <Option<String>>::sse_encode(self.parent_span, serializer);
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for crate::api::camera_events::CameraEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.time_millis, serializer);
+        <String>::sse_encode(self.camera_name, serializer);
+        <crate::api::camera_events::CameraEventKind>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.detail, serializer);
+        <Option<String>>::sse_encode(self.trace_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::camera_events::CameraEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::camera_events::CameraEventKind::Registered => 0,
+                crate::api::camera_events::CameraEventKind::ClientInitialized => 1,
+                crate::api::camera_events::CameraEventKind::ClientInitFailed => 2,
+                crate::api::camera_events::CameraEventKind::Paired => 3,
+                crate::api::camera_events::CameraEventKind::PairingFailed => 4,
+                crate::api::camera_events::CameraEventKind::Deregistered => 5,
+                crate::api::camera_events::CameraEventKind::HeartbeatHealthy => 6,
+                crate::api::camera_events::CameraEventKind::HeartbeatFailed => 7,
+                crate::api::camera_events::CameraEventKind::LockTimeout => 8,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::ClientReloadReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.was_loaded, serializer);
+        <bool>::sse_encode(self.consistent, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::DecryptError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::DecryptError::Busy => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::DecryptError::NotInitialized => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::DecryptError::EpochMismatch { expected, found } => {
+                <i32>::sse_encode(2, serializer);
+                <Option<u64>>::sse_encode(expected, serializer);
+                <Option<u64>>::sse_encode(found, serializer);
+            }
+            crate::api::DecryptError::Failed(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::api::logger::LogEntry {
+impl SseEncode for crate::api::DecryptOutcome {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_timeMillis = <i64>::sse_decode(deserializer);
-        let mut var_level = <i32>::sse_decode(deserializer);
-        let mut var_tag = <String>::sse_decode(deserializer);
-        let mut var_msg = <String>::sse_decode(deserializer);
-        let mut var_traceId = <Option<String>>::sse_decode(deserializer);
-        return crate::api::logger::LogEntry {
-            time_millis: var_timeMillis,
-            level: var_level,
-            tag: var_tag,
-            msg: var_msg,
-            trace_id: var_traceId,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::DecryptOutcome::Decrypted(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::DecryptedFile>::sse_encode(field0, serializer);
+            }
+            crate::api::DecryptOutcome::Failed(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::DecryptError>::sse_encode(field0, serializer);
+            }
+            crate::api::DecryptOutcome::Skipped => {
+                <i32>::sse_encode(2, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::DecryptedFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.filename, serializer);
+        <Option<u64>>::sse_encode(self.epoch, serializer);
+        <bool>::sse_encode(self.caught_up, serializer);
+    }
+}
+
+impl SseEncode for crate::api::push_queue::DrainedPushMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.content_hash, serializer);
+        <i64>::sse_encode(self.received_at_millis, serializer);
+        <Option<i64>>::sse_encode(self.sent_at_millis, serializer);
+        <Option<u64>>::sse_encode(self.generation, serializer);
+        <crate::api::notifications::NotificationPayload>::sse_encode(self.payload, serializer);
+    }
+}
+
+impl SseEncode for crate::api::metrics::ErrorCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <u64>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::lock_manager::HeldLockInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.handle, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <bool>::sse_encode(self.shared, serializer);
+        <String>::sse_encode(self.owner, serializer);
+        <u64>::sse_encode(self.held_millis, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::DecryptOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::DecryptOutcome>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::push_queue::DrainedPushMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::push_queue::DrainedPushMessage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::metrics::ErrorCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::metrics::ErrorCount>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::lock_manager::HeldLockInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::lock_manager::HeldLockInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::logger::LogEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::logger::LogEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::metrics::OperationMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::metrics::OperationMetrics>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u64>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for crate::api::livestream_session::LivestreamError {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::livestream_session::LivestreamError::Busy => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::livestream_session::LivestreamError::NotInitialized => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::livestream_session::LivestreamError::NoSession { session_id } => {
+                <i32>::sse_encode(2, serializer);
+                <u64>::sse_encode(session_id, serializer);
+            }
+            crate::api::livestream_session::LivestreamError::AlreadyOpen { session_id } => {
+                <i32>::sse_encode(3, serializer);
+                <u64>::sse_encode(session_id, serializer);
+            }
+            crate::api::livestream_session::LivestreamError::SessionMismatch { expected, got } => {
+                <i32>::sse_encode(4, serializer);
+                <u64>::sse_encode(expected, serializer);
+                <u64>::sse_encode(got, serializer);
+            }
+            crate::api::livestream_session::LivestreamError::SessionClosed { session_id } => {
+                <i32>::sse_encode(5, serializer);
+                <u64>::sse_encode(session_id, serializer);
+            }
+            crate::api::livestream_session::LivestreamError::AwaitingKeyUpdate => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::api::livestream_session::LivestreamError::EpochMismatch { expected, found } => {
+                <i32>::sse_encode(7, serializer);
+                <Option<u64>>::sse_encode(expected, serializer);
+                <Option<u64>>::sse_encode(found, serializer);
+            }
+            crate::api::livestream_session::LivestreamError::Failed(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::livestream_session::LivestreamSession {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.session_id, serializer);
+        <String>::sse_encode(self.camera_name, serializer);
+        <i64>::sse_encode(self.opened_at_millis, serializer);
+        <bool>::sse_encode(self.key_updated, serializer);
+        <u64>::sse_encode(self.chunks_decrypted, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::livestream_stats::LivestreamStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<i64>>::sse_encode(self.session_started_at_millis, serializer);
+        <u64>::sse_encode(self.update_failures, serializer);
+        <u64>::sse_encode(self.chunks_received, serializer);
+        <u64>::sse_encode(self.chunks_decrypted, serializer);
+        <u64>::sse_encode(self.chunks_failed, serializer);
+        <u64>::sse_encode(self.chunks_out_of_order, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+        <u64>::sse_encode(self.bytes_decrypted, serializer);
+        <Option<u64>>::sse_encode(self.last_chunk_number, serializer);
+        <Option<i64>>::sse_encode(self.last_chunk_at_millis, serializer);
+        <u64>::sse_encode(self.decrypt_p50_micros, serializer);
+        <u64>::sse_encode(self.decrypt_p90_micros, serializer);
+        <u64>::sse_encode(self.decrypt_p99_micros, serializer);
+        <u64>::sse_encode(self.decrypt_max_micros, serializer);
+        <u64>::sse_encode(self.lock_wait_total_micros, serializer);
+        <u64>::sse_encode(self.lock_wait_max_micros, serializer);
+        <u64>::sse_encode(self.lock_timeouts, serializer);
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::api::lock_manager::LockOwnerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.owner, serializer);
+        <bool>::sse_encode(self.shared, serializer);
+        <i64>::sse_encode(self.acquired_at_millis, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__logger__SendToDartLogger_new_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__logger__SendToDartLogger_set_stream_sink_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__lock_manager__acquire_lock_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__logger__create_log_stream_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__decrypt_message_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__decrypt_thumbnail_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__decrypt_video_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__deregister_camera_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__encrypt_settings_message_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__flutter_add_camera_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__generate_heartbeat_request_config_command_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        12 => wire__crate__api__get_group_name_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__logger__init_logger_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__initialize_camera_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__lock_manager__is_lock_held_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__livestream_decrypt_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__livestream_update_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__ping_proprietary_device_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__process_heartbeat_config_response_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        22 => wire__crate__api__lock_manager__release_lock_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__rust_lib_version_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__logger__rust_set_up_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__logger__rust_shutdown_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__shutdown_app_impl(port, ptr, rust_vec_len, data_len),
-        27 => {
-            wire__crate__api__lock_manager__try_acquire_lock_impl(port, ptr, rust_vec_len, data_len)
-        }
-        _ => unreachable!(),
+impl SseEncode for crate::api::logger::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.time_millis, serializer);
+        <i32>::sse_encode(self.level, serializer);
+        <String>::sse_encode(self.tag, serializer);
+        <String>::sse_encode(self.msg, serializer);
+        <Option<String>>::sse_encode(self.trace_id, serializer);
+        <Option<String>>::sse_encode(self.source, serializer);
+        <Option<String>>::sse_encode(self.parent_span, serializer);
+        <Option<String>>::sse_encode(self.span_id, serializer);
+        <i64>::sse_encode(self.duration_micros, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        13 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::metrics::MetricsSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.since_millis, serializer);
+        <i64>::sse_encode(self.taken_at_millis, serializer);
+        <Vec<u64>>::sse_encode(self.latency_bucket_bounds_millis, serializer);
+        <Vec<crate::api::metrics::OperationMetrics>>::sse_encode(self.by_function, serializer);
+        <Vec<crate::api::metrics::OperationMetrics>>::sse_encode(self.by_camera, serializer);
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::api::notifications::NotificationError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::notifications::NotificationError::Busy => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::notifications::NotificationError::NotInitialized => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::notifications::NotificationError::DuplicateMessage => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::notifications::NotificationError::Failed(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<LevelFilter> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::notifications::NotificationPayload {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::notifications::NotificationPayload::Motion { timestamp } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(timestamp, serializer);
+            }
+            crate::api::notifications::NotificationPayload::VideoReady => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::notifications::NotificationPayload::Empty => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::notifications::NotificationPayload::Typed { kind, json } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(kind, serializer);
+                <String>::sse_encode(json, serializer);
+            }
+            crate::api::notifications::NotificationPayload::Unknown { raw } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(raw, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<LevelFilter> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LevelFilter>> for LevelFilter {
-    fn into_into_dart(self) -> FrbWrapper<LevelFilter> {
-        self.into()
+impl SseEncode for crate::api::metrics::OperationMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.function, serializer);
+        <String>::sse_encode(self.camera, serializer);
+        <u64>::sse_encode(self.calls, serializer);
+        <Vec<crate::api::metrics::ErrorCount>>::sse_encode(self.errors, serializer);
+        <Vec<u64>>::sse_encode(self.latency_counts, serializer);
+        <u64>::sse_encode(self.total_micros, serializer);
+        <u64>::sse_encode(self.max_micros, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SendToDartLogger> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SendToDartLogger> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SendToDartLogger>> for SendToDartLogger {
-    fn into_into_dart(self) -> FrbWrapper<SendToDartLogger> {
-        self.into()
+impl SseEncode for Option<LockHandle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <LockHandle>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::logger::LogEntry {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.time_millis.into_into_dart().into_dart(),
-            self.level.into_into_dart().into_dart(),
-            self.tag.into_into_dart().into_dart(),
-            self.msg.into_into_dart().into_dart(),
-            self.trace_id.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Option<crate::api::logger::CallContext> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::logger::CallContext>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::logger::LogEntry {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::logger::LogEntry>
-    for crate::api::logger::LogEntry
-{
-    fn into_into_dart(self) -> crate::api::logger::LogEntry {
-        self
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Option<crate::api::livestream_session::LivestreamSession> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::livestream_session::LivestreamSession>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for LevelFilter {
+impl SseEncode for Option<crate::api::lock_manager::LockOwnerInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::lock_manager::LockOwnerInfo>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for SendToDartLogger {
+impl SseEncode for Option<crate::api::pairing::PairingRequest> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::pairing::PairingRequest>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>>
-{
+impl SseEncode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SendToDartLogger>>
-{
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<crate::api::logger::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for crate::api::pairing::PairingPhase {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(
+            match self {
+                crate::api::pairing::PairingPhase::Connecting => 0,
+                crate::api::pairing::PairingPhase::KeyExchange => 1,
+                crate::api::pairing::PairingPhase::WifiProvisioning => 2,
+                crate::api::pairing::PairingPhase::WaitingForCameraOnline => 3,
+                crate::api::pairing::PairingPhase::Done => 4,
+                crate::api::pairing::PairingPhase::Failed => 5,
+                crate::api::pairing::PairingPhase::Cancelled => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::pairing::PairingRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <String>::sse_encode(self.camera_name, serializer);
+        <String>::sse_encode(self.ip, serializer);
+        <Vec<u8>>::sse_encode(self.secret, serializer);
+        <bool>::sse_encode(self.standalone, serializer);
+        <String>::sse_encode(self.ssid, serializer);
+        <String>::sse_encode(self.wifi_password, serializer);
+        <String>::sse_encode(self.pairing_token, serializer);
+        <crate::api::credentials::ServerCredentials>::sse_encode(self.credentials, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::pairing::PairingSession {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.camera_name, serializer);
+        <crate::api::pairing::PairingPhase>::sse_encode(self.phase, serializer);
+        <Option<String>>::sse_encode(self.firmware_version, serializer);
+        <u32>::sse_encode(self.attempts, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+        <i64>::sse_encode(self.updated_at_millis, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::push_queue::PushDrainReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <Vec<crate::api::push_queue::DrainedPushMessage>>::sse_encode(self.messages, serializer);
+        <u32>::sse_encode(self.duplicates, serializer);
+        <u32>::sse_encode(self.dropped, serializer);
+        <u32>::sse_encode(self.remaining, serializer);
+        <bool>::sse_encode(self.busy, serializer);
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::api::livestream_recording::RecordingSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.path, serializer);
+        <u32>::sse_encode(self.fragments, serializer);
+        <u32>::sse_encode(self.frames, serializer);
+        <u64>::sse_encode(self.duration_millis, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::credentials::ServerCredentials {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.username, serializer);
+        <String>::sse_encode(self.password, serializer);
     }
 }
 
-impl SseEncode for crate::api::logger::LogEntry {
+impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.time_millis, serializer);
-        <i32>::sse_encode(self.level, serializer);
-        <String>::sse_encode(self.tag, serializer);
-        <String>::sse_encode(self.msg, serializer);
-        <Option<String>>::sse_encode(self.trace_id, serializer);
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -1456,6 +5600,35 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::hotspot::WaitOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::hotspot::WaitStatus>::sse_encode(self.status, serializer);
+        <u32>::sse_encode(self.attempts, serializer);
+        <u32>::sse_encode(self.stable_polls, serializer);
+        <Vec<u64>>::sse_encode(self.probe_latencies_micros, serializer);
+        <u64>::sse_encode(self.elapsed_millis, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::hotspot::WaitStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::hotspot::WaitStatus::Ready => 0,
+                crate::api::hotspot::WaitStatus::TimedOut => 1,
+                crate::api::hotspot::WaitStatus::Cancelled => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -1464,6 +5637,7 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::api::lock_manager::*;
     use crate::api::logger::*;
     use crate::*;
     use flutter_rust_bridge::for_generated::byteorder::{
@@ -1493,6 +5667,20 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>>::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_secluso_flutter_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_secluso_flutter_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_secluso_flutter_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
         ptr: *const std::ffi::c_void,
@@ -1519,6 +5707,7 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::api::lock_manager::*;
     use crate::api::logger::*;
     use crate::*;
     use flutter_rust_bridge::for_generated::byteorder::{
@@ -1550,6 +5739,20 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LevelFilter>>::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLockHandle(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LockHandle>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSendToDartLogger(
         ptr: *const std::ffi::c_void,
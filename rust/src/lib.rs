@@ -1,4 +1,7 @@
 //! SPDX-License-Identifier: GPL-3.0-or-later
 
 pub mod api;
+pub mod backend;
+#[cfg(feature = "cli")]
+pub mod cli;
 mod frb_generated;
@@ -0,0 +1,656 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Bridge-layer behaviour against the in-memory fake backend.
+
+use parking_lot::Mutex;
+use std::io;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rust_lib_secluso_flutter::api;
+use rust_lib_secluso_flutter::api::camera_events::{self, CameraEventKind};
+use rust_lib_secluso_flutter::api::hotspot::{self, WaitStatus};
+use rust_lib_secluso_flutter::api::logger::CallContext;
+use rust_lib_secluso_flutter::api::metrics;
+use rust_lib_secluso_flutter::backend::fake::FakeCamera;
+use rust_lib_secluso_flutter::backend::mls;
+use rust_lib_secluso_flutter::backend::{CameraClientBackend, ClientError, NativeBackend};
+
+// The backend factory and client map are process-wide, so tests take turns.
+static SERIAL: Mutex<()> = Mutex::new(());
+
+fn fake_camera() -> FakeCamera {
+    let camera = FakeCamera::new();
+    camera.install();
+    camera
+}
+
+#[test]
+fn lazy_init_happens_on_first_use() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+
+    assert!(api::initialize_camera(
+        "lazy".to_string(),
+        "/tmp/lazy".to_string(),
+        false,
+        None
+    ));
+    assert_eq!(camera.state().initialize_count, 0);
+
+    let decrypted = api::decrypt_video("lazy".to_string(), "encVideo2".to_string(), 1, None);
+    assert_eq!(decrypted, "video_encVideo2.mp4");
+    assert_eq!(camera.state().initialize_count, 1);
+
+    api::deregister_camera("lazy".to_string(), None);
+}
+
+#[test]
+fn reload_reinitializes_from_disk_and_reports_bad_state() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+
+    assert!(api::reload_camera_client("unknown".to_string(), None).is_err());
+
+    api::initialize_camera("reload".to_string(), "/tmp/reload".to_string(), false, None);
+    let report = api::reload_camera_client("reload".to_string(), None).unwrap();
+    assert!(!report.was_loaded && report.consistent);
+    let report = api::reload_camera_client("reload".to_string(), None).unwrap();
+    assert!(report.was_loaded && report.consistent);
+    assert_eq!(camera.state().initialize_count, 2);
+    assert!(!camera.state().deregistered);
+
+    camera.state().initialize_error = Some("corrupt group state".to_string());
+    let report = api::reload_camera_client("reload".to_string(), None).unwrap();
+    assert_eq!(
+        report,
+        api::ClientReloadReport {
+            was_loaded: true,
+            consistent: false,
+            error: Some("corrupt group state".to_string()),
+        }
+    );
+    assert_eq!(
+        api::decrypt_video("reload".to_string(), "enc".to_string(), 0, None),
+        "Error"
+    );
+
+    camera.state().initialize_error = None;
+    api::deregister_camera("reload".to_string(), None);
+}
+
+#[test]
+fn missing_init_params_report_error() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+
+    let decrypted = api::decrypt_video("never-initialized".to_string(), "enc".to_string(), 0, None);
+    assert_eq!(decrypted, "Error");
+    assert_eq!(camera.state().initialize_count, 0);
+    assert!(
+        api::livestream_decrypt("never-initialized".to_string(), vec![1, 2, 3], 0, None).is_empty()
+    );
+}
+
+#[test]
+fn trace_suffix_is_stripped_from_camera_name() {
+    let _serial = SERIAL.lock();
+    let _camera = fake_camera();
+
+    api::initialize_camera(
+        "traced|trace=abc123".to_string(),
+        "/tmp/traced".to_string(),
+        false,
+        None,
+    );
+    let name = api::get_group_name(
+        "motion".to_string(),
+        "traced|trace=def456".to_string(),
+        None,
+    );
+    assert_eq!(name, "motion_group");
+
+    api::deregister_camera("traced".to_string(), None);
+}
+
+#[test]
+fn explicit_call_context_takes_camera_name_literally() {
+    let _serial = SERIAL.lock();
+    let _camera = fake_camera();
+    let literal = "porch|trace=not-a-trace".to_string();
+    let context = || {
+        Some(CallContext {
+            trace_id: Some("abc123".to_string()),
+            source: Some("test".to_string()),
+            parent_span: None,
+        })
+    };
+
+    api::initialize_camera("porch".to_string(), "/tmp/porch".to_string(), false, None);
+    // Without a context the suffix is split off, leaving "porch".
+    assert!(api::reload_camera_client(literal.clone(), None).is_ok());
+    // With one, the whole name is the camera, which was never initialized.
+    assert!(api::reload_camera_client(literal.clone(), context()).is_err());
+    assert!(api::reload_camera_client("porch".to_string(), context()).is_ok());
+
+    api::deregister_camera("porch".to_string(), None);
+}
+
+#[test]
+fn native_errors_are_mapped_to_legacy_strings() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+    camera.fail("decrypt_thumbnail", "bad ciphertext");
+    camera.state().group_epoch = 5;
+    camera.state().file_epochs.insert("enc".to_string(), 3);
+
+    api::initialize_camera("errors".to_string(), "/tmp/errors".to_string(), false, None);
+    assert_eq!(
+        api::decrypt_thumbnail(
+            "errors".to_string(),
+            "enc".to_string(),
+            "/tmp/meta".to_string(),
+            0,
+            None
+        ),
+        "Error(decrypt_thumbnail): bad ciphertext"
+    );
+    assert_eq!(
+        api::decrypt_video_at_epoch("errors".to_string(), "enc".to_string(), 4, None).err(),
+        Some(api::DecryptError::EpochMismatch {
+            expected: Some(5),
+            found: Some(3)
+        })
+    );
+    assert_eq!(
+        api::decrypt_video("errors".to_string(), "enc".to_string(), 4, None),
+        "Error(decrypt_video): message epoch 3 does not match group epoch 5"
+    );
+
+    api::deregister_camera("errors".to_string(), None);
+}
+
+// An MLS PrivateMessage header for `group_id` at `epoch`, then some ciphertext.
+fn private_message(group_id: &[u8], epoch: u64) -> Vec<u8> {
+    let mut message = vec![0, 1, 0, 2];
+    message.extend_from_slice(&(0x4000u16 | group_id.len() as u16).to_be_bytes());
+    message.extend_from_slice(group_id);
+    message.extend_from_slice(&epoch.to_be_bytes());
+    message.extend_from_slice(&[3, 0, 0xAB, 0xCD]);
+    message
+}
+
+#[test]
+fn native_errors_carry_file_and_group_epochs() {
+    assert_eq!(mls::message_epoch(&private_message(b"motion", 7)), Some(7));
+    assert_eq!(
+        mls::message_epoch(&private_message(&[9; 300], 1 << 40)),
+        Some(1 << 40)
+    );
+    assert_eq!(mls::message_epoch(b"\0\x01\0\x02"), None);
+    assert_eq!(mls::message_epoch(b"not an mls message"), None);
+
+    let dir = std::env::temp_dir().join(format!("native_epochs_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("encrypted")).unwrap();
+    for epoch in [5u64, 7] {
+        std::fs::write(
+            dir.join("encrypted").join(format!("encVideo{}", epoch)),
+            private_message(b"motion", epoch),
+        )
+        .unwrap();
+    }
+    std::fs::write(dir.join("encrypted").join("garbled"), b"garbled").unwrap();
+    let mut native = NativeBackend::with_file_dir_for_tests(&dir);
+    // secluso_app_native's error text, as openmls' Debug output.
+    let wrong_epoch = || {
+        Err(io::Error::other(
+            "ProcessMessageError(ValidationError(WrongEpoch))",
+        ))
+    };
+
+    // Nothing opened yet: only the file's epoch is known.
+    assert_eq!(
+        native.open_for_tests("encVideo7", wrong_epoch()),
+        Err(ClientError::WrongEpoch {
+            group_epoch: None,
+            message_epoch: Some(7)
+        })
+    );
+    assert_eq!(native.group_epoch(), None);
+    assert_eq!(
+        native.open_for_tests("encVideo5", Ok("video5".to_string())),
+        Ok("video5".to_string())
+    );
+    assert_eq!(native.group_epoch(), Some(5));
+    assert_eq!(
+        native.open_for_tests("encVideo7", wrong_epoch()),
+        Err(ClientError::WrongEpoch {
+            group_epoch: Some(5),
+            message_epoch: Some(7)
+        })
+    );
+    // A file without an MLS header leaves the epoch where it was.
+    assert!(native.open_for_tests("garbled", Ok(String::new())).is_ok());
+    assert_eq!(native.group_epoch(), Some(5));
+    assert_eq!(
+        native.open_for_tests(
+            "encVideo5",
+            Err(io::Error::other("ValidationError(SecretReuseError)"))
+        ),
+        Err(ClientError::SecretReuse)
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn native_pairing_takes_only_ipv4_on_the_pairing_port() {
+    let native = NativeBackend::default();
+    for good in ["192.168.4.1", " 10.42.0.1 ", "192.168.4.1:12348"] {
+        assert_eq!(native.check_pairing_address(good), Ok(()), "{:?}", good);
+    }
+    for bad in [
+        "192.168.4.1:8080",
+        "::1",
+        "[fe80::1]:12348",
+        "camera.local",
+        "localhost:12348",
+        "",
+    ] {
+        assert!(native.check_pairing_address(bad).is_err(), "{:?}", bad);
+    }
+}
+
+#[test]
+fn decrypt_catches_up_with_the_group_on_disk() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+    let decrypt = |enc: &str, assumed_epoch: u64| {
+        api::decrypt_video_at_epoch("epochs".to_string(), enc.to_string(), assumed_epoch, None)
+    };
+    camera.state().group_epoch = 2;
+    api::initialize_camera("epochs".to_string(), "/tmp/epochs".to_string(), false, None);
+
+    let dec = decrypt("enc2", 2).unwrap();
+    assert_eq!((dec.epoch, dec.caught_up), (Some(2), false));
+    assert_eq!(camera.state().initialize_count, 1);
+
+    // Another process moved the group on. A hint past the loaded epoch reloads
+    // before trying.
+    camera.state().group_epoch = 3;
+    camera.state().file_epochs.insert("enc3".to_string(), 3);
+    let dec = decrypt("enc3", 3).unwrap();
+    assert_eq!((dec.epoch, dec.caught_up), (Some(3), true));
+    assert_eq!(camera.state().initialize_count, 2);
+    assert_eq!(
+        camera
+            .calls()
+            .iter()
+            .filter(|c| *c == "decrypt_video")
+            .count(),
+        2
+    );
+
+    // Without a useful hint the client's own WrongEpoch triggers the reload.
+    camera.state().group_epoch = 4;
+    camera.state().file_epochs.insert("enc4".to_string(), 4);
+    let dec = decrypt("enc4", 0).unwrap();
+    assert_eq!((dec.epoch, dec.caught_up), (Some(4), true));
+    assert_eq!(camera.state().initialize_count, 3);
+
+    // Files from an older epoch can't be helped by reloading.
+    camera.state().file_epochs.insert("old".to_string(), 1);
+    assert_eq!(
+        decrypt("old", 0).err(),
+        Some(api::DecryptError::EpochMismatch {
+            expected: Some(4),
+            found: Some(1)
+        })
+    );
+    assert_eq!(camera.state().initialize_count, 3);
+
+    // A file ahead of what is on disk is still ahead after the reload.
+    camera.state().file_epochs.insert("future".to_string(), 9);
+    assert_eq!(
+        decrypt("future", 9).err(),
+        Some(api::DecryptError::EpochMismatch {
+            expected: Some(4),
+            found: Some(9)
+        })
+    );
+    assert_eq!(camera.state().initialize_count, 4);
+
+    api::deregister_camera("epochs".to_string(), None);
+}
+
+#[test]
+fn batches_keep_order_and_stop_at_a_fatal_epoch_error() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+    let describe = |outcomes: Vec<api::DecryptOutcome>| {
+        outcomes
+            .into_iter()
+            .map(|outcome| match outcome {
+                api::DecryptOutcome::Decrypted(dec) => format!("{} {:?}", dec.filename, dec.epoch),
+                api::DecryptOutcome::Failed(e) => format!("{:?}", e),
+                api::DecryptOutcome::Skipped => "skipped".to_string(),
+            })
+            .collect::<Vec<_>>()
+    };
+    camera.state().group_epoch = 2;
+    camera.state().file_epochs.insert("old".to_string(), 1);
+    camera.state().file_epochs.insert("future".to_string(), 7);
+    api::initialize_camera("batch".to_string(), "/tmp/batch".to_string(), false, None);
+
+    let outcomes = api::decrypt_videos_batch(
+        "batch".to_string(),
+        ["a", "old", "b", "future", "c"]
+            .map(str::to_string)
+            .to_vec(),
+        None,
+    );
+    assert_eq!(
+        describe(outcomes),
+        [
+            "video_a.mp4 Some(2)",
+            "EpochMismatch { expected: Some(2), found: Some(1) }",
+            "video_b.mp4 Some(2)",
+            "EpochMismatch { expected: Some(2), found: Some(7) }",
+            "skipped",
+        ]
+    );
+    // One reload for the file from the future, none for the stale one.
+    assert_eq!(camera.state().initialize_count, 2);
+
+    camera.fail("decrypt_thumbnail", "bad ciphertext");
+    let outcomes = api::decrypt_thumbnails_batch(
+        "batch".to_string(),
+        ["x", "y"].map(str::to_string).to_vec(),
+        "/tmp/meta".to_string(),
+        None,
+    );
+    assert_eq!(
+        describe(outcomes),
+        ["Failed(\"bad ciphertext\")", "Failed(\"bad ciphertext\")"]
+    );
+
+    api::deregister_camera("batch".to_string(), None);
+}
+
+#[test]
+fn metrics_count_calls_and_errors_per_camera() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+    camera.fail("decrypt_message", "bad message");
+    metrics::reset_metrics();
+
+    api::initialize_camera(
+        "metered".to_string(),
+        "/tmp/metered".to_string(),
+        false,
+        None,
+    );
+    api::get_group_name("motion".to_string(), "metered".to_string(), None);
+    api::decrypt_message("fcm".to_string(), "metered".to_string(), vec![1], None);
+    api::decrypt_message("fcm".to_string(), "metered".to_string(), vec![2], None);
+    api::get_group_name("motion".to_string(), "unmetered".to_string(), None);
+
+    let snapshot = metrics::get_metrics_snapshot();
+    let row = |function: &str, camera: &str| {
+        snapshot
+            .by_camera
+            .iter()
+            .find(|m| m.function == function && m.camera == camera)
+            .unwrap_or_else(|| panic!("no metrics for {} on {}", function, camera))
+    };
+    let decrypt = row("decrypt_message", "metered");
+    assert_eq!(decrypt.calls, 2);
+    assert_eq!(decrypt.errors.len(), 1);
+    assert_eq!(
+        (decrypt.errors[0].kind.as_str(), decrypt.errors[0].count),
+        ("failed", 2)
+    );
+    assert_eq!(decrypt.latency_counts.iter().sum::<u64>(), 2);
+    assert!(row("get_group_name", "metered").errors.is_empty());
+    assert_eq!(
+        row("get_group_name", "unmetered").errors[0].kind,
+        "not_initialized"
+    );
+
+    let group_name = snapshot
+        .by_function
+        .iter()
+        .find(|m| m.function == "get_group_name")
+        .unwrap();
+    assert_eq!((group_name.calls, group_name.camera.as_str()), (2, ""));
+
+    metrics::reset_metrics();
+    assert!(metrics::get_metrics_snapshot().by_camera.is_empty());
+
+    api::deregister_camera("metered".to_string(), None);
+    api::deregister_camera("unmetered".to_string(), None);
+}
+
+#[test]
+fn camera_events_follow_the_client_lifecycle() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+    camera_events::take_camera_events_for_tests();
+
+    api::initialize_camera(
+        "lifecycle|trace=ev-1".to_string(),
+        "/tmp/lifecycle".to_string(),
+        false,
+        None,
+    );
+    api::get_group_name("motion".to_string(), "lifecycle".to_string(), None);
+    camera.state().initialize_error = Some("corrupt group state".to_string());
+    assert!(api::reload_camera_client("lifecycle".to_string(), None).is_ok());
+    api::deregister_camera("lifecycle".to_string(), None);
+
+    let events = camera_events::take_camera_events_for_tests();
+    assert!(events.iter().all(|event| event.camera_name == "lifecycle"));
+    let kinds: Vec<_> = events.iter().map(|event| event.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            CameraEventKind::Registered,
+            CameraEventKind::ClientInitialized,
+            CameraEventKind::ClientInitFailed,
+            CameraEventKind::Deregistered,
+        ]
+    );
+    assert_eq!(events[0].trace_id.as_deref(), Some("ev-1"));
+    assert_eq!(events[1].trace_id, None);
+    assert_eq!(events[2].detail.as_deref(), Some("corrupt group state"));
+}
+
+#[test]
+fn busy_client_times_out() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+    api::initialize_camera("busy".to_string(), "/tmp/busy".to_string(), false, None);
+    camera_events::take_camera_events_for_tests();
+    // Initialize before slowing every call down.
+    api::encrypt_settings_message("busy".to_string(), vec![0], None);
+    api::set_client_lock_timeout_for_tests(Some(Duration::from_millis(100)));
+    camera.state().call_delay = Duration::from_millis(500);
+
+    let holder = thread::spawn(|| {
+        api::encrypt_settings_message("busy".to_string(), vec![1], None);
+    });
+    thread::sleep(Duration::from_millis(50));
+
+    let started = Instant::now();
+    assert_eq!(
+        api::decrypt_video("busy".to_string(), "enc".to_string(), 0, None),
+        "Error: Busy"
+    );
+    assert!(started.elapsed() < Duration::from_millis(400));
+    let timeouts: Vec<_> = camera_events::take_camera_events_for_tests()
+        .into_iter()
+        .filter(|event| event.kind == CameraEventKind::LockTimeout)
+        .collect();
+    assert_eq!(timeouts.len(), 1);
+    assert_eq!(timeouts[0].camera_name, "busy");
+    let detail = timeouts[0].detail.as_deref().unwrap();
+    assert!(
+        detail.starts_with("decrypt_video(motion) waited for "),
+        "{}",
+        detail
+    );
+
+    holder.join().unwrap();
+    api::set_client_lock_timeout_for_tests(None);
+    camera.state().call_delay = Duration::ZERO;
+    api::deregister_camera("busy".to_string(), None);
+}
+
+#[test]
+fn deregister_survives_backend_panic() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+    camera.state().panic_on_deregister = true;
+
+    api::initialize_camera(
+        "panicky".to_string(),
+        "/tmp/panicky".to_string(),
+        false,
+        None,
+    );
+    api::get_group_name("motion".to_string(), "panicky".to_string(), None);
+    api::deregister_camera("panicky".to_string(), None);
+
+    assert!(camera.calls().contains(&"deregister".to_string()));
+    // Init params were dropped even though the backend panicked.
+    assert_eq!(
+        api::decrypt_video("panicky".to_string(), "enc".to_string(), 0, None),
+        "Error"
+    );
+}
+
+#[test]
+fn deregister_drops_client_and_init_params() {
+    let _serial = SERIAL.lock();
+    let camera = fake_camera();
+
+    api::initialize_camera("gone".to_string(), "/tmp/gone".to_string(), false, None);
+    api::get_group_name("motion".to_string(), "gone".to_string(), None);
+    api::deregister_camera("gone".to_string(), None);
+
+    assert!(camera.state().deregistered);
+    assert_eq!(
+        api::get_group_name("motion".to_string(), "gone".to_string(), None),
+        "Error"
+    );
+}
+
+#[test]
+fn device_wait_reports_ready_timeout_and_cancel() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let ready = hotspot::new_device_waiter();
+    let outcome =
+        hotspot::wait_for_proprietary_device(addr.clone(), None, 2000, 10, 3, 20, ready.clone())
+            .unwrap();
+    assert_eq!(outcome.status, WaitStatus::Ready);
+    assert_eq!((outcome.attempts, outcome.stable_polls), (3, 3));
+    assert_eq!(outcome.probe_latencies_micros.len(), 3);
+    assert!(outcome.elapsed_millis >= 40);
+    // The id was used up by the wait.
+    assert!(!hotspot::cancel_device_wait(ready.clone()));
+    assert!(hotspot::wait_for_proprietary_device(addr, None, 100, 10, 1, 0, ready).is_err());
+
+    // Nothing listens on a port we just released.
+    let closed = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap().to_string()
+    };
+    let outcome = hotspot::wait_for_proprietary_device(
+        closed.clone(),
+        None,
+        200,
+        20,
+        1,
+        0,
+        hotspot::new_device_waiter(),
+    )
+    .unwrap();
+    assert_eq!(outcome.status, WaitStatus::TimedOut);
+    assert!(outcome.attempts >= 2);
+    assert_eq!(outcome.stable_polls, 0);
+    assert!(outcome.last_error.is_some());
+
+    let (first, second) = (hotspot::new_device_waiter(), hotspot::new_device_waiter());
+    assert_ne!(first, second);
+    let waiter = {
+        let (closed, second) = (closed.clone(), second.clone());
+        thread::spawn(move || {
+            hotspot::wait_for_proprietary_device(closed, None, 60_000, 20, 1, 0, second)
+        })
+    };
+    thread::sleep(Duration::from_millis(100));
+    assert!(hotspot::cancel_device_wait(second.clone()));
+    let outcome = waiter.join().unwrap().unwrap();
+    assert_eq!(outcome.status, WaitStatus::Cancelled);
+    assert!(outcome.elapsed_millis < 5_000);
+    assert!(!hotspot::cancel_device_wait(second));
+
+    // Cancelling before the wait starts makes it give up at once.
+    assert!(hotspot::cancel_device_wait(first.clone()));
+    let outcome =
+        hotspot::wait_for_proprietary_device(closed, None, 60_000, 20, 1, 0, first).unwrap();
+    assert_eq!(
+        (outcome.status, outcome.attempts),
+        (WaitStatus::Cancelled, 0)
+    );
+
+    assert!(hotspot::wait_for_proprietary_device(
+        "not an ip".to_string(),
+        None,
+        100,
+        10,
+        1,
+        0,
+        hotspot::new_device_waiter()
+    )
+    .is_err());
+}
+
+#[test]
+fn device_probe_accepts_ports_ipv6_and_hostnames() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let probe = |address: &str, port| hotspot::probe_proprietary_device(address.to_string(), port);
+
+    assert_eq!(probe("127.0.0.1", Some(port)), Ok(true));
+    assert_eq!(probe(&format!("127.0.0.1:{}", port), None), Ok(true));
+    assert_eq!(probe(&format!("127.0.0.1:{}", port), Some(port)), Ok(true));
+    assert_eq!(probe(&format!("localhost:{}", port), None), Ok(true));
+    assert!(api::ping_proprietary_device(format!("127.0.0.1:{}", port)));
+    if let Ok(v6) = std::net::TcpListener::bind("[::1]:0") {
+        let v6_port = v6.local_addr().unwrap().port();
+        assert_eq!(probe(&format!("[::1]:{}", v6_port), None), Ok(true));
+        assert_eq!(probe("::1", Some(v6_port)), Ok(true));
+    }
+    drop(listener);
+    assert_eq!(probe("127.0.0.1", Some(port)), Ok(false));
+
+    for bad in [
+        "",
+        "[::1",
+        "[127.0.0.1]:80",
+        "[::1]x",
+        "127.0.0.1:http",
+        "127.0.0.1:0",
+        "1:2:3",
+        "bad host!",
+        "-camera.local",
+    ] {
+        let result = probe(bad, None);
+        assert!(result.is_err(), "{:?} gave {:?}", bad, result);
+    }
+    assert!(probe("127.0.0.1:80", Some(81))
+        .unwrap_err()
+        .contains("port"));
+    assert!(!api::ping_proprietary_device("[::1".to_string()));
+}
@@ -0,0 +1,162 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Backup and restore of camera client state.
+
+use parking_lot::Mutex;
+use std::fs;
+use std::path::PathBuf;
+
+use rust_lib_secluso_flutter::api::{self, backup};
+use rust_lib_secluso_flutter::backend::fake::FakeCamera;
+
+static SERIAL: Mutex<()> = Mutex::new(());
+
+fn file_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "secluso-backup-{}-{}-{}",
+        name,
+        std::process::id(),
+        rand::random::<u32>()
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// The fake reports `epoch` as the group epoch of whatever state it loads.
+fn camera_with_state(camera_name: &str, epoch: u64) -> (FakeCamera, PathBuf) {
+    let camera = FakeCamera::new();
+    camera.install();
+    camera.state().group_epoch = epoch;
+    backup::set_kdf_cost_for_tests(8, 1);
+    let dir = file_dir(camera_name);
+    fs::write(dir.join("group_state"), b"mls group state").unwrap();
+    fs::create_dir_all(dir.join("keys")).unwrap();
+    fs::write(dir.join("keys").join("identity"), b"identity key").unwrap();
+    fs::create_dir_all(dir.join("videos")).unwrap();
+    fs::write(dir.join("videos").join("video_1.mp4"), b"media").unwrap();
+    api::initialize_camera(
+        camera_name.to_string(),
+        dir.to_string_lossy().into_owned(),
+        false,
+        None,
+    );
+    (camera, dir)
+}
+
+#[test]
+fn backup_round_trips_state_without_media() {
+    let _serial = SERIAL.lock();
+    let (_camera, source) = camera_with_state("roundtrip", 4);
+
+    let bytes =
+        backup::export_camera_backup("roundtrip".to_string(), "hunter2".to_string(), None).unwrap();
+    assert!(!bytes
+        .windows(b"mls group state".len())
+        .any(|w| w == b"mls group state"));
+
+    // State at the same epoch is replaced, files the backup lacks included;
+    // downloaded media is left alone.
+    let target = file_dir("roundtrip-target");
+    fs::write(target.join("group_state"), b"other group state").unwrap();
+    fs::write(target.join("stale_key"), b"stale").unwrap();
+    fs::create_dir_all(target.join("videos")).unwrap();
+    fs::write(target.join("videos").join("video_2.mp4"), b"media").unwrap();
+    let camera_name = backup::import_camera_backup(
+        bytes,
+        "hunter2".to_string(),
+        target.to_string_lossy().into_owned(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(camera_name, "roundtrip");
+    assert_eq!(
+        fs::read(target.join("group_state")).unwrap(),
+        b"mls group state"
+    );
+    assert_eq!(
+        fs::read(target.join("keys").join("identity")).unwrap(),
+        b"identity key"
+    );
+    assert!(!target.join("stale_key").exists());
+    assert!(!target.join("videos").join("video_1.mp4").exists());
+    assert!(target.join("videos").join("video_2.mp4").exists());
+
+    api::deregister_camera("roundtrip".to_string(), None);
+    let _ = fs::remove_dir_all(source);
+    let _ = fs::remove_dir_all(target);
+}
+
+#[test]
+fn backup_rejects_wrong_passphrase_tampering_and_newer_state() {
+    let _serial = SERIAL.lock();
+    let (camera, source) = camera_with_state("guarded", 4);
+    let bytes =
+        backup::export_camera_backup("guarded".to_string(), "correct".to_string(), None).unwrap();
+    let target = file_dir("guarded-target");
+    let target_str = target.to_string_lossy().into_owned();
+
+    assert!(backup::import_camera_backup(
+        bytes.clone(),
+        "wrong".to_string(),
+        target_str.clone(),
+        None
+    )
+    .is_err());
+    let mut tampered = bytes.clone();
+    let middle = tampered.len() / 2;
+    tampered[middle] ^= 1;
+    assert!(backup::import_camera_backup(
+        tampered,
+        "correct".to_string(),
+        target_str.clone(),
+        None
+    )
+    .is_err());
+    assert!(!target.join("group_state").exists());
+
+    // Existing state known to be newer than the backup is kept.
+    fs::write(target.join("group_state"), b"newer group state").unwrap();
+    camera.state().group_epoch = 9;
+    let err =
+        backup::import_camera_backup(bytes, "correct".to_string(), target_str, None).unwrap_err();
+    assert!(err.contains("epoch 9"), "{}", err);
+    assert_eq!(
+        fs::read(target.join("group_state")).unwrap(),
+        b"newer group state"
+    );
+
+    api::deregister_camera("guarded".to_string(), None);
+    let _ = fs::remove_dir_all(source);
+    let _ = fs::remove_dir_all(target);
+}
+
+#[test]
+fn backup_replaces_state_it_cannot_compare() {
+    let _serial = SERIAL.lock();
+    let (camera, source) = camera_with_state("unreadable", 4);
+    let bytes = backup::export_camera_backup("unreadable".to_string(), "correct".to_string(), None)
+        .unwrap();
+
+    // State that doesn't load has no epoch to compare, and is what a restore
+    // is for.
+    let target = file_dir("unreadable-target");
+    fs::write(target.join("group_state"), b"corrupt group state").unwrap();
+    camera.state().initialize_error = Some("corrupt group state".to_string());
+    let camera_name = backup::import_camera_backup(
+        bytes,
+        "correct".to_string(),
+        target.to_string_lossy().into_owned(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(camera_name, "unreadable");
+    assert_eq!(
+        fs::read(target.join("group_state")).unwrap(),
+        b"mls group state"
+    );
+
+    camera.state().initialize_error = None;
+    api::deregister_camera("unreadable".to_string(), None);
+    let _ = fs::remove_dir_all(source);
+    let _ = fs::remove_dir_all(target);
+}
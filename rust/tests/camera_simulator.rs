@@ -0,0 +1,936 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! End-to-end runs of the bridge API against a simulated camera on localhost.
+
+use parking_lot::Mutex;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use rust_lib_secluso_flutter::api;
+use rust_lib_secluso_flutter::api::camera_events::{self, CameraEventKind};
+use rust_lib_secluso_flutter::api::credentials::ServerCredentials;
+use rust_lib_secluso_flutter::api::livestream_recording;
+use rust_lib_secluso_flutter::api::livestream_session::{self, LivestreamError};
+use rust_lib_secluso_flutter::api::livestream_stats;
+use rust_lib_secluso_flutter::api::lock_manager;
+use rust_lib_secluso_flutter::api::notifications::{self, NotificationError, NotificationPayload};
+use rust_lib_secluso_flutter::api::pairing::{self, PairingPhase, PairingRequest};
+use rust_lib_secluso_flutter::api::push_queue;
+use rust_lib_secluso_flutter::backend::fake::{self, FakeCamera};
+use rust_lib_secluso_flutter::backend::simulator::SimulatedCamera;
+
+static SERIAL: Mutex<()> = Mutex::new(());
+
+// Kinds and details of the events recorded for `camera_name` since the last call.
+fn events_for(camera_name: &str) -> Vec<(CameraEventKind, Option<String>)> {
+    camera_events::take_camera_events_for_tests()
+        .into_iter()
+        .filter(|event| event.camera_name == camera_name)
+        .map(|event| (event.kind, event.detail))
+        .collect()
+}
+
+struct Setup {
+    camera: FakeCamera,
+    camera_name: String,
+    file_dir: PathBuf,
+    sim: SimulatedCamera,
+}
+
+impl Drop for Setup {
+    fn drop(&mut self) {
+        api::deregister_camera(self.camera_name.clone(), None);
+        let _ = fs::remove_dir_all(&self.file_dir);
+    }
+}
+
+fn file_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "secluso-sim-{}-{}-{}",
+        name,
+        std::process::id(),
+        rand::random::<u32>()
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn server_credentials() -> ServerCredentials {
+    ServerCredentials {
+        address: "https://relay.example.com:8000".to_string(),
+        username: "relayuser00001".to_string(),
+        password: "relaypass00001".to_string(),
+    }
+}
+
+fn add_camera(camera_name: &str, sim: &SimulatedCamera, secret: Vec<u8>) -> String {
+    api::flutter_add_camera(
+        camera_name.to_string(),
+        sim.addr().to_string(),
+        secret,
+        false,
+        "ssid".to_string(),
+        "wifi-password".to_string(),
+        "pairing-token".to_string(),
+        server_credentials(),
+        None,
+    )
+}
+
+fn paired(camera_name: &str) -> Setup {
+    let camera = FakeCamera::new();
+    camera.install();
+    let sim = SimulatedCamera::start().unwrap();
+    let file_dir = file_dir(camera_name);
+    api::initialize_camera(
+        camera_name.to_string(),
+        file_dir.to_string_lossy().into_owned(),
+        true,
+        None,
+    );
+    assert_eq!(
+        add_camera(camera_name, &sim, sim.secret()),
+        sim.firmware_version()
+    );
+    Setup {
+        camera,
+        camera_name: camera_name.to_string(),
+        file_dir,
+        sim,
+    }
+}
+
+#[test]
+fn pairing_hands_over_credentials() {
+    let _serial = SERIAL.lock();
+    let setup = paired("pairing");
+
+    assert!(setup.sim.is_paired());
+    assert_eq!(setup.sim.pairing_token().as_deref(), Some("pairing-token"));
+    assert_eq!(
+        setup.sim.credentials_full().as_deref(),
+        Some("SCRED1;14:relayuser0000114:relaypass0000130:https://relay.example.com:8000")
+    );
+    assert_eq!(
+        setup.sim.server_credentials(),
+        Some(Ok(server_credentials()))
+    );
+}
+
+#[test]
+fn pairing_checks_server_credentials() {
+    let _serial = SERIAL.lock();
+    FakeCamera::new().install();
+    let sim = SimulatedCamera::start().unwrap();
+    let dir = file_dir("credentials");
+    api::initialize_camera(
+        "credentials".to_string(),
+        dir.to_string_lossy().into_owned(),
+        true,
+        None,
+    );
+    let add = |credentials: ServerCredentials| {
+        api::flutter_add_camera(
+            "credentials".to_string(),
+            sim.addr().to_string(),
+            sim.secret(),
+            false,
+            "ssid".to_string(),
+            "wifi-password".to_string(),
+            "pairing-token".to_string(),
+            credentials,
+            None,
+        )
+    };
+    let credentials = server_credentials();
+
+    // Rejected before the camera is contacted.
+    for bad in [
+        ServerCredentials {
+            username: "user".to_string(),
+            ..credentials.clone()
+        },
+        ServerCredentials {
+            password: "relaypass\n0001".to_string(),
+            ..credentials.clone()
+        },
+        ServerCredentials {
+            password: "relaypäss0001".to_string(),
+            ..credentials.clone()
+        },
+        ServerCredentials {
+            address: "relay.example.com".to_string(),
+            ..credentials.clone()
+        },
+        ServerCredentials {
+            address: "https://".to_string(),
+            ..credentials.clone()
+        },
+    ] {
+        let result = add(bad);
+        assert!(result.starts_with("Error"), "{}", result);
+    }
+    // So is an address that doesn't resolve.
+    let result = api::flutter_add_camera(
+        "credentials".to_string(),
+        "not a camera".to_string(),
+        sim.secret(),
+        false,
+        String::new(),
+        String::new(),
+        "pairing-token".to_string(),
+        credentials.clone(),
+        None,
+    );
+    assert!(result.starts_with("Error"), "{}", result);
+    assert!(!sim.is_paired());
+
+    assert_eq!(add(credentials.clone()), sim.firmware_version());
+    assert_eq!(sim.server_credentials(), Some(Ok(credentials)));
+
+    api::deregister_camera("credentials".to_string(), None);
+    let _ = fs::remove_dir_all(dir);
+}
+
+fn pairing_request(camera_name: &str, sim: &SimulatedCamera) -> PairingRequest {
+    PairingRequest {
+        camera_name: camera_name.to_string(),
+        ip: sim.addr().to_string(),
+        secret: sim.secret(),
+        standalone: true,
+        ssid: "home-wifi".to_string(),
+        wifi_password: "wifi-password".to_string(),
+        pairing_token: "pairing-token".to_string(),
+        credentials: server_credentials(),
+    }
+}
+
+#[test]
+fn pairing_session_resumes_the_failed_phase_after_restart() {
+    let _serial = SERIAL.lock();
+    let camera = FakeCamera::new();
+    camera.install();
+    let sim = SimulatedCamera::start().unwrap();
+    let dir = file_dir("resumable");
+    api::initialize_camera(
+        "resumable".to_string(),
+        dir.to_string_lossy().into_owned(),
+        true,
+        None,
+    );
+    let step = |online| pairing::pairing_step("resumable".to_string(), online, None);
+
+    let session = pairing::begin_pairing(pairing_request("resumable", &sim), None).unwrap();
+    assert_eq!(session.phase, PairingPhase::Connecting);
+    // Secrets never reach the disk.
+    let saved = fs::read_to_string(dir.join("pairing_session.json")).unwrap();
+    for secret in ["wifi-password", "relaypass00001", "pairing-token"] {
+        assert!(!saved.contains(secret), "{}", saved);
+    }
+    assert_eq!(step(false).unwrap().phase, PairingPhase::KeyExchange);
+
+    // The hotspot drops during the key exchange, then the app restarts.
+    camera.fail("add_camera", "connection reset");
+    assert!(step(false).is_err());
+    pairing::forget_pairing_sessions_for_tests();
+    assert!(step(false).is_err());
+    let session = pairing::resume_pairing("resumable".to_string(), None, None).unwrap();
+    assert_eq!(session.phase, PairingPhase::KeyExchange);
+    assert_eq!(session.attempts, 1);
+    assert!(session.last_error.unwrap().contains("connection reset"));
+
+    // The key exchange can't go on until the request is given again.
+    camera.state().failures.clear();
+    assert!(step(false).unwrap_err().contains("without its request"));
+    let request = pairing_request("resumable", &sim);
+    let session = pairing::resume_pairing("resumable".to_string(), Some(request), None).unwrap();
+    assert_eq!(session.phase, PairingPhase::KeyExchange);
+    let session = step(false).unwrap();
+    assert_eq!(session.phase, PairingPhase::WifiProvisioning);
+    assert_eq!(
+        session.firmware_version.as_deref(),
+        Some(sim.firmware_version())
+    );
+    assert!(sim.is_paired());
+
+    assert_eq!(
+        step(false).unwrap().phase,
+        PairingPhase::WaitingForCameraOnline
+    );
+    assert_eq!(
+        step(false).unwrap().phase,
+        PairingPhase::WaitingForCameraOnline
+    );
+    assert_eq!(step(true).unwrap().phase, PairingPhase::Done);
+    assert!(!dir.join("pairing_session.json").exists());
+    assert!(step(true).is_err());
+
+    api::deregister_camera("resumable".to_string(), None);
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn cancelled_pairing_is_forgotten() {
+    let _serial = SERIAL.lock();
+    FakeCamera::new().install();
+    let sim = SimulatedCamera::start().unwrap();
+    let dir = file_dir("cancelled");
+    api::initialize_camera(
+        "cancelled".to_string(),
+        dir.to_string_lossy().into_owned(),
+        true,
+        None,
+    );
+
+    let mut request = pairing_request("cancelled", &sim);
+    request.credentials.address = "relay.example.com".to_string();
+    assert!(pairing::begin_pairing(request, None).is_err());
+
+    pairing::begin_pairing(pairing_request("cancelled", &sim), None).unwrap();
+    assert!(dir.join("pairing_session.json").exists());
+    let session = pairing::cancel_pairing("cancelled".to_string(), None).unwrap();
+    assert_eq!(session.phase, PairingPhase::Cancelled);
+    assert!(!dir.join("pairing_session.json").exists());
+    assert!(pairing::resume_pairing("cancelled".to_string(), None, None).is_err());
+    assert!(!sim.is_paired());
+
+    api::deregister_camera("cancelled".to_string(), None);
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn sealed_payloads_fail_with_the_wrong_key_or_tampering() {
+    let sealed = fake::seal(b"key", 7, b"payload");
+    assert_eq!(
+        fake::open(b"key", &sealed).unwrap(),
+        (7, b"payload".to_vec())
+    );
+    assert!(fake::open(b"other key", &sealed).is_err());
+    assert!(fake::open(b"key", &sealed[..sealed.len() - 1]).is_err());
+    let mut tampered = sealed.clone();
+    tampered[0] ^= 1;
+    assert!(fake::open(b"key", &tampered).is_err());
+
+    // Any key works, including none.
+    let sealed = fake::seal(b"", 0, b"");
+    assert_eq!(fake::open(b"", &sealed).unwrap(), (0, Vec::new()));
+}
+
+#[test]
+fn pairing_with_wrong_secret_fails() {
+    let _serial = SERIAL.lock();
+    FakeCamera::new().install();
+    let sim = SimulatedCamera::start().unwrap();
+    let dir = file_dir("wrong-secret");
+    api::initialize_camera(
+        "wrong-secret".to_string(),
+        dir.to_string_lossy().into_owned(),
+        true,
+        None,
+    );
+
+    events_for("wrong-secret");
+    let result = add_camera("wrong-secret", &sim, vec![0; 16]);
+    assert!(result.starts_with("Error"), "{}", result);
+    assert!(!sim.is_paired());
+    assert_eq!(
+        events_for("wrong-secret").last(),
+        Some(&(CameraEventKind::PairingFailed, Some(result)))
+    );
+
+    api::deregister_camera("wrong-secret".to_string(), None);
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn motion_video_and_thumbnail_decrypt() {
+    let _serial = SERIAL.lock();
+    let setup = paired("motion");
+
+    let enc = setup
+        .sim
+        .record_motion(&setup.file_dir, 2, b"fake mp4 bytes")
+        .unwrap();
+    let dec = api::decrypt_video(setup.camera_name.clone(), enc, 1, None);
+    assert_eq!(dec, "video_2.mp4");
+    assert_eq!(
+        fs::read(setup.file_dir.join("videos").join(&dec)).unwrap(),
+        b"fake mp4 bytes"
+    );
+
+    let enc = setup
+        .sim
+        .record_thumbnail(&setup.file_dir, 2, b"fake png bytes")
+        .unwrap();
+    let dec = api::decrypt_thumbnail(
+        setup.camera_name.clone(),
+        enc,
+        setup.file_dir.to_string_lossy().into_owned(),
+        1,
+        None,
+    );
+    assert_eq!(dec, "thumbnail_2.png");
+}
+
+#[test]
+fn notifications_decode_to_typed_payloads() {
+    let _serial = SERIAL.lock();
+    let setup = paired("notify");
+    let decrypt = |counter: u64, payload: &[u8]| {
+        notifications::decrypt_notification(
+            setup.camera_name.clone(),
+            "fcm".to_string(),
+            setup.sim.notification(counter, payload).unwrap(),
+            None,
+        )
+    };
+
+    assert_eq!(
+        decrypt(1, b"1760000000"),
+        Ok(NotificationPayload::Motion {
+            timestamp: "1760000000".to_string()
+        })
+    );
+    assert_eq!(decrypt(2, b"Download"), Ok(NotificationPayload::VideoReady));
+    assert_eq!(decrypt(3, b"None"), Ok(NotificationPayload::Empty));
+    assert_eq!(
+        decrypt(4, br#"{"type":"firmware_update"}"#),
+        Ok(NotificationPayload::Typed {
+            kind: "firmware_update".to_string(),
+            json: r#"{"type":"firmware_update"}"#.to_string(),
+        })
+    );
+    assert_eq!(
+        decrypt(5, br#"{"status":"low_battery"}"#),
+        Ok(NotificationPayload::Unknown {
+            raw: r#"{"status":"low_battery"}"#.to_string()
+        })
+    );
+
+    // FCM redelivering a message is reported as such, not as a decrypt failure.
+    assert_eq!(
+        decrypt(1, b"1760000000"),
+        Err(NotificationError::DuplicateMessage)
+    );
+    assert!(matches!(
+        notifications::decrypt_notification(
+            setup.camera_name.clone(),
+            "fcm".to_string(),
+            vec![0; 20],
+            None,
+        ),
+        Err(NotificationError::Failed(_))
+    ));
+
+    api::deregister_camera(setup.camera_name.clone(), None);
+    let _ = fs::remove_dir_all(&setup.file_dir);
+}
+
+#[test]
+fn push_queue_dedups_orders_and_retries() {
+    let _serial = SERIAL.lock();
+    let setup = paired("push-queue");
+    let camera = || setup.camera_name.clone();
+    let enqueue = |data: Vec<u8>| {
+        push_queue::enqueue_push_message(camera(), "fcm".to_string(), data, None, None)
+    };
+    let motion = |counter: u64| {
+        setup
+            .sim
+            .notification(counter, counter.to_string().as_bytes())
+            .unwrap()
+    };
+
+    // Arrive out of order, with one redelivery.
+    assert_eq!(enqueue(motion(12)), Ok(true));
+    assert_eq!(enqueue(motion(10)), Ok(true));
+    assert_eq!(enqueue(motion(12)), Ok(false));
+    assert_eq!(enqueue(motion(11)), Ok(true));
+    assert_eq!(enqueue(vec![0; 20]), Ok(true));
+    let queue_dir = setup.file_dir.join("push_queue");
+    let stored = fs::read_dir(&queue_dir)
+        .unwrap()
+        .filter(|e| e.as_ref().unwrap().path().extension() == Some("msg".as_ref()))
+        .count();
+    assert_eq!(stored, 4);
+
+    let report = push_queue::drain_push_messages(camera(), None).unwrap();
+    let timestamps: Vec<_> = report.messages.iter().map(|m| m.payload.clone()).collect();
+    assert_eq!(
+        timestamps,
+        [10, 11, 12].map(|timestamp| NotificationPayload::Motion {
+            timestamp: timestamp.to_string()
+        })
+    );
+    assert_eq!(report.messages[0].generation, Some(10));
+    assert_eq!(
+        (report.duplicates, report.dropped, report.remaining),
+        (0, 0, 1)
+    );
+    assert!(!report.busy);
+
+    // Already decrypted once, e.g. delivered again after the drain.
+    assert_eq!(enqueue(motion(11)), Ok(true));
+    let report = push_queue::drain_push_messages(camera(), None).unwrap();
+    assert!(report.messages.is_empty());
+    assert_eq!((report.duplicates, report.remaining), (1, 1));
+
+    // The undecryptable message is given up on after its third failed drain.
+    let report = push_queue::drain_push_messages(camera(), None).unwrap();
+    assert_eq!((report.dropped, report.remaining), (1, 0));
+    let report = push_queue::drain_push_messages(camera(), None).unwrap();
+    assert_eq!((report.dropped, report.remaining), (0, 0));
+
+    api::deregister_camera(camera(), None);
+    let _ = fs::remove_dir_all(&setup.file_dir);
+}
+
+#[test]
+fn push_queue_orders_by_sent_time_without_generations() {
+    let _serial = SERIAL.lock();
+    let setup = paired("push-queue-sent");
+    setup.camera.state().hide_generation = true;
+    let camera = || setup.camera_name.clone();
+    let enqueue = |counter: u64, sent_at: i64| {
+        let data = setup
+            .sim
+            .notification(counter, counter.to_string().as_bytes())
+            .unwrap();
+        push_queue::enqueue_push_message(camera(), "fcm".to_string(), data, Some(sent_at), None)
+    };
+
+    assert_eq!(enqueue(3, 3000), Ok(true));
+    assert_eq!(enqueue(1, 1000), Ok(true));
+    assert_eq!(enqueue(2, 2000), Ok(true));
+    let report = push_queue::drain_push_messages(camera(), None).unwrap();
+    let order: Vec<_> = report
+        .messages
+        .iter()
+        .map(|m| (m.payload.clone(), m.sent_at_millis, m.generation))
+        .collect();
+    assert_eq!(
+        order,
+        [1, 2, 3].map(|n| (
+            NotificationPayload::Motion {
+                timestamp: n.to_string()
+            },
+            Some(n as i64 * 1000),
+            None
+        ))
+    );
+
+    // A stuck holder of the queue lock makes enqueues fail instead of hang.
+    let lock_path = setup.file_dir.join("push_queue").join(".lock");
+    let holder =
+        lock_manager::acquire_lock_as(lock_path.to_string_lossy().into_owned(), None).unwrap();
+    assert_eq!(enqueue(4, 4000), Err("push queue is busy".to_string()));
+    drop(holder);
+    assert_eq!(enqueue(4, 4000), Ok(true));
+
+    api::deregister_camera(camera(), None);
+    let _ = fs::remove_dir_all(&setup.file_dir);
+}
+
+#[test]
+fn livestream_chunks_decrypt_in_order() {
+    let _serial = SERIAL.lock();
+    let setup = paired("livestream");
+
+    assert!(api::livestream_update(
+        setup.camera_name.clone(),
+        vec![],
+        None
+    ));
+    for chunk_number in 0..3u64 {
+        let payload = format!("chunk {}", chunk_number).into_bytes();
+        let chunk = setup.sim.livestream_chunk(chunk_number, &payload).unwrap();
+        assert_eq!(
+            api::livestream_decrypt(setup.camera_name.clone(), chunk, chunk_number, None),
+            payload
+        );
+    }
+
+    let late = setup.sim.livestream_chunk(7, b"late").unwrap();
+    assert!(api::livestream_decrypt(setup.camera_name.clone(), late, 3, None).is_empty());
+}
+
+#[test]
+fn livestream_sessions_open_update_and_close() {
+    let _serial = SERIAL.lock();
+    let setup = paired("livestream-session");
+    let camera = || setup.camera_name.clone();
+    let chunk = |n: u64| setup.sim.livestream_chunk(n, b"frame").unwrap();
+
+    let session = livestream_session::open_livestream_session(camera(), false, None).unwrap();
+    let id = session.session_id;
+    assert!(!session.key_updated);
+    assert_eq!(
+        livestream_session::current_livestream_session(camera(), None),
+        Some(session)
+    );
+    // A second viewer cannot open its own session on top.
+    assert_eq!(
+        livestream_session::open_livestream_session(camera(), false, None),
+        Err(LivestreamError::AlreadyOpen { session_id: id })
+    );
+    // Nor can the legacy functions act on the client underneath it.
+    assert!(!api::livestream_update(camera(), vec![], None));
+    assert!(api::livestream_decrypt(camera(), chunk(1), 1, None).is_empty());
+
+    // Chunks wait for the key update.
+    assert_eq!(
+        livestream_session::livestream_session_decrypt(camera(), id, chunk(1), 1, None),
+        Err(LivestreamError::AwaitingKeyUpdate)
+    );
+    assert_eq!(
+        livestream_session::livestream_session_update(camera(), id + 1, vec![], None),
+        Err(LivestreamError::SessionMismatch {
+            expected: id,
+            got: id + 1
+        })
+    );
+    livestream_session::livestream_session_update(camera(), id, vec![], None).unwrap();
+    for n in 1..=2 {
+        assert_eq!(
+            livestream_session::livestream_session_decrypt(camera(), id, chunk(n), n, None)
+                .unwrap(),
+            b"frame"
+        );
+    }
+
+    let initialized = setup.camera.state().initialize_count;
+    let closed = livestream_session::close_livestream_session(camera(), id, None).unwrap();
+    assert!(closed.key_updated);
+    assert_eq!(closed.chunks_decrypted, 2);
+    assert_eq!(
+        livestream_session::current_livestream_session(camera(), None),
+        None
+    );
+    assert_eq!(
+        livestream_session::livestream_session_decrypt(camera(), id, chunk(3), 3, None),
+        Err(LivestreamError::SessionClosed { session_id: id })
+    );
+    assert_eq!(
+        livestream_session::livestream_session_update(camera(), id, vec![], None),
+        Err(LivestreamError::SessionClosed { session_id: id })
+    );
+    assert_eq!(
+        livestream_session::close_livestream_session(camera(), id, None),
+        Err(LivestreamError::SessionClosed { session_id: id })
+    );
+    // Closing dropped the client's livestream state; the legacy functions,
+    // free to run again, start from a client reloaded from disk.
+    assert!(api::livestream_update(camera(), vec![], None));
+    assert_eq!(setup.camera.state().initialize_count, initialized + 1);
+
+    // A second viewer may take over, which closes the first viewer's session.
+    let first = livestream_session::open_livestream_session(camera(), false, None).unwrap();
+    let second = livestream_session::open_livestream_session(camera(), true, None).unwrap();
+    assert_eq!(
+        livestream_session::livestream_session_update(camera(), first.session_id, vec![], None),
+        Err(LivestreamError::SessionClosed {
+            session_id: first.session_id
+        })
+    );
+    livestream_session::livestream_session_update(camera(), second.session_id, vec![], None)
+        .unwrap();
+    assert_eq!(setup.camera.state().initialize_count, initialized + 2);
+
+    // A session left idle expires.
+    livestream_session::set_session_idle_timeout_for_tests(Some(Duration::ZERO));
+    assert_eq!(
+        livestream_session::current_livestream_session(camera(), None),
+        None
+    );
+    livestream_session::set_session_idle_timeout_for_tests(None);
+    assert_eq!(
+        livestream_session::livestream_session_decrypt(
+            camera(),
+            second.session_id,
+            chunk(1),
+            1,
+            None
+        ),
+        Err(LivestreamError::SessionClosed {
+            session_id: second.session_id
+        })
+    );
+
+    // Deregistering drops whatever session is open.
+    let reopened = livestream_session::open_livestream_session(camera(), false, None).unwrap();
+    assert!(reopened.session_id > second.session_id);
+    api::deregister_camera(camera(), None);
+    assert_eq!(
+        livestream_session::current_livestream_session(camera(), None),
+        None
+    );
+    assert_eq!(
+        livestream_session::livestream_session_update(camera(), reopened.session_id, vec![], None),
+        Err(LivestreamError::NoSession {
+            session_id: reopened.session_id
+        })
+    );
+}
+
+#[test]
+fn livestream_stats_track_the_session() {
+    let _serial = SERIAL.lock();
+    let setup = paired("livestream-stats");
+    let camera = || setup.camera_name.clone();
+
+    let stats = livestream_stats::livestream_stats(camera(), None);
+    assert_eq!(stats.session_started_at_millis, None);
+    assert_eq!(stats.chunks_received, 0);
+
+    assert!(api::livestream_update(camera(), vec![], None));
+    for chunk_number in [1u64, 2, 4] {
+        let chunk = setup
+            .sim
+            .livestream_chunk(chunk_number, b"0123456789")
+            .unwrap();
+        assert!(!api::livestream_decrypt(camera(), chunk, chunk_number, None).is_empty());
+    }
+    let late = setup.sim.livestream_chunk(9, b"late").unwrap();
+    assert!(api::livestream_decrypt(camera(), late, 5, None).is_empty());
+
+    let stats = livestream_stats::livestream_stats(camera(), None);
+    assert!(stats.session_started_at_millis.is_some());
+    assert_eq!(
+        (
+            stats.chunks_received,
+            stats.chunks_decrypted,
+            stats.chunks_failed,
+            stats.chunks_out_of_order
+        ),
+        (4, 3, 1, 1)
+    );
+    assert_eq!(stats.bytes_decrypted, 30);
+    assert!(stats.bytes_received > stats.bytes_decrypted);
+    assert_eq!(stats.last_chunk_number, Some(4));
+    assert!(stats.decrypt_p50_micros <= stats.decrypt_p99_micros);
+    assert!(stats.decrypt_p99_micros <= stats.decrypt_max_micros);
+    assert_eq!(stats.lock_timeouts, 0);
+
+    // A new session starts from scratch.
+    assert!(api::livestream_update(camera(), vec![], None));
+    let stats = livestream_stats::livestream_stats(camera(), None);
+    assert_eq!((stats.chunks_received, stats.last_chunk_number), (0, None));
+}
+
+fn mp4_box(kind: &[u8; 4], parts: &[&[u8]]) -> Vec<u8> {
+    let body = parts.concat();
+    [&((body.len() + 8) as u32).to_be_bytes()[..], kind, &body].concat()
+}
+
+// ftyp + moov for one video track, with every duration left at zero.
+fn init_segment(movie_timescale: u32, media_timescale: u32) -> Vec<u8> {
+    let full = [0u8; 4];
+    let times = [0u8; 8];
+    let zero = 0u32.to_be_bytes();
+    let mvhd = mp4_box(
+        b"mvhd",
+        &[
+            &full,
+            &times,
+            &movie_timescale.to_be_bytes(),
+            &zero,
+            &[0; 80],
+        ],
+    );
+    let tkhd = mp4_box(
+        b"tkhd",
+        &[&full, &times, &1u32.to_be_bytes(), &zero, &zero, &[0; 60]],
+    );
+    let mdhd = mp4_box(
+        b"mdhd",
+        &[&full, &times, &media_timescale.to_be_bytes(), &zero, &zero],
+    );
+    let trak = mp4_box(b"trak", &[&tkhd, &mp4_box(b"mdia", &[&mdhd])]);
+    let mehd = mp4_box(b"mehd", &[&full, &zero]);
+    let trex = mp4_box(b"trex", &[&full, &1u32.to_be_bytes(), &[0; 16]]);
+    let mvex = mp4_box(b"mvex", &[&mehd, &trex]);
+    [
+        mp4_box(b"ftyp", &[b"isom", &zero, b"isom"]),
+        mp4_box(b"moov", &[&mvhd, &trak, &mvex]),
+    ]
+    .concat()
+}
+
+// moof + mdat holding `samples` four-byte samples of `sample_duration` ticks.
+fn fragment(sequence: u32, samples: u32, sample_duration: u32) -> Vec<u8> {
+    let full = [0u8; 4];
+    let tfhd = mp4_box(b"tfhd", &[&full, &1u32.to_be_bytes()]);
+    let tfdt = mp4_box(
+        b"tfdt",
+        &[&full, &(sequence * samples * sample_duration).to_be_bytes()],
+    );
+    let per_sample: Vec<u8> = (0..samples)
+        .flat_map(|_| [sample_duration.to_be_bytes(), 4u32.to_be_bytes()].concat())
+        .collect();
+    // Sample durations and sizes present.
+    let trun = mp4_box(
+        b"trun",
+        &[
+            &0x0000_0300u32.to_be_bytes(),
+            &samples.to_be_bytes(),
+            &per_sample,
+        ],
+    );
+    let traf = mp4_box(b"traf", &[&tfhd, &tfdt, &trun]);
+    let mfhd = mp4_box(b"mfhd", &[&full, &sequence.to_be_bytes()]);
+    [
+        mp4_box(b"moof", &[&mfhd, &traf]),
+        mp4_box(b"mdat", &[&vec![0xAB; samples as usize * 4]]),
+    ]
+    .concat()
+}
+
+#[test]
+fn livestream_recording_writes_a_finalized_mp4() {
+    let _serial = SERIAL.lock();
+    let setup = paired("recording");
+    let camera = || setup.camera_name.clone();
+    let out = setup.file_dir.join("clip.mp4");
+    let out_path = || out.to_string_lossy().into_owned();
+
+    assert!(livestream_recording::stop_livestream_recording(camera(), None).is_err());
+    assert!(api::livestream_update(camera(), vec![], None));
+    let init = init_segment(90000, 90000);
+    let fragments: Vec<Vec<u8>> = (0..3).map(|seq| fragment(seq, 3, 3000)).collect();
+    let mut chunk_number = 0;
+    let mut send = |payload: &[u8]| {
+        let chunk = setup.sim.livestream_chunk(chunk_number, payload).unwrap();
+        assert_eq!(
+            api::livestream_decrypt(camera(), chunk, chunk_number, None),
+            payload
+        );
+        chunk_number += 1;
+    };
+
+    // The recording starts mid-stream, after the init segment went by.
+    send(&init);
+    send(&fragments[0]);
+    livestream_recording::start_livestream_recording(camera(), out_path(), None).unwrap();
+    assert!(livestream_recording::start_livestream_recording(camera(), out_path(), None).is_err());
+    // A fragment split across chunks is reassembled.
+    let (head, tail) = fragments[1].split_at(fragments[1].len() / 2);
+    send(head);
+    send(tail);
+    send(&fragments[2]);
+
+    let summary = livestream_recording::stop_livestream_recording(camera(), None).unwrap();
+    assert_eq!(summary.path, out_path());
+    assert_eq!((summary.fragments, summary.frames), (2, 6));
+    assert_eq!(summary.duration_millis, 200);
+
+    let written = fs::read(&out).unwrap();
+    assert_eq!(summary.bytes, written.len() as u64);
+    assert_eq!(
+        written.len(),
+        init.len() + fragments[1].len() + fragments[2].len()
+    );
+    assert_eq!(
+        &written[init.len()..],
+        [&fragments[1][..], &fragments[2]].concat()
+    );
+    // mvhd sits right after the ftyp and moov headers; its duration follows
+    // version/flags, two timestamps and the timescale. Like mp4_fix.dart, it
+    // holds the 90 kHz tick total.
+    let ftyp_len = 20;
+    let mvhd_duration_at = ftyp_len + 8 + 8 + 16;
+    assert_eq!(
+        written[mvhd_duration_at..mvhd_duration_at + 4],
+        18000u32.to_be_bytes()
+    );
+    assert!(livestream_recording::stop_livestream_recording(camera(), None).is_err());
+
+    // Nothing playable arrived: the file is removed.
+    livestream_recording::start_livestream_recording(camera(), out_path(), None).unwrap();
+    assert!(livestream_recording::stop_livestream_recording(camera(), None).is_err());
+    assert!(!out.exists());
+}
+
+#[test]
+fn livestream_recording_survives_garbage_and_ends_at_a_restart() {
+    let _serial = SERIAL.lock();
+    let setup = paired("recording-restart");
+    let camera = || setup.camera_name.clone();
+    let out = setup.file_dir.join("restart.mp4");
+    let out_path = out.to_string_lossy().into_owned();
+
+    assert!(api::livestream_update(camera(), vec![], None));
+    let init = init_segment(90000, 90000);
+    let fragments: Vec<Vec<u8>> = (0..4).map(|seq| fragment(seq, 2, 4500)).collect();
+    let mut chunk_number = 0;
+    let mut send = |payload: &[u8]| {
+        let chunk = setup.sim.livestream_chunk(chunk_number, payload).unwrap();
+        assert_eq!(
+            api::livestream_decrypt(camera(), chunk, chunk_number, None),
+            payload
+        );
+        chunk_number += 1;
+    };
+
+    send(&init);
+    livestream_recording::start_livestream_recording(camera(), out_path.clone(), None).unwrap();
+    send(&fragments[0]);
+    // A box claiming to be smaller than its header loses the stream until
+    // the next chunk that starts a fragment.
+    send(&[0, 0, 0, 4, b'j', b'u', b'n', b'k', 1, 2, 3]);
+    send(&fragments[1]);
+    send(&fragments[2]);
+    // A new init segment ends the recording; later fragments stay out.
+    send(&init);
+    send(&fragments[3]);
+
+    let summary = livestream_recording::stop_livestream_recording(camera(), None).unwrap();
+    assert_eq!((summary.fragments, summary.frames), (3, 6));
+    assert_eq!(summary.duration_millis, 300);
+    let written = fs::read(&out).unwrap();
+    assert_eq!(written.len() as u64, summary.bytes);
+    assert_eq!(&written[init.len()..], fragments[..3].concat());
+}
+
+#[test]
+fn heartbeat_round_trip() {
+    let _serial = SERIAL.lock();
+    events_for("heartbeat");
+    let setup = paired("heartbeat");
+    let paired_event = (
+        CameraEventKind::Paired,
+        Some(setup.sim.firmware_version().to_string()),
+    );
+    assert!(events_for("heartbeat").contains(&paired_event));
+
+    let request =
+        api::generate_heartbeat_request_config_command(setup.camera_name.clone(), 1234, None);
+    assert!(!request.is_empty());
+    let response = setup.sim.answer_heartbeat(&request).unwrap();
+
+    assert_eq!(
+        api::process_heartbeat_config_response(
+            setup.camera_name.clone(),
+            response.clone(),
+            1234,
+            None
+        ),
+        format!("healthy_{}", setup.sim.firmware_version())
+    );
+    let failed =
+        api::process_heartbeat_config_response(setup.camera_name.clone(), response, 999, None);
+    assert!(failed.starts_with("Error"));
+    // After pairing the client reloads lazily, reported before the heartbeats.
+    let events = events_for("heartbeat");
+    assert_eq!(events[0].0, CameraEventKind::ClientInitialized);
+    assert_eq!(
+        events[1..],
+        [
+            (
+                CameraEventKind::HeartbeatHealthy,
+                Some(setup.sim.firmware_version().to_string())
+            ),
+            (CameraEventKind::HeartbeatFailed, Some(failed)),
+        ]
+    );
+}
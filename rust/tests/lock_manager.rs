@@ -0,0 +1,176 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! File locks shared between isolates and processes.
+
+use std::thread;
+use std::time::Duration;
+
+use rust_lib_secluso_flutter::api::lock_manager;
+
+fn lock_path(name: &str) -> String {
+    let dir = std::env::temp_dir().join(format!(
+        "secluso-locks-{}-{}-{}",
+        name,
+        std::process::id(),
+        rand::random::<u32>()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join("lock").to_string_lossy().into_owned()
+}
+
+#[test]
+fn lock_wait_resolves_once_the_holder_releases() {
+    let path = lock_path("wait");
+    let holder = lock_manager::acquire_lock_as(path.clone(), Some("holder".to_string())).unwrap();
+
+    let waiter_id = lock_manager::new_lock_waiter();
+    let waiter = {
+        let (path, waiter_id) = (path.clone(), waiter_id.clone());
+        thread::spawn(move || {
+            lock_manager::wait_for_lock(path, 5000, Some("waiter".to_string()), waiter_id)
+        })
+    };
+    thread::sleep(Duration::from_millis(100));
+    assert!(!waiter.is_finished());
+    assert!(lock_manager::release_lock_handle(holder));
+
+    let handle = waiter.join().unwrap().unwrap();
+    let owner = lock_manager::lock_owner_info(path.clone())
+        .unwrap()
+        .unwrap();
+    assert_eq!(owner.owner, "waiter");
+    // The id was used up by the wait.
+    assert!(!lock_manager::cancel_lock_wait(waiter_id.clone()));
+    assert!(lock_manager::wait_for_lock(path, 0, None, waiter_id).is_err());
+    assert!(lock_manager::release_lock_handle(handle));
+}
+
+#[test]
+fn lock_wait_times_out_or_is_cancelled() {
+    let path = lock_path("cancel");
+    let holder = lock_manager::acquire_lock_as(path.clone(), None).unwrap();
+
+    let err = lock_manager::wait_for_lock(path.clone(), 100, None, lock_manager::new_lock_waiter())
+        .unwrap_err();
+    assert!(err.starts_with("Timeout"), "{}", err);
+
+    let (first, second) = (
+        lock_manager::new_lock_waiter(),
+        lock_manager::new_lock_waiter(),
+    );
+    assert_ne!(first, second);
+    let waiter = {
+        let (path, second) = (path.clone(), second.clone());
+        thread::spawn(move || lock_manager::wait_for_lock(path, 5000, None, second))
+    };
+    thread::sleep(Duration::from_millis(50));
+    assert!(lock_manager::cancel_lock_wait(second));
+    assert_eq!(waiter.join().unwrap().unwrap_err(), "Cancelled");
+
+    // Cancelling before the wait starts makes it give up at once.
+    assert!(lock_manager::cancel_lock_wait(first.clone()));
+    assert_eq!(
+        lock_manager::wait_for_lock(path, 5000, None, first).unwrap_err(),
+        "Cancelled"
+    );
+
+    assert!(lock_manager::release_lock_handle(holder));
+}
+
+fn handles_on(path: &str) -> Vec<(String, bool)> {
+    lock_manager::list_held_locks()
+        .into_iter()
+        .filter(|info| info.path == path)
+        .map(|info| (info.owner, info.shared))
+        .collect()
+}
+
+#[test]
+fn an_owner_can_relock_what_it_holds() {
+    let path = lock_path("reenter");
+    let owner = |name: &str| Some(name.to_string());
+    let first = lock_manager::acquire_lock_as(path.clone(), owner("isolate-a")).unwrap();
+    let second = lock_manager::try_acquire_lock_as(path.clone(), owner("isolate-a"))
+        .unwrap()
+        .unwrap();
+    let shared = lock_manager::try_acquire_shared_lock(path.clone(), owner("isolate-a"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(handles_on(&path).len(), 3);
+
+    // Other owners, and callers that give none, are excluded as before.
+    assert!(
+        lock_manager::try_acquire_lock_as(path.clone(), owner("isolate-b"))
+            .unwrap()
+            .is_none()
+    );
+    assert!(lock_manager::try_acquire_lock_as(path.clone(), None)
+        .unwrap()
+        .is_none());
+
+    // Released handle by handle, and only by the last one.
+    assert!(lock_manager::release_lock_handle(first));
+    drop(shared);
+    assert_eq!(handles_on(&path), [("isolate-a".to_string(), false)]);
+    assert!(
+        lock_manager::try_acquire_lock_as(path.clone(), owner("isolate-b"))
+            .unwrap()
+            .is_none()
+    );
+    assert!(lock_manager::release_lock_handle(second));
+    assert!(!lock_manager::is_lock_held(path.clone()).unwrap());
+    assert!(lock_manager::lock_owner_info(path.clone())
+        .unwrap()
+        .is_none());
+
+    let other = lock_manager::try_acquire_lock_as(path.clone(), owner("isolate-b")).unwrap();
+    assert!(other.is_some());
+}
+
+#[test]
+fn shared_locks_coexist_and_keep_writers_out() {
+    let path = lock_path("shared");
+    let reader =
+        lock_manager::acquire_shared_lock(path.clone(), Some("reader".to_string())).unwrap();
+    let other_reader = lock_manager::try_acquire_shared_lock(path.clone(), None)
+        .unwrap()
+        .unwrap();
+    assert!(
+        lock_manager::try_acquire_lock_as(path.clone(), Some("writer".to_string()))
+            .unwrap()
+            .is_none()
+    );
+    let holder = lock_manager::lock_owner_info(path.clone())
+        .unwrap()
+        .unwrap();
+    assert!(holder.shared);
+    assert_eq!(holder.pid, std::process::id());
+
+    drop(reader);
+    drop(other_reader);
+    let writer = lock_manager::try_acquire_lock_as(path.clone(), Some("writer".to_string()))
+        .unwrap()
+        .unwrap();
+    let holder = lock_manager::lock_owner_info(path.clone())
+        .unwrap()
+        .unwrap();
+    assert_eq!((holder.owner.as_str(), holder.shared), ("writer", false));
+    assert!(lock_manager::release_lock_handle(writer));
+}
+
+#[test]
+fn release_by_path_leaves_handles_alone() {
+    let path = lock_path("by-path");
+    let handle = lock_manager::acquire_lock_as(path.clone(), Some("isolate".to_string())).unwrap();
+    // The path API won't touch a path held through handles.
+    assert!(lock_manager::try_acquire_lock(path.clone()).is_err());
+    assert!(lock_manager::acquire_lock(path.clone()).is_err());
+    lock_manager::release_lock(path.clone()).unwrap();
+    assert_eq!(handles_on(&path).len(), 1);
+
+    assert!(lock_manager::release_lock_handle(handle));
+    assert!(lock_manager::try_acquire_lock(path.clone()).unwrap());
+    assert!(!lock_manager::try_acquire_lock(path.clone()).unwrap());
+    lock_manager::release_lock(path.clone()).unwrap();
+    assert!(!lock_manager::is_lock_held(path).unwrap());
+}
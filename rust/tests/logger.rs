@@ -0,0 +1,115 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Log entries kept for the Dart log viewer.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::LevelFilter;
+use parking_lot::{Mutex, MutexGuard};
+use rust_lib_secluso_flutter::api;
+use rust_lib_secluso_flutter::api::logger::{self, LogEntry};
+
+// The logger and its buffer are process-wide, so tests take turns. It is
+// installed once, at info level.
+static SERIAL: Mutex<()> = Mutex::new(());
+
+fn init() -> MutexGuard<'static, ()> {
+    logger::init_buffered_logger(LevelFilter::Info);
+    SERIAL.lock()
+}
+
+#[test]
+fn spans_are_not_timed_above_debug_level() {
+    let _serial = init();
+    tracing::info_span!("filtered_span").in_scope(|| log::info!("inside filtered_span"));
+
+    let entries = logger::recent_logs(0, 0, None);
+    assert!(entries.iter().any(|e| e.msg == "inside filtered_span"));
+    assert!(!entries
+        .iter()
+        .any(|e| e.span_id.is_some() || e.msg.starts_with("filtered_span")));
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
+fn has(entries: &[LogEntry], msg: &str) -> bool {
+    entries.iter().any(|e| e.msg.contains(msg))
+}
+
+#[test]
+fn recent_logs_filter_by_time_level_and_trace() {
+    let _serial = init();
+    let since = now_millis();
+    // Logs a warning under the call's trace id: the camera was never set up.
+    api::get_group_name(
+        "motion".to_string(),
+        "logs-missing|trace=logs-trace".to_string(),
+        None,
+    );
+    log::info!("logs: untraced");
+    log::debug!("logs: below the logger's level");
+
+    let traced = logger::recent_logs(since, 0, Some("logs-trace".to_string()));
+    assert!(has(&traced, "No init params for camera logs-missing"));
+    assert!(traced
+        .iter()
+        .all(|e| e.trace_id.as_deref() == Some("logs-trace")));
+
+    let all = logger::recent_logs(since, 0, None);
+    assert!(has(&all, "logs: untraced"));
+    assert!(!has(&all, "logs: below the logger's level"));
+
+    let warnings = logger::recent_logs(since, 3, None);
+    assert!(has(&warnings, "No init params for camera logs-missing"));
+    assert!(!has(&warnings, "logs: untraced"));
+
+    assert!(logger::recent_logs(now_millis() + 60_000, 0, None).is_empty());
+}
+
+#[test]
+fn an_explicit_context_wins_over_the_trace_suffix() {
+    let _serial = init();
+    let since = now_millis();
+    let context = logger::CallContext {
+        trace_id: Some("logs-context".to_string()),
+        source: None,
+        parent_span: None,
+    };
+    // With a context the name is taken as is, suffix and all.
+    api::get_group_name(
+        "motion".to_string(),
+        "logs-literal|trace=ignored".to_string(),
+        Some(context),
+    );
+
+    let traced = logger::recent_logs(since, 0, Some("logs-context".to_string()));
+    assert!(has(
+        &traced,
+        "No init params for camera logs-literal|trace=ignored"
+    ));
+    assert!(logger::recent_logs(since, 0, Some("ignored".to_string())).is_empty());
+}
+
+#[test]
+fn recent_logs_keep_only_the_newest_entries() {
+    let _serial = init();
+    for i in 0..2500 {
+        log::info!("ring entry {}", i);
+    }
+    let entries = logger::recent_logs(0, 0, None);
+    assert!(entries.len() <= 2000);
+    assert!(has(&entries, "ring entry 2499"));
+    assert!(!entries.iter().any(|e| e.msg == "ring entry 0"));
+    // Oldest first.
+    let ring: Vec<&str> = entries
+        .iter()
+        .filter(|e| e.msg.starts_with("ring entry "))
+        .map(|e| e.msg.as_str())
+        .collect();
+    assert_eq!(ring.last(), Some(&"ring entry 2499"));
+}
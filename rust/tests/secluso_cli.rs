@@ -0,0 +1,131 @@
+//! SPDX-License-Identifier: GPL-3.0-or-later
+
+//! The headless CLI, driven against the in-memory fake backend.
+
+use parking_lot::Mutex;
+
+use rust_lib_secluso_flutter::backend::fake::FakeCamera;
+use rust_lib_secluso_flutter::cli;
+
+// The backend factory and client map are process-wide, so tests take turns.
+static SERIAL: Mutex<()> = Mutex::new(());
+
+fn run(args: &[&str]) -> (Result<(), String>, String) {
+    let mut out = Vec::new();
+    let argv = args.iter().map(|arg| arg.to_string()).collect();
+    let result = cli::run(argv, &mut out);
+    (result, String::from_utf8(out).unwrap())
+}
+
+fn cli(args: &[&str]) -> Result<String, String> {
+    let (result, out) = run(args);
+    result.map(|()| out)
+}
+
+#[test]
+fn bad_command_lines_print_usage() {
+    assert!(cli(&[]).unwrap_err().starts_with("usage:"));
+    assert!(cli(&["--help"]).unwrap_err().starts_with("usage:"));
+
+    let err = cli(&["--camera", "c", "--dir", "/tmp/c", "--bogus", "init"]).unwrap_err();
+    assert!(err.starts_with("unknown option --bogus\n"), "{}", err);
+    let err = cli(&["--epoch", "soon", "decrypt-video", "f"]).unwrap_err();
+    assert!(err.starts_with("--epoch: "), "{}", err);
+    assert_eq!(
+        cli(&["--camera", "c", "init"]).unwrap_err(),
+        "--dir is required"
+    );
+}
+
+fn with<'a>(args: &[&'a str]) -> Vec<&'a str> {
+    [
+        &["--camera", "cli-commands", "--dir", "/tmp/cli-commands"],
+        args,
+    ]
+    .concat()
+}
+
+#[test]
+fn commands_go_through_the_bridge() {
+    let _serial = SERIAL.lock();
+    let camera = FakeCamera::new();
+    camera.install();
+    camera.state().group_epoch = 3;
+
+    assert_eq!(
+        cli(&with(&["group-name", "motion"])).unwrap(),
+        "motion_group\n"
+    );
+    assert_eq!(
+        cli(&with(&["decrypt-video", "encVideo1"])).unwrap(),
+        "video_encVideo1.mp4 (epoch 3)\n"
+    );
+    assert_eq!(
+        cli(&with(&["heartbeat-request", "42"])).unwrap(),
+        "000000000000002a\n"
+    );
+    let err = cli(&with(&["heartbeat-request", "later"])).unwrap_err();
+    assert!(err.starts_with("timestamp: "), "{}", err);
+
+    let dir = std::env::temp_dir().join(format!("secluso-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let (input, output) = (dir.join("settings"), dir.join("settings.enc"));
+    std::fs::write(&input, b"{\"motion\":true}").unwrap();
+    let (input, output) = (input.to_str().unwrap(), output.to_str().unwrap());
+    let out = cli(&with(&["--out", output, "encrypt-settings", input])).unwrap();
+    assert!(out.is_empty());
+    assert_eq!(std::fs::read(output).unwrap(), b"{\"motion\":true}");
+
+    camera.fail("get_group_name", "no group");
+    let err = cli(&with(&["group-name", "motion"])).unwrap_err();
+    assert!(err.starts_with("Error(get_group_name): "), "{}", err);
+    let err = cli(&with(&["frobnicate"])).unwrap_err();
+    assert!(err.starts_with("unknown command\n"), "{}", err);
+}
+
+#[test]
+fn tail_logs_prints_what_the_command_logged() {
+    let _serial = SERIAL.lock();
+    let camera = FakeCamera::new();
+    camera.install();
+    let tail = |trace: &str| {
+        run(&[
+            "--camera",
+            "cli-logs",
+            "--dir",
+            "/tmp/cli-logs",
+            "--trace",
+            trace,
+            "--log-level",
+            "debug",
+            "tail-logs",
+            "group-name",
+            "motion",
+        ])
+    };
+
+    let (result, out) = tail("cli-trace-1");
+    result.unwrap();
+    let mut lines = out.lines();
+    assert_eq!(lines.next(), Some("motion_group"));
+    let entries: Vec<&str> = lines.collect();
+    assert!(!entries.is_empty());
+    assert!(entries
+        .iter()
+        .all(|line| line.contains(" trace=cli-trace-1 source=cli ")));
+    assert!(
+        entries
+            .iter()
+            .any(|line| line.contains(" span=")
+                && line.contains("get_group_name camera=cli-logs took ")),
+        "{}",
+        out
+    );
+
+    // A failing command still prints its entries, and only its own.
+    camera.fail("get_group_name", "no group");
+    let (result, out) = tail("cli-trace-2");
+    assert!(result.unwrap_err().starts_with("Error(get_group_name): "));
+    assert!(out.contains("get_group_name error: no group"), "{}", out);
+    assert!(!out.contains("cli-trace-1"));
+}